@@ -1,14 +1,17 @@
-use anyhow::{Result, anyhow};
-use bytes::BytesMut;
+use anyhow::{Context, Result, anyhow};
+use bytes::{Bytes, BytesMut};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::net::TcpStream;
 
-use crate::protocol::redis_serialization_protocol::RedisType;
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
 use crate::storage::StorageEngine;
 
 /// Command trait following the Open-Closed Principle.
-/// New commands can be added by implementing this trait and registering
-/// a new dispatch inside `dispatch_and_execute`.
+/// New commands can be added by implementing this trait and registering its spec in
+/// `command_table::COMMAND_TABLE`, with a `Handler` that `dispatch_and_execute` maps to the command.
 pub trait RedisCommand: Sized {
     /// Parses the given RedisType into a concrete command instance.
     fn parse(redis_type: &RedisType) -> Result<Self>;
@@ -32,6 +35,71 @@ fn storage_engine() -> Result<Arc<StorageEngine>> {
         .ok_or_else(|| anyhow!("Storage engine is not initialized"))
 }
 
+// Number of clients currently inside a blocking command (BLPOP), reported by INFO.
+static BLOCKED_CLIENTS: AtomicUsize = AtomicUsize::new(0);
+
+fn blocked_clients() -> usize {
+    BLOCKED_CLIENTS.load(Ordering::Relaxed)
+}
+
+/// Counts the current client as blocked for as long as the guard is alive.
+/// Decrementing on drop keeps the gauge correct when the command returns, times out,
+/// fails, or the client disconnects while waiting.
+struct BlockedClientGuard;
+
+impl BlockedClientGuard {
+    fn new() -> Self {
+        BLOCKED_CLIENTS.fetch_add(1, Ordering::Relaxed);
+        Self
+    }
+}
+
+impl Drop for BlockedClientGuard {
+    fn drop(&mut self) {
+        BLOCKED_CLIENTS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+// Set once at startup from `--read-only`: commands flagged `write` in the command table are rejected.
+static READ_ONLY: AtomicBool = AtomicBool::new(false);
+
+const READ_ONLY_ERROR: &str = "READONLY You can't write against a read only replica.";
+
+pub fn set_read_only(read_only: bool) {
+    READ_ONLY.store(read_only, Ordering::Relaxed);
+}
+
+// With `--requirepass`, connections that haven't authenticated yet may only send these commands.
+pub const NOAUTH_ERROR: &str = "NOAUTH Authentication required.";
+
+const ALLOWED_BEFORE_AUTH: &[&str] = &["AUTH", "HELLO", "QUIT"];
+
+pub fn is_auth_command(redis_type: &RedisType) -> bool {
+    upper_first_bulk_string(redis_type).as_deref() == Some("AUTH")
+}
+
+pub fn is_hello_command(redis_type: &RedisType) -> bool {
+    upper_first_bulk_string(redis_type).as_deref() == Some("HELLO")
+}
+
+pub fn is_client_command(redis_type: &RedisType) -> bool {
+    upper_first_bulk_string(redis_type).as_deref() == Some("CLIENT")
+}
+
+pub fn is_allowed_before_auth(redis_type: &RedisType) -> bool {
+    upper_first_bulk_string(redis_type)
+        .as_deref()
+        .is_some_and(|name| ALLOWED_BEFORE_AUTH.contains(&name))
+}
+
+// Replies queued by the connection handler must be written out before one of these starts waiting.
+pub fn is_blocking_command(redis_type: &RedisType) -> bool {
+    upper_first_bulk_string(redis_type)
+        .as_deref()
+        .and_then(command_table::find_command_spec)
+        .is_some_and(|spec| spec.is_blocking())
+}
+
 // Helpers used by submodules
 fn expect_cmd_array(redis_type: &RedisType) -> Result<&[RedisType]> {
     if let RedisType::Array(elements) = redis_type {
@@ -41,40 +109,300 @@ fn expect_cmd_array(redis_type: &RedisType) -> Result<&[RedisType]> {
     }
 }
 
+fn unix_time_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Converts an `EX`/`PX`/`EXAT`/`PXAT` option (shared by SET and GETEX) into a delay from now in milliseconds.
+/// Returns None when `option` is not an expiration option. Absolute timestamps in the past give a 0 delay.
+fn parse_expiration_option(option: &str, value: &str, command_name: &str) -> Result<Option<u64>> {
+    let option = option.to_uppercase();
+    if !matches!(option.as_str(), "EX" | "PX" | "EXAT" | "PXAT") {
+        return Ok(None);
+    }
+
+    let number = value
+        .parse::<u64>()
+        .with_context(|| format!("Can't convert {option} value '{value}' to number"))?;
+
+    let invalid_expire_time = || anyhow!("ERR invalid expire time in '{command_name}' command");
+    if number == 0 {
+        return Err(invalid_expire_time());
+    }
+
+    let delay_in_ms = match option.as_str() {
+        "EX" => number.checked_mul(1000),
+        "PX" => Some(number),
+        "EXAT" => number
+            .checked_mul(1000)
+            .map(|deadline_ms| deadline_ms.saturating_sub(unix_time_ms())),
+        _ => Some(number.saturating_sub(unix_time_ms())),
+    };
+
+    delay_in_ms.map(Some).ok_or_else(invalid_expire_time)
+}
+
+///
+/// Timeout argument of the blocking list commands (BLPOP, BRPOP), converted to milliseconds.
+/// The timeout argument is interpreted as a double value specifying the maximum number of seconds to block. A timeout of zero can be used to block indefinitely.
+///
+fn parse_blocking_timeout_ms(timeout_str: &str, command_name: &str) -> Result<u64> {
+    let timeout_as_sec = timeout_str.parse::<f64>().with_context(|| {
+        format!("{command_name} 'timeout' must be a finite, non-negative number")
+    })?;
+
+    if !timeout_as_sec.is_finite() || timeout_as_sec < 0.0 {
+        anyhow::bail!("{command_name} 'timeout' must be a finite, non-negative number");
+    }
+
+    let timeout_in_ms = if timeout_as_sec == 0.0 {
+        u64::MAX
+    } else {
+        let millis = (timeout_as_sec * 1000.0).floor();
+        if millis > u64::MAX as f64 {
+            u64::MAX
+        } else {
+            millis as u64
+        }
+    };
+
+    Ok(timeout_in_ms)
+}
+
+// Multi-key commands and shards
+//
+// Every shard owns its keys and applies one request at a time, so a request touching a single shard
+// is atomic. Commands taking several keys (MGET, MSET, DEL, EXISTS, LMOVE/RPOPLPUSH, RENAME, SINTER, ...)
+// issue one request per involved shard instead, and those requests are NOT atomic together:
+// another client may observe the command half applied, e.g. an MSET that already wrote the keys
+// of one shard but not yet those of another. Nothing is rolled back when a later shard request fails.
+//
+// Convention for such commands: the doc comment states how the command behaves across shards,
+// including what may be observed in between, and the case is covered in `tests/cross_shard_test.rs`.
+
+/// Groups argument positions by the shard owning the key at that position.
+/// Multi-key commands send one batched request per shard and use the positions
+/// to put the per-shard results back in the original argument order.
+fn group_keys_by_shard<'a>(
+    engine: &StorageEngine,
+    keys: impl Iterator<Item = &'a str>,
+) -> HashMap<usize, Vec<usize>> {
+    let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (position, key) in keys.enumerate() {
+        groups
+            .entry(engine.shard_index_for_key(key))
+            .or_default()
+            .push(position);
+    }
+    groups
+}
+
+/// Value argument as stored: a BulkString as is, a RESP Integer in its decimal form.
+/// Clients may send values such as `:42` typed, Redis treats them as the same bytes.
+fn value_bytes(element: &RedisType) -> Option<Bytes> {
+    match element {
+        RedisType::BulkString(value) => Some(value.clone()),
+        RedisType::Integer(value) => Some(value.to_string().into()),
+        _ => None,
+    }
+}
+
 fn upper_first_bulk_string(redis_type: &RedisType) -> Option<String> {
     if let RedisType::Array(elements) = redis_type
         && let Some(RedisType::BulkString(cmd)) = elements.first()
     {
-        return Some(cmd.to_uppercase());
+        return Some(bulk_str(cmd).to_uppercase());
     }
     None
 }
 
 // Submodules containing individual command implementations
+mod append;
+mod argument_spec;
+mod auth;
 mod blpop;
+mod brpop;
+mod client;
+mod client_tracking;
 mod command_meta;
+mod command_table;
+mod debug;
+mod decr;
+mod decrby;
+mod del;
 mod echo;
+mod exists;
+mod expire;
 mod get;
+mod getex;
+mod getrange;
+mod hdel;
+mod hello;
+mod hexists;
+mod hget;
+mod hgetall;
+mod hincrby;
+mod hincrbyfloat;
+mod hlen;
+mod hmget;
+mod hset;
+mod incr;
+mod incrby;
+mod incrbyfloat;
+mod info;
+mod key_type;
+mod lindex;
 mod llen;
+mod lmove;
 mod lpop;
 mod lpush;
+mod lpushx;
 mod lrange;
+mod lrem;
+mod mget;
+mod mset;
+mod object;
+mod persist;
 mod ping;
+mod pttl;
+mod rename;
+mod rpop;
+mod rpoplpush;
 mod rpush;
+mod rpushx;
+mod sadd;
+mod scard;
 mod set;
+mod setnx;
+mod setrange;
+mod sinter;
+mod sismember;
+mod smembers;
+mod spop;
+mod srandmember;
+mod srem;
+mod ttl;
+mod xadd;
+mod xlen;
+mod xrange;
+mod zadd;
+mod zcount;
+mod zpopmax;
+mod zpopmin;
+mod zrange;
+mod zrangebyscore;
+mod zscore;
+
+use command_table::Handler;
 
 // Re-export for convenience
+pub use append::AppendCommand;
+pub use auth::AuthCommand;
 pub use blpop::BlockingLeftPopCommand;
+pub use brpop::BlockingRightPopCommand;
+pub use client::ClientCommand;
+pub use client_tracking::{
+    TrackingConnection, invalidate_keys, invalidate_written_keys, track_read_keys,
+};
 pub use command_meta::CommandCommand;
+pub use debug::DebugCommand;
+pub use decr::DecrCommand;
+pub use decrby::DecrByCommand;
+pub use del::DelCommand;
 pub use echo::EchoCommand;
+pub use exists::ExistsCommand;
+pub use expire::ExpireCommand;
 pub use get::GetCommand;
+pub use getex::GetExCommand;
+pub use getrange::GetRangeCommand;
+pub use hdel::HDelCommand;
+pub use hello::HelloCommand;
+pub use hexists::HExistsCommand;
+pub use hget::HGetCommand;
+pub use hgetall::HashViewCommand;
+pub use hincrby::HIncrByCommand;
+pub use hincrbyfloat::HIncrByFloatCommand;
+pub use hlen::HLenCommand;
+pub use hmget::HMGetCommand;
+pub use hset::HSetCommand;
+pub use incr::IncrCommand;
+pub use incrby::IncrByCommand;
+pub use incrbyfloat::IncrByFloatCommand;
+pub use info::InfoCommand;
+pub use key_type::TypeCommand;
+pub use lindex::LIndexCommand;
 pub use llen::LLenCommand;
+pub use lmove::LMoveCommand;
 pub use lpop::LPopCommand;
 pub use lpush::LPushCommand;
+pub use lpushx::LPushXCommand;
 pub use lrange::LRange;
+pub use lrem::LRemCommand;
+pub use mget::MGetCommand;
+pub use mset::MSetCommand;
+pub use object::ObjectCommand;
+pub use persist::PersistCommand;
 pub use ping::PingCommand;
+pub use pttl::PTtlCommand;
+pub use rename::RenameCommand;
+pub use rpop::RPopCommand;
+pub use rpoplpush::RPopLPushCommand;
 pub use rpush::RPushCommand;
+pub use rpushx::RPushXCommand;
+pub use sadd::SAddCommand;
+pub use scard::SCardCommand;
 pub use set::SetCommand;
+pub use setnx::SetNxCommand;
+pub use setrange::SetRangeCommand;
+pub use sinter::SetOperationCommand;
+pub use sismember::SIsMemberCommand;
+pub use smembers::SMembersCommand;
+pub use spop::SPopCommand;
+pub use srandmember::SRandMemberCommand;
+pub use srem::SRemCommand;
+pub use ttl::TtlCommand;
+pub use xadd::XAddCommand;
+pub use xlen::XLenCommand;
+pub use xrange::XRangeCommand;
+pub use zadd::ZAddCommand;
+pub use zcount::ZCountCommand;
+pub use zpopmax::ZPopMaxCommand;
+pub use zpopmin::ZPopMinCommand;
+pub use zrange::ZRangeCommand;
+pub use zrangebyscore::ZRangeByScoreCommand;
+pub use zscore::ZScoreCommand;
+
+/// Builds the reply for PING and ECHO without going through `dispatch_and_execute`.
+/// Both commands never access the storage engine, so health probes and benchmarks
+/// skip the command name allocation and the parse step of the full dispatch.
+/// Returns None for anything else (including malformed PING/ECHO), so the caller
+/// falls back to the full dispatch which produces the usual error replies.
+pub fn try_fast_path_reply(redis_type: &RedisType) -> Option<RedisType> {
+    let RedisType::Array(elements) = redis_type else {
+        return None;
+    };
+    let Some(RedisType::BulkString(cmd)) = elements.first() else {
+        return None;
+    };
+
+    if cmd.eq_ignore_ascii_case(b"PING") {
+        match &elements[1..] {
+            [] => Some(RedisType::SimpleString("PONG".to_string())),
+            [RedisType::BulkString(arg)] => Some(RedisType::BulkString(arg.clone())),
+            _ => None,
+        }
+    } else if cmd.eq_ignore_ascii_case(b"ECHO") {
+        match &elements[1..] {
+            [RedisType::BulkString(arg)] => Some(RedisType::BulkString(arg.clone())),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
 
 /// Dispatches a parsed RESP value to the corresponding command and executes it.
 /// Returns an error if the command is unsupported or invalid.
@@ -83,66 +411,405 @@ pub async fn dispatch_and_execute(
     output_buf: &mut BytesMut,
     stream: &mut TcpStream,
 ) -> Result<()> {
-    match upper_first_bulk_string(redis_type).as_deref() {
-        Some("PING") => {
-            return PingCommand::parse(redis_type)?
+    let command_name = upper_first_bulk_string(redis_type);
+    let Some(name) = command_name.as_deref() else {
+        return Err(anyhow!("Incorrect command type format"));
+    };
+    let spec = command_table::find_command_spec(name);
+
+    if READ_ONLY.load(Ordering::Relaxed) && spec.is_some_and(|spec| spec.is_write()) {
+        return Err(anyhow!(READ_ONLY_ERROR));
+    }
+
+    argument_spec::validate_arguments(name, redis_type)?;
+
+    // Only the commands of the table are served, with the handler of their spec
+    let Some(spec) = spec else {
+        return Err(anyhow!("Command type is not defined or unknown {name}"));
+    };
+
+    match spec.handler {
+        // Never dispatched, the connection handler answers these first
+        Handler::Connection => Err(anyhow!("Command type is not defined or unknown {name}")),
+        Handler::Ping => {
+            PingCommand::parse(redis_type)?
                 .execute(output_buf, stream)
-                .await;
+                .await
         }
-        Some("ECHO") => {
-            return EchoCommand::parse(redis_type)?
+        Handler::Echo => {
+            EchoCommand::parse(redis_type)?
                 .execute(output_buf, stream)
-                .await;
+                .await
         }
-        Some("COMMAND") => {
-            return CommandCommand::parse(redis_type)?
+        Handler::Command => {
+            CommandCommand::parse(redis_type)?
                 .execute(output_buf, stream)
-                .await;
+                .await
         }
-        Some("SET") => {
-            return SetCommand::parse(redis_type)?
+        Handler::Set => {
+            SetCommand::parse(redis_type)?
                 .execute(output_buf, stream)
-                .await;
+                .await
         }
-        Some("GET") => {
-            return GetCommand::parse(redis_type)?
+        Handler::MSet => {
+            MSetCommand::parse(redis_type)?
                 .execute(output_buf, stream)
-                .await;
+                .await
         }
-        Some("RPUSH") => {
-            return RPushCommand::parse(redis_type)?
+        Handler::MGet => {
+            MGetCommand::parse(redis_type)?
                 .execute(output_buf, stream)
-                .await;
+                .await
         }
-        Some("LPUSH") => {
-            return LPushCommand::parse(redis_type)?
+        Handler::SetNx => {
+            SetNxCommand::parse(redis_type)?
                 .execute(output_buf, stream)
-                .await;
+                .await
         }
-
-        Some("LPOP") => {
-            return LPopCommand::parse(redis_type)?
+        Handler::Get => {
+            GetCommand::parse(redis_type)?
                 .execute(output_buf, stream)
-                .await;
+                .await
         }
-
-        Some("BLPOP") => {
-            return BlockingLeftPopCommand::parse(redis_type)?
+        Handler::GetEx => {
+            GetExCommand::parse(redis_type)?
                 .execute(output_buf, stream)
-                .await;
+                .await
         }
-
-        Some("LRANGE") => {
-            return LRange::parse(redis_type)?.execute(output_buf, stream).await;
+        Handler::GetRange => {
+            GetRangeCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
         }
-
-        Some("LLEN") => {
-            return LLenCommand::parse(redis_type)?
+        Handler::SetRange => {
+            SetRangeCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::Append => {
+            AppendCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::Ttl => {
+            TtlCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::PTtl => {
+            PTtlCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::Expire => {
+            ExpireCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::Persist => {
+            PersistCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::Del => {
+            DelCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::Exists => {
+            ExistsCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::Type => {
+            TypeCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::Object => {
+            ObjectCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::Rename => {
+            RenameCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::Incr => {
+            IncrCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::Decr => {
+            DecrCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::IncrBy => {
+            IncrByCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::DecrBy => {
+            DecrByCommand::parse(redis_type)?
                 .execute(output_buf, stream)
-                .await;
+                .await
         }
+        Handler::IncrByFloat => {
+            IncrByFloatCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::RPush => {
+            RPushCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::RPushX => {
+            RPushXCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::LPush => {
+            LPushCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::LPushX => {
+            LPushXCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::LPop => {
+            LPopCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::RPop => {
+            RPopCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::LMove => {
+            LMoveCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::RPopLPush => {
+            RPopLPushCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::BlockingLeftPop => {
+            BlockingLeftPopCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::BlockingRightPop => {
+            BlockingRightPopCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::LRange => LRange::parse(redis_type)?.execute(output_buf, stream).await,
+        Handler::LLen => {
+            LLenCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::LIndex => {
+            LIndexCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::LRem => {
+            LRemCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::Info => {
+            InfoCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::HSet => {
+            HSetCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::HGet => {
+            HGetCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::HMGet => {
+            HMGetCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::HExists => {
+            HExistsCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::HDel => {
+            HDelCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::HashView => {
+            HashViewCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::HLen => {
+            HLenCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::HIncrBy => {
+            HIncrByCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::HIncrByFloat => {
+            HIncrByFloatCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::SAdd => {
+            SAddCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::SRem => {
+            SRemCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::SMembers => {
+            SMembersCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::SIsMember => {
+            SIsMemberCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::SCard => {
+            SCardCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::SPop => {
+            SPopCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::SRandMember => {
+            SRandMemberCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::SetOperation => {
+            SetOperationCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::ZAdd => {
+            ZAddCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::ZScore => {
+            ZScoreCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::ZRange => {
+            ZRangeCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::ZRangeByScore => {
+            ZRangeByScoreCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::ZCount => {
+            ZCountCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::ZPopMin => {
+            ZPopMinCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::ZPopMax => {
+            ZPopMaxCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::XAdd => {
+            XAddCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::XLen => {
+            XLenCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::XRange => {
+            XRangeCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+        Handler::Debug => {
+            DebugCommand::parse(redis_type)?
+                .execute(output_buf, stream)
+                .await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::command_table::{COMMAND_TABLE, Handler};
+
+    // Answered by the connection handler before `dispatch_and_execute`, they change the connection state.
+    const HANDLED_BY_CONNECTION: &[&str] = &["AUTH", "HELLO", "CLIENT"];
+
+    fn registered_commands() -> BTreeSet<String> {
+        COMMAND_TABLE
+            .iter()
+            .map(|spec| spec.name.to_uppercase())
+            .collect()
+    }
+
+    // `dispatch_and_execute` only serves table commands, through their handler: the commands it
+    // doesn't dispatch are exactly the ones the connection handler answers
+    #[test]
+    fn only_connection_commands_are_not_dispatched() {
+        let not_dispatched: BTreeSet<String> = COMMAND_TABLE
+            .iter()
+            .filter(|spec| spec.handler == Handler::Connection)
+            .map(|spec| spec.name.to_uppercase())
+            .collect();
+        let expected: BTreeSet<String> = HANDLED_BY_CONNECTION
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+
+        assert_eq!(not_dispatched, expected);
+    }
 
-        Some(cmd) => Err(anyhow!("Command type is not defined or unknown {cmd}")),
-        None => Err(anyhow!("Incorrect command type format")),
+    #[test]
+    fn command_table_has_no_duplicates() {
+        assert_eq!(registered_commands().len(), COMMAND_TABLE.len());
     }
 }
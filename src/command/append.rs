@@ -0,0 +1,74 @@
+use anyhow::{Result, anyhow};
+use bytes::{Bytes, BytesMut};
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{AppendStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/append/
+/// If key already exists and is a string, appends the value at the end of the string.
+/// If key does not exist it is created and set as an empty string, so APPEND will be similar to SET in this special case.
+/// Returns the length of the string after the append operation.
+///
+#[derive(Debug)]
+pub struct AppendCommand {
+    key: String,
+    value: Bytes,
+}
+
+impl RedisCommand for AppendCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // APPEND key value
+        if elements.len() != 3 {
+            return Err(anyhow!("Wrong number of arguments for APPEND command"));
+        }
+
+        if let RedisType::BulkString(key) = &elements[1]
+            && let Some(value) = super::value_bytes(&elements[2])
+        {
+            Ok(Self {
+                key: bulk_str(key).into_owned(),
+                value,
+            })
+        } else {
+            Err(anyhow!(
+                "APPEND incorrect parameter types, expected BulkString, BulkString or Integer"
+            ))
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(AppendStorage {
+                key: self.key.clone(),
+                value: self.value.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(len) => {
+                RedisType::Integer(len)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during APPEND".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,222 @@
+use anyhow::{Result, anyhow};
+
+use std::borrow::Cow;
+
+use bytes::Bytes;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::NOT_AN_INTEGER_ERROR;
+
+///
+/// Declarative argument specs, checked by `validate_arguments` during dispatch before a command parses
+/// its request, so malformed requests get the same replies whatever the command:
+/// - wrong argument count: `ERR wrong number of arguments for '<command>' command`
+/// - wrong RESP type: `ERR invalid argument '<name>' for '<command>' command, expected <kind>`
+/// - integer argument that does not parse: `ERR value is not an integer or out of range`
+///
+/// A command listed in `ARGUMENT_SPECS` can rely on its input matching the spec and read it with
+/// `bulk_arg` / `value_arg` / `integer_arg`. The specs are also reported by COMMAND DOCS.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgKind {
+    /// Key name, a BulkString
+    Key,
+    /// Any BulkString
+    String,
+    /// BulkString or RESP Integer, integers are taken in their decimal form
+    StringOrInteger,
+    /// BulkString or RESP Integer holding a signed 64-bit integer
+    Integer,
+}
+
+impl ArgKind {
+    /// Argument type as named by COMMAND DOCS.
+    pub fn docs_type(self) -> &'static str {
+        match self {
+            ArgKind::Key => "key",
+            ArgKind::String | ArgKind::StringOrInteger => "string",
+            ArgKind::Integer => "integer",
+        }
+    }
+
+    fn expected(self) -> &'static str {
+        match self {
+            ArgKind::Key | ArgKind::String => "a bulk string",
+            ArgKind::StringOrInteger | ArgKind::Integer => "a bulk string or an integer",
+        }
+    }
+
+    /// Ok, or whether the RESP type is wrong (false) or the integer does not parse (true).
+    fn check(self, element: &RedisType) -> Result<(), bool> {
+        match (self, element) {
+            (ArgKind::Key | ArgKind::String, RedisType::BulkString(_)) => Ok(()),
+            (ArgKind::StringOrInteger, RedisType::BulkString(_) | RedisType::Integer(_)) => Ok(()),
+            (ArgKind::Integer, RedisType::Integer(_)) => Ok(()),
+            (ArgKind::Integer, RedisType::BulkString(value)) => {
+                bulk_str(value).parse::<i64>().map(|_| ()).map_err(|_| true)
+            }
+            _ => Err(false),
+        }
+    }
+}
+
+/// How many times an argument may appear at its position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Multiplicity {
+    /// Exactly once
+    Single,
+    /// One or more times, only as the last argument
+    OneOrMore,
+    /// Zero or more times, only as the last argument
+    ZeroOrMore,
+}
+
+#[derive(Debug)]
+pub struct ArgSpec {
+    pub name: &'static str,
+    pub kind: ArgKind,
+    pub multiplicity: Multiplicity,
+}
+
+const fn arg(name: &'static str, kind: ArgKind) -> ArgSpec {
+    ArgSpec {
+        name,
+        kind,
+        multiplicity: Multiplicity::Single,
+    }
+}
+
+const fn one_or_more(name: &'static str, kind: ArgKind) -> ArgSpec {
+    ArgSpec {
+        name,
+        kind,
+        multiplicity: Multiplicity::OneOrMore,
+    }
+}
+
+const fn zero_or_more(name: &'static str, kind: ArgKind) -> ArgSpec {
+    ArgSpec {
+        name,
+        kind,
+        multiplicity: Multiplicity::ZeroOrMore,
+    }
+}
+
+/// Arguments after the command name, per command (lowercase, like `COMMAND_TABLE`).
+/// A repeated argument can only come last.
+pub static ARGUMENT_SPECS: &[(&str, &[ArgSpec])] = &[
+    ("get", &[arg("key", ArgKind::Key)]),
+    (
+        "set",
+        &[
+            arg("key", ArgKind::Key),
+            arg("value", ArgKind::StringOrInteger),
+            // Expiration options are checked by SET itself, they depend on each other
+            zero_or_more("option", ArgKind::String),
+        ],
+    ),
+    (
+        "rpush",
+        &[
+            arg("key", ArgKind::Key),
+            one_or_more("element", ArgKind::StringOrInteger),
+        ],
+    ),
+    (
+        "lrange",
+        &[
+            arg("key", ArgKind::Key),
+            arg("start", ArgKind::Integer),
+            arg("stop", ArgKind::Integer),
+        ],
+    ),
+];
+
+/// Case-insensitive lookup of the argument spec of a command.
+pub fn find_argument_spec(name: &str) -> Option<&'static [ArgSpec]> {
+    ARGUMENT_SPECS
+        .iter()
+        .find(|(command, _)| command.eq_ignore_ascii_case(name))
+        .map(|(_, specs)| *specs)
+}
+
+/// Checks the request of a command against its spec, commands without spec are accepted as is.
+pub fn validate_arguments(name: &str, redis_type: &RedisType) -> Result<()> {
+    let Some(specs) = find_argument_spec(name) else {
+        return Ok(());
+    };
+    let RedisType::Array(elements) = redis_type else {
+        return Ok(());
+    };
+
+    let command = name.to_lowercase();
+    let wrong_arity = || anyhow!("ERR wrong number of arguments for '{command}' command");
+
+    let mut args = elements[1..].iter();
+    for spec in specs {
+        let (min, max) = match spec.multiplicity {
+            Multiplicity::Single => (1, 1),
+            Multiplicity::OneOrMore => (1, usize::MAX),
+            Multiplicity::ZeroOrMore => (0, usize::MAX),
+        };
+
+        let mut seen = 0;
+        while seen < max {
+            let Some(element) = args.next() else {
+                break;
+            };
+            spec.kind.check(element).map_err(|not_an_integer| {
+                if not_an_integer {
+                    anyhow!(NOT_AN_INTEGER_ERROR)
+                } else {
+                    anyhow!(
+                        "ERR invalid argument '{}' for '{command}' command, expected {}",
+                        spec.name,
+                        spec.kind.expected()
+                    )
+                }
+            })?;
+            seen += 1;
+        }
+
+        if seen < min {
+            return Err(wrong_arity());
+        }
+    }
+
+    if args.next().is_some() {
+        return Err(wrong_arity());
+    }
+
+    Ok(())
+}
+
+const NOT_VALIDATED: &str = "ERR argument does not match the command argument spec";
+
+/// BulkString argument at `index` of a validated request, as text.
+pub fn bulk_arg(elements: &[RedisType], index: usize) -> Result<Cow<'_, str>> {
+    match elements.get(index) {
+        Some(RedisType::BulkString(value)) => Ok(bulk_str(value)),
+        _ => Err(anyhow!(NOT_VALIDATED)),
+    }
+}
+
+/// BulkString or Integer argument at `index` of a validated request, integers in decimal form.
+pub fn value_arg(elements: &[RedisType], index: usize) -> Result<Bytes> {
+    match elements.get(index) {
+        Some(RedisType::BulkString(value)) => Ok(value.clone()),
+        Some(RedisType::Integer(value)) => Ok(value.to_string().into()),
+        _ => Err(anyhow!(NOT_VALIDATED)),
+    }
+}
+
+/// Integer argument at `index` of a validated request.
+pub fn integer_arg(elements: &[RedisType], index: usize) -> Result<i64> {
+    match elements.get(index) {
+        Some(RedisType::Integer(value)) => Ok(*value),
+        Some(RedisType::BulkString(value)) => bulk_str(value)
+            .parse::<i64>()
+            .map_err(|_| anyhow!(NOT_AN_INTEGER_ERROR)),
+        _ => Err(anyhow!(NOT_VALIDATED)),
+    }
+}
@@ -0,0 +1,84 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+
+///
+/// https://redis.io/docs/latest/commands/auth/
+/// Authenticates the current connection against the password configured with `--requirepass`.
+/// The only user is `default`, so `AUTH password` and `AUTH default password` are equivalent.
+/// Replies OK on success, WRONGPASS for a wrong password or user, and an error when no password is configured.
+///
+/// AUTH changes the state of the connection, so unlike other commands it is executed by the
+/// connection loop and not by `dispatch_and_execute`.
+///
+#[derive(Debug)]
+pub struct AuthCommand {
+    username: Option<String>,
+    password: String,
+}
+
+const DEFAULT_USER: &str = "default";
+
+const WRONG_PASSWORD_ERROR: &str = "WRONGPASS invalid username-password pair or user is disabled.";
+
+const NO_PASSWORD_CONFIGURED_ERROR: &str = "ERR AUTH <password> called without any password configured for the default user. Are you sure your configuration is correct?";
+
+impl AuthCommand {
+    pub fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // AUTH [username] password
+        match &elements[1..] {
+            [RedisType::BulkString(password)] => Ok(Self {
+                username: None,
+                password: bulk_str(password).into_owned(),
+            }),
+            [
+                RedisType::BulkString(username),
+                RedisType::BulkString(password),
+            ] => Ok(Self {
+                username: Some(bulk_str(username).into_owned()),
+                password: bulk_str(password).into_owned(),
+            }),
+            [_] | [_, _] => Err(anyhow!("AUTH arguments are not BulkStrings")),
+            _ => Err(anyhow!("ERR wrong number of arguments for 'auth' command")),
+        }
+    }
+
+    /// Replies to the client and returns whether the connection is now authenticated.
+    pub async fn execute(
+        &self,
+        requirepass: Option<&str>,
+        output_buf: &mut BytesMut,
+        stream: &mut TcpStream,
+    ) -> Result<bool> {
+        let (reply, authenticated) =
+            match check_password(self.username.as_deref(), &self.password, requirepass) {
+                Ok(()) => (RedisType::SimpleString("OK".to_string()), true),
+                Err(error) => (RedisType::SimpleError(error.to_string()), false),
+            };
+
+        reply.write_resp_to_stream(output_buf, stream).await?;
+
+        Ok(authenticated)
+    }
+}
+
+/// Checks credentials of AUTH and of the AUTH option of HELLO, the error is the reply to send back.
+pub(super) fn check_password(
+    username: Option<&str>,
+    password: &str,
+    requirepass: Option<&str>,
+) -> Result<(), &'static str> {
+    match requirepass {
+        None => Err(NO_PASSWORD_CONFIGURED_ERROR),
+        Some(requirepass)
+            if password == requirepass && username.is_none_or(|user| user == DEFAULT_USER) =>
+        {
+            Ok(())
+        }
+        Some(_) => Err(WRONG_PASSWORD_ERROR),
+    }
+}
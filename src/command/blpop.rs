@@ -1,15 +1,15 @@
 use std::time::Duration;
 
-use anyhow::{Context, Result, anyhow};
+use anyhow::{Result, anyhow};
 use bytes::BytesMut;
 use futures::{StreamExt, stream::FuturesUnordered};
 use tokio::net::TcpStream;
 use tokio::time::timeout;
 
-use crate::protocol::redis_serialization_protocol::RedisType;
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
 use crate::storage::{ListLeftBlockingPopStorage, StorageResponse};
 
-use super::{RedisCommand, storage_engine};
+use super::{BlockedClientGuard, RedisCommand, storage_engine};
 
 ///
 /// https://redis.io/docs/latest/commands/blpop/
@@ -35,14 +35,14 @@ impl RedisCommand for BlockingLeftPopCommand {
 
         for single_argument in &elements[1..elements.len() - 1] {
             if let RedisType::BulkString(key) = single_argument {
-                keys.push(key.clone());
+                keys.push(bulk_str(key).into_owned());
             } else {
                 return Err(anyhow!("BLPOP incorrect list key, not BulkString"));
             }
         }
 
         if let Some(RedisType::BulkString(timeout_str)) = elements.last() {
-            let timeout_in_ms = Self::convert_float_str_seconds_to_ms(timeout_str)?;
+            let timeout_in_ms = super::parse_blocking_timeout_ms(&bulk_str(timeout_str), "BLPOP")?;
 
             Ok(BlockingLeftPopCommand {
                 keys,
@@ -56,6 +56,9 @@ impl RedisCommand for BlockingLeftPopCommand {
     async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
         let engine = storage_engine()?;
 
+        // Reported by INFO as blocked until this command returns
+        let _blocked_guard = BlockedClientGuard::new();
+
         // Build a set of futures (one per key) each with a timeout.
         let mut futures = FuturesUnordered::new();
 
@@ -93,8 +96,8 @@ impl RedisCommand for BlockingLeftPopCommand {
                     StorageResponse::ValueFromList { value, list_name } => {
                         // Array reply: the key from which the element was popped and the value of the popped element.
                         RedisType::Array(vec![
-                            RedisType::BulkString(list_name),
-                            RedisType::BulkString(value),
+                            RedisType::BulkString(list_name.into()),
+                            RedisType::BulkString(value.into()),
                         ])
                         .write_resp_to_stream(output_buf, stream)
                         .await?;
@@ -129,31 +132,3 @@ impl RedisCommand for BlockingLeftPopCommand {
         Ok(())
     }
 }
-
-impl BlockingLeftPopCommand {
-    ///
-    /// The timeout argument is interpreted as a double value specifying the maximum number of seconds to block. A timeout of zero can be used to block indefinitely.
-    ///
-    fn convert_float_str_seconds_to_ms(timeout_str: &str) -> Result<u64> {
-        let timeout_as_sec = timeout_str
-            .parse::<f64>()
-            .with_context(|| "BLPOP 'timeout' must be a finite, non-negative numbe")?;
-
-        if !timeout_as_sec.is_finite() || timeout_as_sec < 0.0 {
-            anyhow::bail!("BLPOP 'timeout' must be a finite, non-negative number");
-        }
-
-        let timeout_in_ms = if timeout_as_sec == 0.0 {
-            u64::MAX
-        } else {
-            let millis = (timeout_as_sec * 1000.0).floor();
-            if millis > u64::MAX as f64 {
-                u64::MAX
-            } else {
-                millis as u64
-            }
-        };
-
-        Ok(timeout_in_ms)
-    }
-}
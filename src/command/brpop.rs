@@ -0,0 +1,156 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use futures::{StreamExt, stream::FuturesUnordered};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{ListRightBlockingPopStorage, StorageResponse};
+
+use super::{BlockedClientGuard, RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/brpop/
+/// Pops the tail of the first non-empty list, checking the keys in the order they were given.
+/// When they are all empty, waits on every key until one gets an element or the timeout expires:
+/// the keys may be on different shards, but a single element is ever popped.
+///
+#[derive(Debug)]
+pub struct BlockingRightPopCommand {
+    keys: Vec<String>,
+    timeout_in_ms: u64,
+}
+
+impl RedisCommand for BlockingRightPopCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // BRPOP key [key ...] timeout
+        if elements.len() < 3 {
+            return Err(anyhow!(
+                "Incomplete BRPOP command, expected at least 3 values: 'BRPOP key timeout'"
+            ));
+        }
+
+        let mut keys = Vec::new();
+
+        for single_argument in &elements[1..elements.len() - 1] {
+            if let RedisType::BulkString(key) = single_argument {
+                keys.push(bulk_str(key).into_owned());
+            } else {
+                return Err(anyhow!("BRPOP incorrect list key, not BulkString"));
+            }
+        }
+
+        if let Some(RedisType::BulkString(timeout_str)) = elements.last() {
+            let timeout_in_ms = super::parse_blocking_timeout_ms(&bulk_str(timeout_str), "BRPOP")?;
+
+            Ok(BlockingRightPopCommand {
+                keys,
+                timeout_in_ms,
+            })
+        } else {
+            Err(anyhow!("BRPOP incorrect 'timeout' argument"))
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let claim = Arc::new(AtomicBool::new(false));
+
+        // Lists that already have elements are served right away, in the order the keys were given
+        for key in &self.keys {
+            let request = ListRightBlockingPopStorage::new(key.clone(), false, Arc::clone(&claim));
+            let response = engine.execute(request).await;
+            if !matches!(response, Ok(StorageResponse::Null)) {
+                return write_reply(Some(response), output_buf, stream).await;
+            }
+        }
+
+        // Reported by INFO as blocked until this command returns
+        let _blocked_guard = BlockedClientGuard::new();
+
+        // Every list is empty: wait on all of them, the shared claim lets a single one pop
+        let mut waits: FuturesUnordered<_> = self
+            .keys
+            .iter()
+            .map(|key| {
+                engine.execute(ListRightBlockingPopStorage::new(
+                    key.clone(),
+                    true,
+                    Arc::clone(&claim),
+                ))
+            })
+            .collect();
+
+        let mut reply = timeout(
+            Duration::from_millis(self.timeout_in_ms),
+            next_reply(&mut waits),
+        )
+        .await
+        .unwrap_or(None);
+
+        // Taking the claim stops the shards from popping, unless one already claimed an element:
+        // its reply is then on the way, and must be sent rather than lost
+        if !matches!(reply, Some(Ok(StorageResponse::ValueFromList { .. })))
+            && claim.swap(true, Ordering::AcqRel)
+        {
+            reply = loop {
+                match next_reply(&mut waits).await {
+                    Some(Ok(response @ StorageResponse::ValueFromList { .. })) => {
+                        break Some(Ok(response));
+                    }
+                    Some(_) => continue,
+                    None => break None,
+                }
+            };
+        }
+
+        // Cancel the waits still pending
+        drop(waits);
+
+        write_reply(reply, output_buf, stream).await
+    }
+}
+
+/// First reply of the waits other than Null, which is sent by the keys that lost the claim.
+/// None once every wait replied.
+async fn next_reply(
+    waits: &mut FuturesUnordered<impl Future<Output = Result<StorageResponse>>>,
+) -> Option<Result<StorageResponse>> {
+    while let Some(reply) = waits.next().await {
+        if !matches!(reply, Ok(StorageResponse::Null)) {
+            return Some(reply);
+        }
+    }
+    None
+}
+
+/// None when nothing was popped before the timeout.
+async fn write_reply(
+    reply: Option<Result<StorageResponse>>,
+    output_buf: &mut BytesMut,
+    stream: &mut TcpStream,
+) -> Result<()> {
+    let reply = match reply {
+        // Array reply: the key from which the element was popped and the value of the popped element.
+        Some(Ok(StorageResponse::ValueFromList { value, list_name })) => RedisType::Array(vec![
+            RedisType::BulkString(list_name.into()),
+            RedisType::BulkString(value.into()),
+        ]),
+        Some(Ok(StorageResponse::Failed(msg))) => RedisType::SimpleError(msg),
+        Some(Ok(_)) => RedisType::SimpleError("Unknown error occurred during BRPOP".to_string()),
+        // Storage returned an error
+        Some(Err(e)) => RedisType::SimpleError(format!("BRPOP error: {e}")),
+        None => {
+            tracing::debug!("BRPOP timed out");
+            RedisType::NullArray
+        }
+    };
+
+    reply.write_resp_to_stream(output_buf, stream).await
+}
@@ -0,0 +1,127 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{
+    RedisType, RespVersion, bulk_str, with_resp_version,
+};
+
+use super::client_tracking::{TrackingOptions, disable_tracking, enable_tracking};
+
+///
+/// https://redis.io/docs/latest/commands/client-id/
+/// https://redis.io/docs/latest/commands/client-tracking/
+/// CLIENT ID replies with the ID of the connection, the same as reported by HELLO.
+/// CLIENT TRACKING ON|OFF [REDIRECT id] [BCAST] [PREFIX prefix ...] enables or disables client-side
+/// caching invalidation messages, see `client_tracking`. Without REDIRECT the messages are RESP3 pushes,
+/// so the connection must have switched to RESP3 with HELLO 3 first. OPTIN, OPTOUT and NOLOOP are not supported.
+///
+/// CLIENT works on the state of the connection, so like HELLO it is executed by the connection loop.
+///
+#[derive(Debug)]
+pub enum ClientCommand {
+    Id,
+    TrackingOn(TrackingOptions),
+    TrackingOff,
+}
+
+const PREFIX_WITHOUT_BCAST_ERROR: &str = "ERR PREFIX option requires BCAST mode to be enabled";
+
+const TRACKING_REQUIRES_RESP3_ERROR: &str =
+    "ERR Client tracking without REDIRECT requires RESP3, switch the protocol with HELLO 3";
+
+impl ClientCommand {
+    pub fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        let mut arguments = Vec::with_capacity(elements.len());
+        for element in elements {
+            let RedisType::BulkString(argument) = element else {
+                return Err(anyhow!("ERR syntax error"));
+            };
+            arguments.push(bulk_str(argument).into_owned());
+        }
+
+        let Some(subcommand) = arguments.get(1) else {
+            return Err(anyhow!(
+                "ERR wrong number of arguments for 'client' command"
+            ));
+        };
+
+        match (subcommand.to_uppercase().as_str(), &arguments[2..]) {
+            ("ID", []) => Ok(Self::Id),
+            ("TRACKING", [mode, options @ ..]) if mode.eq_ignore_ascii_case("ON") => {
+                Self::parse_tracking_options(options).map(Self::TrackingOn)
+            }
+            ("TRACKING", [mode, options @ ..]) if mode.eq_ignore_ascii_case("OFF") => {
+                // Options are checked as for ON, even though there is nothing to apply
+                Self::parse_tracking_options(options).map(|_| Self::TrackingOff)
+            }
+            ("TRACKING", [_, ..]) => Err(anyhow!("ERR syntax error")),
+            _ => Err(anyhow!(
+                "ERR unknown subcommand or wrong number of arguments for '{subcommand}'"
+            )),
+        }
+    }
+
+    fn parse_tracking_options(options: &[String]) -> Result<TrackingOptions> {
+        let mut redirect = None;
+        let mut bcast = false;
+        let mut prefixes = Vec::new();
+
+        let mut options = options.iter();
+        while let Some(option) = options.next() {
+            match option.to_uppercase().as_str() {
+                "REDIRECT" => {
+                    let client_id = options.next().ok_or(anyhow!("ERR syntax error"))?;
+                    let client_id = client_id
+                        .parse::<u64>()
+                        .map_err(|_| anyhow!("ERR Invalid client ID"))?;
+                    redirect = Some(client_id);
+                }
+                "BCAST" => bcast = true,
+                "PREFIX" => {
+                    let prefix = options.next().ok_or(anyhow!("ERR syntax error"))?;
+                    prefixes.push(prefix.clone());
+                }
+                _ => return Err(anyhow!("ERR syntax error")),
+            }
+        }
+
+        if !bcast && !prefixes.is_empty() {
+            return Err(anyhow!(PREFIX_WITHOUT_BCAST_ERROR));
+        }
+
+        Ok(TrackingOptions {
+            redirect,
+            bcast_prefixes: bcast.then_some(prefixes),
+        })
+    }
+
+    pub async fn execute(
+        &self,
+        client_id: u64,
+        resp_version: RespVersion,
+        output_buf: &mut BytesMut,
+        stream: &mut TcpStream,
+    ) -> Result<()> {
+        let reply = match self {
+            Self::Id => RedisType::Integer(client_id as i64),
+            Self::TrackingOn(options)
+                if options.redirect.is_none() && resp_version != RespVersion::Resp3 =>
+            {
+                RedisType::SimpleError(TRACKING_REQUIRES_RESP3_ERROR.to_string())
+            }
+            Self::TrackingOn(options) => match enable_tracking(client_id, options.clone()) {
+                Ok(()) => RedisType::SimpleString("OK".to_string()),
+                Err(error) => RedisType::SimpleError(error.to_string()),
+            },
+            Self::TrackingOff => {
+                disable_tracking(client_id);
+                RedisType::SimpleString("OK".to_string())
+            }
+        };
+
+        with_resp_version(resp_version, reply.write_resp_to_stream(output_buf, stream)).await
+    }
+}
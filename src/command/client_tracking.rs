@@ -0,0 +1,279 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{LazyLock, Mutex};
+
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+
+use super::command_table::{CommandSpec, find_command_spec};
+use super::upper_first_bulk_string;
+
+///
+/// Server side of client-side caching, enabled per connection with CLIENT TRACKING.
+/// https://redis.io/docs/latest/develop/reference/client-side-caching/
+///
+/// In the default mode the keys read by a tracking client are remembered, and the client is sent
+/// a single invalidation message once one of them is modified. In BCAST mode nothing is remembered:
+/// the client is sent an invalidation message for every modified key starting with one of its prefixes
+/// (every key without prefixes).
+///
+/// Keys are taken from the command table key positions by the connection: the keys of any command other
+/// than a `write` one are tracked before it is executed, the keys of a `write` command are invalidated after.
+/// Changes the connection can't know about are reported by the shard making them, through the storage
+/// keys-changed hook set to `invalidate_keys`: keys removed by expiration or DEBUG FLUSHALL, and the key
+/// a blocking pop eventually popped from.
+///
+/// Invalidation messages are queued on the connection they are sent to (the tracking one,
+/// or its REDIRECT target) and written out while it waits for its next request:
+/// - RESP3 push `invalidate [key ...]` to the tracking connection itself
+/// - `message __redis__:invalidate [key ...]`, as Pub/Sub would send it, to a REDIRECT target
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackingOptions {
+    pub redirect: Option<u64>,
+    /// `Some` in BCAST mode, with the PREFIX options (possibly none).
+    pub bcast_prefixes: Option<Vec<String>>,
+}
+
+const INVALIDATE_CHANNEL: &str = "__redis__:invalidate";
+
+const REDIRECT_NOT_FOUND_ERROR: &str = "ERR The client ID you want redirect to does not exist";
+
+// Number of connections with tracking enabled, commands skip the registry lock while it's 0.
+static TRACKING_CLIENTS: AtomicUsize = AtomicUsize::new(0);
+
+static REGISTRY: LazyLock<Mutex<TrackingRegistry>> =
+    LazyLock::new(|| Mutex::new(TrackingRegistry::default()));
+
+#[derive(Default)]
+struct TrackingRegistry {
+    // Every open connection, by client ID, with the queue of messages to send to it
+    connections: HashMap<u64, UnboundedSender<RedisType>>,
+    tracking: HashMap<u64, TrackingOptions>,
+    // Keys read by clients in the default mode, a client is forgotten once it was sent an invalidation
+    tracked_keys: HashMap<String, HashSet<u64>>,
+}
+
+impl TrackingRegistry {
+    fn stop_tracking(&mut self, client_id: u64) {
+        if self.tracking.remove(&client_id).is_some() {
+            TRACKING_CLIENTS.fetch_sub(1, Ordering::Relaxed);
+            self.tracked_keys.retain(|_, clients| {
+                clients.remove(&client_id);
+                !clients.is_empty()
+            });
+        }
+    }
+
+    fn invalidate(&mut self, keys: &[String]) {
+        let mut invalidated: HashMap<u64, Vec<String>> = HashMap::new();
+
+        for key in keys {
+            for client_id in self.tracked_keys.remove(key).unwrap_or_default() {
+                invalidated.entry(client_id).or_default().push(key.clone());
+            }
+
+            for (client_id, options) in &self.tracking {
+                if let Some(prefixes) = &options.bcast_prefixes
+                    && (prefixes.is_empty() || prefixes.iter().any(|p| key.starts_with(p.as_str())))
+                {
+                    invalidated.entry(*client_id).or_default().push(key.clone());
+                }
+            }
+        }
+
+        for (client_id, keys) in invalidated {
+            let Some(options) = self.tracking.get(&client_id) else {
+                continue;
+            };
+            let keys = RedisType::Array(
+                keys.into_iter()
+                    .map(|key| RedisType::BulkString(key.into()))
+                    .collect(),
+            );
+            let bulk = |value: &str| RedisType::BulkString(value.to_string().into());
+
+            let (target, message) = match options.redirect {
+                Some(target) => (
+                    target,
+                    RedisType::Push(vec![bulk("message"), bulk(INVALIDATE_CHANNEL), keys]),
+                ),
+                None => (client_id, RedisType::Push(vec![bulk("invalidate"), keys])),
+            };
+
+            // A REDIRECT target that went away just doesn't get the message
+            if let Some(sender) = self.connections.get(&target) {
+                let _ = sender.send(message);
+            }
+        }
+    }
+}
+
+fn registry() -> std::sync::MutexGuard<'static, TrackingRegistry> {
+    REGISTRY
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Registration of an open connection, it can be a REDIRECT target and receive invalidation messages.
+/// Dropping it (the connection is closed) also disables tracking for the connection.
+pub struct TrackingConnection {
+    client_id: u64,
+}
+
+impl TrackingConnection {
+    /// Returns the registration and the queue of invalidation messages to send on the connection.
+    pub fn register(client_id: u64) -> (Self, UnboundedReceiver<RedisType>) {
+        let (sender, receiver) = unbounded_channel();
+        registry().connections.insert(client_id, sender);
+        (Self { client_id }, receiver)
+    }
+}
+
+impl Drop for TrackingConnection {
+    fn drop(&mut self) {
+        let mut registry = registry();
+        registry.connections.remove(&self.client_id);
+        registry.stop_tracking(self.client_id);
+    }
+}
+
+/// CLIENT TRACKING ON: replaces the previous options of the client, and forgets the keys it read so far.
+pub fn enable_tracking(client_id: u64, options: TrackingOptions) -> Result<(), &'static str> {
+    let mut registry = registry();
+
+    if let Some(target) = options.redirect
+        && !registry.connections.contains_key(&target)
+    {
+        return Err(REDIRECT_NOT_FOUND_ERROR);
+    }
+
+    registry.stop_tracking(client_id);
+    registry.tracking.insert(client_id, options);
+    TRACKING_CLIENTS.fetch_add(1, Ordering::Relaxed);
+    Ok(())
+}
+
+/// CLIENT TRACKING OFF
+pub fn disable_tracking(client_id: u64) {
+    registry().stop_tracking(client_id);
+}
+
+/// Keys of `redis_type` with its command spec, from the command table key positions.
+/// None for blocking commands: only the shard knows which key a blocking pop popped from, so it reports it.
+fn command_keys(redis_type: &RedisType) -> Option<(&'static CommandSpec, Vec<String>)> {
+    let spec = upper_first_bulk_string(redis_type)
+        .as_deref()
+        .and_then(find_command_spec)?;
+    if spec.is_blocking() {
+        return None;
+    }
+    let RedisType::Array(elements) = redis_type else {
+        return None;
+    };
+    let keys: Vec<String> = spec
+        .key_positions(elements.len())
+        .filter_map(|position| match elements.get(position) {
+            Some(RedisType::BulkString(key)) => Some(bulk_str(key).into_owned()),
+            _ => None,
+        })
+        .collect();
+    (!keys.is_empty()).then_some((spec, keys))
+}
+
+/// Called before `client_id` executes `redis_type`: remembers the keys a read command is about to read.
+/// Registering them after the read would miss a write landing in between, and the client would keep
+/// a stale value that is never invalidated.
+pub fn track_read_keys(client_id: u64, redis_type: &RedisType) {
+    if TRACKING_CLIENTS.load(Ordering::Relaxed) == 0 {
+        return;
+    }
+    let Some((spec, keys)) = command_keys(redis_type) else {
+        return;
+    };
+    if spec.is_write() {
+        return;
+    }
+
+    let mut registry = registry();
+    if registry
+        .tracking
+        .get(&client_id)
+        .is_some_and(|options| options.bcast_prefixes.is_none())
+    {
+        for key in keys {
+            registry
+                .tracked_keys
+                .entry(key)
+                .or_default()
+                .insert(client_id);
+        }
+    }
+}
+
+/// Called once `redis_type` was executed: invalidates the keys of a write command.
+pub fn invalidate_written_keys(redis_type: &RedisType) {
+    if TRACKING_CLIENTS.load(Ordering::Relaxed) == 0 {
+        return;
+    }
+    if let Some((spec, keys)) = command_keys(redis_type)
+        && spec.is_write()
+    {
+        registry().invalidate(&keys);
+    }
+}
+
+/// Keys the shards removed or modified on their own (expiration, flush, blocking pops),
+/// set as the storage keys-changed hook at startup.
+pub fn invalidate_keys(keys: &[String]) {
+    if TRACKING_CLIENTS.load(Ordering::Relaxed) == 0 || keys.is_empty() {
+        return;
+    }
+
+    registry().invalidate(keys);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        TrackingConnection, TrackingOptions, enable_tracking, invalidate_keys, track_read_keys,
+    };
+    use crate::protocol::redis_serialization_protocol::RedisType;
+
+    fn command(args: &[&str]) -> RedisType {
+        RedisType::Array(
+            args.iter()
+                .map(|arg| RedisType::BulkString(arg.to_string().into()))
+                .collect(),
+        )
+    }
+
+    // A write landing while the read is executed must reach the reader, so keys are tracked up front
+    #[test]
+    fn read_keys_are_tracked_before_the_command_runs() {
+        let client_id = u64::MAX - 1;
+        let (_connection, mut messages) = TrackingConnection::register(client_id);
+        let options = TrackingOptions {
+            redirect: None,
+            bcast_prefixes: None,
+        };
+        enable_tracking(client_id, options).expect("enable tracking");
+
+        track_read_keys(client_id, &command(&["GET", "tracked-before-read"]));
+        invalidate_keys(&["tracked-before-read".to_string()]);
+
+        let RedisType::Push(message) = messages.try_recv().expect("invalidation message") else {
+            panic!("expected a push message");
+        };
+        assert_eq!(
+            message[1],
+            RedisType::Array(vec![RedisType::BulkString("tracked-before-read".into())])
+        );
+
+        // Write commands are not tracked, their keys are invalidated once executed
+        track_read_keys(client_id, &command(&["SET", "written", "v"]));
+        invalidate_keys(&["written".to_string()]);
+        assert!(messages.try_recv().is_err());
+    }
+}
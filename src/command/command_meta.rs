@@ -1,23 +1,169 @@
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use bytes::BytesMut;
 use tokio::net::TcpStream;
 
-use crate::protocol::redis_serialization_protocol::RedisType;
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
 
 use super::RedisCommand;
+use super::argument_spec::{Multiplicity, find_argument_spec};
+use super::command_table::{COMMAND_TABLE, CommandSpec, find_command_spec};
 
+///
+/// https://redis.io/docs/latest/commands/command/
+///
+/// COMMAND - minimal placeholder reply (compatibility)
+/// COMMAND INFO [command-name ...] - spec tuple per command, Null for unknown commands
+/// COMMAND DOCS [command-name ...] - docs per command, unknown commands are skipped
+/// COMMAND LIST - names of every supported command
+/// COMMAND COUNT - number of supported commands
+///
+/// Without names, INFO and DOCS report every supported command.
+///
 #[derive(Debug)]
-pub struct CommandCommand;
+pub enum CommandCommand {
+    Default,
+    Info(Vec<String>),
+    Docs(Vec<String>),
+    List,
+    Count,
+}
 
 impl RedisCommand for CommandCommand {
-    fn parse(_redis_type: &RedisType) -> Result<Self> {
-        Ok(Self)
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        let Some(subcommand) = elements.get(1) else {
+            return Ok(Self::Default);
+        };
+
+        let RedisType::BulkString(subcommand) = subcommand else {
+            return Err(anyhow!("COMMAND subcommand is not a BulkString"));
+        };
+        let subcommand = bulk_str(subcommand);
+
+        let names = elements[2..]
+            .iter()
+            .map(|element| match element {
+                RedisType::BulkString(name) => Ok(bulk_str(name).into_owned()),
+                _ => Err(anyhow!("COMMAND argument is not a BulkString")),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        match subcommand.to_uppercase().as_str() {
+            "INFO" => Ok(Self::Info(names)),
+            "DOCS" => Ok(Self::Docs(names)),
+            "LIST" | "COUNT" if !names.is_empty() => Err(anyhow!(
+                "ERR unknown subcommand or wrong number of arguments for '{subcommand}'"
+            )),
+            "LIST" => Ok(Self::List),
+            "COUNT" => Ok(Self::Count),
+            _ => Err(anyhow!("ERR unknown subcommand '{subcommand}'")),
+        }
     }
 
     async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
-        RedisType::Array(vec![])
-            .write_resp_to_stream(output_buf, stream)
-            .await?;
+        let reply = match self {
+            Self::Default => RedisType::Array(vec![]),
+            Self::Info(names) if names.is_empty() => {
+                RedisType::Array(COMMAND_TABLE.iter().map(Self::info_reply).collect())
+            }
+            Self::Info(names) => RedisType::Array(
+                names
+                    .iter()
+                    .map(|name| match find_command_spec(name) {
+                        Some(spec) => Self::info_reply(spec),
+                        None => RedisType::NullArray,
+                    })
+                    .collect(),
+            ),
+            Self::Docs(names) if names.is_empty() => {
+                RedisType::Array(COMMAND_TABLE.iter().flat_map(Self::docs_reply).collect())
+            }
+            Self::Docs(names) => RedisType::Array(
+                names
+                    .iter()
+                    .filter_map(|name| find_command_spec(name))
+                    .flat_map(Self::docs_reply)
+                    .collect(),
+            ),
+            Self::List => RedisType::Array(
+                COMMAND_TABLE
+                    .iter()
+                    .map(|spec| RedisType::BulkString(spec.name.into()))
+                    .collect(),
+            ),
+            Self::Count => RedisType::Integer(COMMAND_TABLE.len() as i64),
+        };
+
+        reply.write_resp_to_stream(output_buf, stream).await?;
         Ok(())
     }
 }
+
+impl CommandCommand {
+    /// `[name, arity, [flags...], first key, last key, step, [acl categories], [tips], [key specs], [subcommands]]`
+    fn info_reply(spec: &CommandSpec) -> RedisType {
+        RedisType::Array(vec![
+            RedisType::BulkString(spec.name.into()),
+            RedisType::Integer(spec.arity),
+            RedisType::Array(
+                spec.flags
+                    .iter()
+                    .map(|flag| RedisType::SimpleString(flag.to_string()))
+                    .collect(),
+            ),
+            RedisType::Integer(spec.first_key),
+            RedisType::Integer(spec.last_key),
+            RedisType::Integer(spec.step),
+            RedisType::Array(vec![]),
+            RedisType::Array(vec![]),
+            RedisType::Array(vec![]),
+            RedisType::Array(vec![]),
+        ])
+    }
+
+    /// RESP2 map encoding: the command name followed by a flat `[field, value, ...]` array.
+    /// Commands with an argument spec also list their `arguments`, each one a map of name, type and flags.
+    fn docs_reply(spec: &CommandSpec) -> [RedisType; 2] {
+        let mut docs = vec![
+            RedisType::BulkString("summary".into()),
+            RedisType::BulkString(spec.summary.into()),
+            RedisType::BulkString("group".into()),
+            RedisType::BulkString(spec.group.into()),
+        ];
+
+        if let Some(arguments) = find_argument_spec(spec.name) {
+            docs.push(RedisType::BulkString("arguments".into()));
+            docs.push(RedisType::Array(
+                arguments
+                    .iter()
+                    .map(|argument| {
+                        let flags = match argument.multiplicity {
+                            Multiplicity::Single => vec![],
+                            Multiplicity::OneOrMore => vec!["multiple"],
+                            Multiplicity::ZeroOrMore => vec!["optional", "multiple"],
+                        };
+                        RedisType::Array(vec![
+                            RedisType::BulkString("name".into()),
+                            RedisType::BulkString(argument.name.into()),
+                            RedisType::BulkString("type".into()),
+                            RedisType::BulkString(argument.kind.docs_type().into()),
+                            RedisType::BulkString("flags".into()),
+                            RedisType::Array(
+                                flags
+                                    .into_iter()
+                                    .map(|flag| RedisType::SimpleString(flag.to_string()))
+                                    .collect(),
+                            ),
+                        ])
+                    })
+                    .collect(),
+            ));
+        }
+
+        [
+            RedisType::BulkString(spec.name.into()),
+            RedisType::Array(docs),
+        ]
+    }
+}
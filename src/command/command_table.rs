@@ -0,0 +1,1004 @@
+/// Implementation `dispatch_and_execute` runs for a command, so the commands it serves are the ones of the table.
+/// Commands sharing an implementation (e.g. DEL and UNLINK) share a handler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Handler {
+    /// Changes the connection state, executed by the connection handler before any dispatch
+    Connection,
+    Ping,
+    Echo,
+    Command,
+    Set,
+    MSet,
+    MGet,
+    SetNx,
+    Get,
+    GetEx,
+    GetRange,
+    SetRange,
+    Append,
+    Ttl,
+    PTtl,
+    Expire,
+    Persist,
+    Del,
+    Exists,
+    Type,
+    Object,
+    Rename,
+    Incr,
+    Decr,
+    IncrBy,
+    DecrBy,
+    IncrByFloat,
+    RPush,
+    RPushX,
+    LPush,
+    LPushX,
+    LPop,
+    RPop,
+    LMove,
+    RPopLPush,
+    BlockingLeftPop,
+    BlockingRightPop,
+    LRange,
+    LLen,
+    LIndex,
+    LRem,
+    Info,
+    HSet,
+    HGet,
+    HMGet,
+    HExists,
+    HDel,
+    HashView,
+    HLen,
+    HIncrBy,
+    HIncrByFloat,
+    SAdd,
+    SRem,
+    SMembers,
+    SIsMember,
+    SCard,
+    SPop,
+    SRandMember,
+    SetOperation,
+    ZAdd,
+    ZScore,
+    ZRange,
+    ZRangeByScore,
+    ZCount,
+    ZPopMin,
+    ZPopMax,
+    XAdd,
+    XLen,
+    XRange,
+    Debug,
+}
+
+///
+/// Static metadata of every supported command, reported by COMMAND INFO / COMMAND DOCS.
+/// https://redis.io/docs/latest/develop/reference/command-tips/
+///
+/// `arity` follows the Redis convention: a positive value is the exact number of arguments
+/// (command name included), a negative value is the minimum number of arguments.
+/// `first_key`, `last_key` and `step` describe key positions, a negative `last_key` counts from the end.
+/// `handler` is what `dispatch_and_execute` runs for the command.
+///
+#[derive(Debug)]
+pub struct CommandSpec {
+    pub name: &'static str,
+    pub handler: Handler,
+    pub arity: i64,
+    pub flags: &'static [&'static str],
+    pub first_key: i64,
+    pub last_key: i64,
+    pub step: i64,
+    pub group: &'static str,
+    pub summary: &'static str,
+}
+
+impl CommandSpec {
+    /// Commands flagged `write` may modify the keyspace, they are rejected in read-only mode.
+    pub fn is_write(&self) -> bool {
+        self.flags.contains(&"write")
+    }
+
+    /// Positions of the keys in a request of `argc` arguments (command name included).
+    pub fn key_positions(&self, argc: usize) -> impl Iterator<Item = usize> {
+        let last_key = if self.last_key < 0 {
+            argc as i64 + self.last_key
+        } else {
+            self.last_key
+        };
+        let (first, last) = if self.first_key > 0 && self.step > 0 {
+            (self.first_key as usize, last_key.max(0) as usize)
+        } else {
+            (1, 0)
+        };
+        (first..=last).step_by(self.step.max(1) as usize)
+    }
+
+    /// Commands flagged `blocking` may wait indefinitely for another client before replying.
+    pub fn is_blocking(&self) -> bool {
+        self.flags.contains(&"blocking")
+    }
+}
+
+pub static COMMAND_TABLE: &[CommandSpec] = &[
+    CommandSpec {
+        name: "ping",
+        handler: Handler::Ping,
+        arity: -1,
+        flags: &["fast"],
+        first_key: 0,
+        last_key: 0,
+        step: 0,
+        group: "connection",
+        summary: "Returns the server's liveliness response.",
+    },
+    CommandSpec {
+        name: "auth",
+        handler: Handler::Connection,
+        arity: -2,
+        flags: &["fast", "no_auth"],
+        first_key: 0,
+        last_key: 0,
+        step: 0,
+        group: "connection",
+        summary: "Authenticates the connection.",
+    },
+    CommandSpec {
+        name: "hello",
+        handler: Handler::Connection,
+        arity: -1,
+        flags: &["fast", "no_auth"],
+        first_key: 0,
+        last_key: 0,
+        step: 0,
+        group: "connection",
+        summary: "Handshakes with the Redis server.",
+    },
+    CommandSpec {
+        name: "client",
+        handler: Handler::Connection,
+        arity: -2,
+        flags: &["loading", "stale"],
+        first_key: 0,
+        last_key: 0,
+        step: 0,
+        group: "connection",
+        summary: "A container for client connection commands.",
+    },
+    CommandSpec {
+        name: "echo",
+        handler: Handler::Echo,
+        arity: 2,
+        flags: &["fast"],
+        first_key: 0,
+        last_key: 0,
+        step: 0,
+        group: "connection",
+        summary: "Returns the given string.",
+    },
+    CommandSpec {
+        name: "command",
+        handler: Handler::Command,
+        arity: -1,
+        flags: &["loading", "stale"],
+        first_key: 0,
+        last_key: 0,
+        step: 0,
+        group: "server",
+        summary: "Returns detailed information about commands.",
+    },
+    CommandSpec {
+        name: "set",
+        handler: Handler::Set,
+        arity: -3,
+        flags: &["write", "denyoom"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "string",
+        summary: "Sets the string value of a key, ignoring its type. The key is created if it doesn't exist.",
+    },
+    CommandSpec {
+        name: "mset",
+        handler: Handler::MSet,
+        arity: -3,
+        flags: &["write", "denyoom"],
+        first_key: 1,
+        last_key: -1,
+        step: 2,
+        group: "string",
+        summary: "Atomically creates or modifies the string values of one or more keys.",
+    },
+    CommandSpec {
+        name: "mget",
+        handler: Handler::MGet,
+        arity: -2,
+        flags: &["readonly", "fast"],
+        first_key: 1,
+        last_key: -1,
+        step: 1,
+        group: "string",
+        summary: "Atomically returns the string values of one or more keys.",
+    },
+    CommandSpec {
+        name: "setnx",
+        handler: Handler::SetNx,
+        arity: 3,
+        flags: &["write", "denyoom", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "string",
+        summary: "Set the string value of a key only when the key doesn't exist.",
+    },
+    CommandSpec {
+        name: "get",
+        handler: Handler::Get,
+        arity: 2,
+        flags: &["readonly", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "string",
+        summary: "Returns the string value of a key.",
+    },
+    CommandSpec {
+        name: "getex",
+        handler: Handler::GetEx,
+        arity: -2,
+        flags: &["write", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "string",
+        summary: "Returns the string value of a key after setting its expiration time.",
+    },
+    CommandSpec {
+        name: "getrange",
+        handler: Handler::GetRange,
+        arity: 4,
+        flags: &["readonly"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "string",
+        summary: "Returns a substring of the string stored at a key.",
+    },
+    CommandSpec {
+        name: "setrange",
+        handler: Handler::SetRange,
+        arity: 4,
+        flags: &["write", "denyoom"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "string",
+        summary: "Overwrites a part of a string value with another by an offset. Creates the key if it doesn't exist.",
+    },
+    CommandSpec {
+        name: "append",
+        handler: Handler::Append,
+        arity: 3,
+        flags: &["write", "denyoom", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "string",
+        summary: "Appends a string to the value of a key. Creates the key if it doesn't exist.",
+    },
+    CommandSpec {
+        name: "ttl",
+        handler: Handler::Ttl,
+        arity: 2,
+        flags: &["readonly", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "generic",
+        summary: "Returns the expiration time in seconds of a key.",
+    },
+    CommandSpec {
+        name: "pttl",
+        handler: Handler::PTtl,
+        arity: 2,
+        flags: &["readonly", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "generic",
+        summary: "Returns the expiration time in milliseconds of a key.",
+    },
+    CommandSpec {
+        name: "expire",
+        handler: Handler::Expire,
+        arity: -3,
+        flags: &["write", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "generic",
+        summary: "Sets the expiration time of a key in seconds.",
+    },
+    CommandSpec {
+        name: "persist",
+        handler: Handler::Persist,
+        arity: 2,
+        flags: &["write", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "generic",
+        summary: "Removes the expiration time of a key.",
+    },
+    CommandSpec {
+        name: "del",
+        handler: Handler::Del,
+        arity: -2,
+        flags: &["write"],
+        first_key: 1,
+        last_key: -1,
+        step: 1,
+        group: "generic",
+        summary: "Deletes one or more keys.",
+    },
+    CommandSpec {
+        name: "unlink",
+        handler: Handler::Del,
+        arity: -2,
+        flags: &["write", "fast"],
+        first_key: 1,
+        last_key: -1,
+        step: 1,
+        group: "generic",
+        summary: "Asynchronously deletes one or more keys.",
+    },
+    CommandSpec {
+        name: "exists",
+        handler: Handler::Exists,
+        arity: -2,
+        flags: &["readonly", "fast"],
+        first_key: 1,
+        last_key: -1,
+        step: 1,
+        group: "generic",
+        summary: "Determines whether one or more keys exist.",
+    },
+    CommandSpec {
+        name: "type",
+        handler: Handler::Type,
+        arity: 2,
+        flags: &["readonly", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "generic",
+        summary: "Determines the type of value stored at a key.",
+    },
+    CommandSpec {
+        name: "object",
+        handler: Handler::Object,
+        arity: -2,
+        flags: &["readonly"],
+        first_key: 0,
+        last_key: 0,
+        step: 0,
+        group: "generic",
+        summary: "A container for object introspection commands.",
+    },
+    CommandSpec {
+        name: "rename",
+        handler: Handler::Rename,
+        arity: 3,
+        flags: &["write"],
+        first_key: 1,
+        last_key: 2,
+        step: 1,
+        group: "generic",
+        summary: "Renames a key and overwrites the destination.",
+    },
+    CommandSpec {
+        name: "renamenx",
+        handler: Handler::Rename,
+        arity: 3,
+        flags: &["write", "fast"],
+        first_key: 1,
+        last_key: 2,
+        step: 1,
+        group: "generic",
+        summary: "Renames a key only when the target key name doesn't exist.",
+    },
+    CommandSpec {
+        name: "incr",
+        handler: Handler::Incr,
+        arity: 2,
+        flags: &["write", "denyoom", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "string",
+        summary: "Increments the integer value of a key by one. Uses 0 as initial value if the key doesn't exist.",
+    },
+    CommandSpec {
+        name: "decr",
+        handler: Handler::Decr,
+        arity: 2,
+        flags: &["write", "denyoom", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "string",
+        summary: "Decrements the integer value of a key by one. Uses 0 as initial value if the key doesn't exist.",
+    },
+    CommandSpec {
+        name: "incrby",
+        handler: Handler::IncrBy,
+        arity: 3,
+        flags: &["write", "denyoom", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "string",
+        summary: "Increments the integer value of a key by a number. Uses 0 as initial value if the key doesn't exist.",
+    },
+    CommandSpec {
+        name: "decrby",
+        handler: Handler::DecrBy,
+        arity: 3,
+        flags: &["write", "denyoom", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "string",
+        summary: "Decrements a number from the integer value of a key. Uses 0 as initial value if the key doesn't exist.",
+    },
+    CommandSpec {
+        name: "incrbyfloat",
+        handler: Handler::IncrByFloat,
+        arity: 3,
+        flags: &["write", "denyoom", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "string",
+        summary: "Increment the floating point value of a key by a number. Uses 0 as initial value if the key doesn't exist.",
+    },
+    CommandSpec {
+        name: "rpush",
+        handler: Handler::RPush,
+        arity: -3,
+        flags: &["write", "denyoom", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "list",
+        summary: "Appends one or more elements to a list. Creates the key if it doesn't exist.",
+    },
+    CommandSpec {
+        name: "rpushx",
+        handler: Handler::RPushX,
+        arity: -3,
+        flags: &["write", "denyoom", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "list",
+        summary: "Appends one or more elements to a list only when the list exists.",
+    },
+    CommandSpec {
+        name: "lpush",
+        handler: Handler::LPush,
+        arity: -3,
+        flags: &["write", "denyoom", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "list",
+        summary: "Prepends one or more elements to a list. Creates the key if it doesn't exist.",
+    },
+    CommandSpec {
+        name: "lpushx",
+        handler: Handler::LPushX,
+        arity: -3,
+        flags: &["write", "denyoom", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "list",
+        summary: "Prepends one or more elements to a list only when the list exists.",
+    },
+    CommandSpec {
+        name: "lpop",
+        handler: Handler::LPop,
+        arity: -2,
+        flags: &["write", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "list",
+        summary: "Returns the first elements in a list after removing it. Deletes the list if the last element was popped.",
+    },
+    CommandSpec {
+        name: "rpop",
+        handler: Handler::RPop,
+        arity: -2,
+        flags: &["write", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "list",
+        summary: "Returns and removes the last elements of a list. Deletes the list if the last element was popped.",
+    },
+    CommandSpec {
+        name: "lmove",
+        handler: Handler::LMove,
+        arity: 5,
+        flags: &["write", "denyoom"],
+        first_key: 1,
+        last_key: 2,
+        step: 1,
+        group: "list",
+        summary: "Returns an element after popping it from one list and pushing it to another. Deletes the list if the last element was moved.",
+    },
+    CommandSpec {
+        name: "rpoplpush",
+        handler: Handler::RPopLPush,
+        arity: 3,
+        flags: &["write", "denyoom"],
+        first_key: 1,
+        last_key: 2,
+        step: 1,
+        group: "list",
+        summary: "Returns the last element of a list after removing and pushing it to another list. Deletes the list if the last element was popped.",
+    },
+    CommandSpec {
+        name: "blpop",
+        handler: Handler::BlockingLeftPop,
+        arity: -3,
+        flags: &["write", "blocking"],
+        first_key: 1,
+        last_key: -2,
+        step: 1,
+        group: "list",
+        summary: "Removes and returns the first element in a list. Blocks until an element is available otherwise.",
+    },
+    CommandSpec {
+        name: "brpop",
+        handler: Handler::BlockingRightPop,
+        arity: -3,
+        flags: &["write", "blocking"],
+        first_key: 1,
+        last_key: -2,
+        step: 1,
+        group: "list",
+        summary: "Removes and returns the last element in a list. Blocks until an element is available otherwise. Deletes the list if the last element was popped.",
+    },
+    CommandSpec {
+        name: "lrange",
+        handler: Handler::LRange,
+        arity: 4,
+        flags: &["readonly"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "list",
+        summary: "Returns a range of elements from a list.",
+    },
+    CommandSpec {
+        name: "llen",
+        handler: Handler::LLen,
+        arity: 2,
+        flags: &["readonly", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "list",
+        summary: "Returns the length of a list.",
+    },
+    CommandSpec {
+        name: "lindex",
+        handler: Handler::LIndex,
+        arity: 3,
+        flags: &["readonly"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "list",
+        summary: "Returns an element from a list by its index.",
+    },
+    CommandSpec {
+        name: "lrem",
+        handler: Handler::LRem,
+        arity: 4,
+        flags: &["write"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "list",
+        summary: "Removes elements from a list. Deletes the list if the last element was removed.",
+    },
+    CommandSpec {
+        name: "info",
+        handler: Handler::Info,
+        arity: -1,
+        flags: &["loading", "stale"],
+        first_key: 0,
+        last_key: 0,
+        step: 0,
+        group: "server",
+        summary: "Returns information and statistics about the server.",
+    },
+    CommandSpec {
+        name: "hset",
+        handler: Handler::HSet,
+        arity: -4,
+        flags: &["write", "denyoom", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "hash",
+        summary: "Creates or modifies the value of a field in a hash.",
+    },
+    CommandSpec {
+        name: "hget",
+        handler: Handler::HGet,
+        arity: 3,
+        flags: &["readonly", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "hash",
+        summary: "Returns the value of a field in a hash.",
+    },
+    CommandSpec {
+        name: "hmget",
+        handler: Handler::HMGet,
+        arity: -3,
+        flags: &["readonly", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "hash",
+        summary: "Returns the values of all fields in a hash.",
+    },
+    CommandSpec {
+        name: "hexists",
+        handler: Handler::HExists,
+        arity: 3,
+        flags: &["readonly", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "hash",
+        summary: "Determines whether a field exists in a hash.",
+    },
+    CommandSpec {
+        name: "hdel",
+        handler: Handler::HDel,
+        arity: -3,
+        flags: &["write", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "hash",
+        summary: "Deletes one or more fields and their values from a hash. Deletes the hash if no fields remain.",
+    },
+    CommandSpec {
+        name: "hgetall",
+        handler: Handler::HashView,
+        arity: 2,
+        flags: &["readonly"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "hash",
+        summary: "Returns all fields and values in a hash.",
+    },
+    CommandSpec {
+        name: "hkeys",
+        handler: Handler::HashView,
+        arity: 2,
+        flags: &["readonly"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "hash",
+        summary: "Returns all fields in a hash.",
+    },
+    CommandSpec {
+        name: "hvals",
+        handler: Handler::HashView,
+        arity: 2,
+        flags: &["readonly"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "hash",
+        summary: "Returns all values in a hash.",
+    },
+    CommandSpec {
+        name: "hlen",
+        handler: Handler::HLen,
+        arity: 2,
+        flags: &["readonly", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "hash",
+        summary: "Returns the number of fields in a hash.",
+    },
+    CommandSpec {
+        name: "hincrby",
+        handler: Handler::HIncrBy,
+        arity: 4,
+        flags: &["write", "denyoom", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "hash",
+        summary: "Increments the integer value of a field in a hash by a number. Uses 0 as initial value if the field doesn't exist.",
+    },
+    CommandSpec {
+        name: "hincrbyfloat",
+        handler: Handler::HIncrByFloat,
+        arity: 4,
+        flags: &["write", "denyoom", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "hash",
+        summary: "Increments the floating point value of a field by a number. Uses 0 as initial value if the field doesn't exist.",
+    },
+    CommandSpec {
+        name: "sadd",
+        handler: Handler::SAdd,
+        arity: -3,
+        flags: &["write", "denyoom", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "set",
+        summary: "Adds one or more members to a set. Creates the key if it doesn't exist.",
+    },
+    CommandSpec {
+        name: "srem",
+        handler: Handler::SRem,
+        arity: -3,
+        flags: &["write", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "set",
+        summary: "Removes one or more members from a set. Deletes the set if the last member was removed.",
+    },
+    CommandSpec {
+        name: "smembers",
+        handler: Handler::SMembers,
+        arity: 2,
+        flags: &["readonly"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "set",
+        summary: "Returns all members of a set.",
+    },
+    CommandSpec {
+        name: "sismember",
+        handler: Handler::SIsMember,
+        arity: 3,
+        flags: &["readonly", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "set",
+        summary: "Determines whether a member belongs to a set.",
+    },
+    CommandSpec {
+        name: "scard",
+        handler: Handler::SCard,
+        arity: 2,
+        flags: &["readonly", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "set",
+        summary: "Returns the number of members in a set.",
+    },
+    CommandSpec {
+        name: "spop",
+        handler: Handler::SPop,
+        arity: -2,
+        flags: &["write", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "set",
+        summary: "Returns one or more random members from a set after removing them. Deletes the set if the last member was popped.",
+    },
+    CommandSpec {
+        name: "srandmember",
+        handler: Handler::SRandMember,
+        arity: -2,
+        flags: &["readonly"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "set",
+        summary: "Gets one or multiple random members from a set.",
+    },
+    CommandSpec {
+        name: "sinter",
+        handler: Handler::SetOperation,
+        arity: -2,
+        flags: &["readonly"],
+        first_key: 1,
+        last_key: -1,
+        step: 1,
+        group: "set",
+        summary: "Returns the intersect of multiple sets.",
+    },
+    CommandSpec {
+        name: "sunion",
+        handler: Handler::SetOperation,
+        arity: -2,
+        flags: &["readonly"],
+        first_key: 1,
+        last_key: -1,
+        step: 1,
+        group: "set",
+        summary: "Returns the union of multiple sets.",
+    },
+    CommandSpec {
+        name: "sdiff",
+        handler: Handler::SetOperation,
+        arity: -2,
+        flags: &["readonly"],
+        first_key: 1,
+        last_key: -1,
+        step: 1,
+        group: "set",
+        summary: "Returns the difference of multiple sets.",
+    },
+    CommandSpec {
+        name: "zadd",
+        handler: Handler::ZAdd,
+        arity: -4,
+        flags: &["write", "denyoom", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "sorted-set",
+        summary: "Adds one or more members to a sorted set, or updates their scores. Creates the key if it doesn't exist.",
+    },
+    CommandSpec {
+        name: "zscore",
+        handler: Handler::ZScore,
+        arity: 3,
+        flags: &["readonly", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "sorted-set",
+        summary: "Returns the score of a member in a sorted set.",
+    },
+    CommandSpec {
+        name: "zrange",
+        handler: Handler::ZRange,
+        arity: -4,
+        flags: &["readonly"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "sorted-set",
+        summary: "Returns members in a sorted set within a range of indexes.",
+    },
+    CommandSpec {
+        name: "zrevrange",
+        handler: Handler::ZRange,
+        arity: -4,
+        flags: &["readonly"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "sorted-set",
+        summary: "Returns members in a sorted set within a range of indexes in reverse order.",
+    },
+    CommandSpec {
+        name: "zrangebyscore",
+        handler: Handler::ZRangeByScore,
+        arity: -4,
+        flags: &["readonly"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "sorted-set",
+        summary: "Returns members in a sorted set within a range of scores.",
+    },
+    CommandSpec {
+        name: "zcount",
+        handler: Handler::ZCount,
+        arity: 4,
+        flags: &["readonly", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "sorted-set",
+        summary: "Returns the count of members in a sorted set that have scores within a range.",
+    },
+    CommandSpec {
+        name: "zpopmin",
+        handler: Handler::ZPopMin,
+        arity: -2,
+        flags: &["write", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "sorted-set",
+        summary: "Returns the lowest-scoring members from a sorted set after removing them. Deletes the sorted set if the last member was popped.",
+    },
+    CommandSpec {
+        name: "zpopmax",
+        handler: Handler::ZPopMax,
+        arity: -2,
+        flags: &["write", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "sorted-set",
+        summary: "Returns the highest-scoring members from a sorted set after removing them. Deletes the sorted set if the last member was popped.",
+    },
+    CommandSpec {
+        name: "xadd",
+        handler: Handler::XAdd,
+        arity: -5,
+        flags: &["write", "denyoom", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "stream",
+        summary: "Appends a new message to a stream. Creates the key if it doesn't exist.",
+    },
+    CommandSpec {
+        name: "xlen",
+        handler: Handler::XLen,
+        arity: 2,
+        flags: &["readonly", "fast"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "stream",
+        summary: "Return the number of messages in a stream.",
+    },
+    CommandSpec {
+        name: "xrange",
+        handler: Handler::XRange,
+        arity: -4,
+        flags: &["readonly"],
+        first_key: 1,
+        last_key: 1,
+        step: 1,
+        group: "stream",
+        summary: "Returns the messages from a stream within a range of IDs.",
+    },
+    CommandSpec {
+        name: "debug",
+        handler: Handler::Debug,
+        arity: -2,
+        flags: &["admin", "noscript", "loading", "stale"],
+        first_key: 0,
+        last_key: 0,
+        step: 0,
+        group: "server",
+        summary: "A container for debugging commands.",
+    },
+];
+
+/// Case-insensitive lookup of a command by name.
+pub fn find_command_spec(name: &str) -> Option<&'static CommandSpec> {
+    COMMAND_TABLE
+        .iter()
+        .find(|spec| spec.name.eq_ignore_ascii_case(name))
+}
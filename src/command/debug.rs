@@ -0,0 +1,96 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{FlushStorage, NOT_AN_INTEGER_ERROR, ShardBlockStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/debug/
+/// Internal helpers for tests and diagnostics, not meant for regular clients.
+///
+/// DEBUG FLUSHALL - wipes every shard directly. Unlike a user-facing FLUSHALL it is meant for fast test resets
+/// and must never emit keyspace notifications or be persisted.
+///
+/// DEBUG SHARD-BLOCK key milliseconds - blocks the shard owning key for the given time, replies once it is over.
+/// Requests for keys on that shard queue up meanwhile, used to test head-of-line blocking between shards.
+///
+#[derive(Debug)]
+pub enum DebugCommand {
+    FlushAll,
+    ShardBlock { key: String, duration_ms: u64 },
+}
+
+impl RedisCommand for DebugCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // DEBUG subcommand [arguments]
+        let Some(RedisType::BulkString(subcommand)) = elements.get(1) else {
+            return Err(anyhow!("Not enough arguments for DEBUG command"));
+        };
+        let subcommand = bulk_str(subcommand);
+
+        match (subcommand.to_uppercase().as_str(), elements.len()) {
+            ("FLUSHALL", 2) => Ok(Self::FlushAll),
+            ("FLUSHALL", _) => Err(anyhow!("Wrong number of arguments for DEBUG FLUSHALL")),
+            ("SHARD-BLOCK", 4) => match (&elements[2], &elements[3]) {
+                (RedisType::BulkString(key), RedisType::BulkString(duration_ms)) => {
+                    Ok(Self::ShardBlock {
+                        key: bulk_str(key).into_owned(),
+                        duration_ms: bulk_str(duration_ms)
+                            .parse::<u64>()
+                            .map_err(|_| anyhow!(NOT_AN_INTEGER_ERROR))?,
+                    })
+                }
+                _ => Err(anyhow!("DEBUG SHARD-BLOCK arguments are not BulkStrings")),
+            },
+            ("SHARD-BLOCK", _) => Err(anyhow!("Wrong number of arguments for DEBUG SHARD-BLOCK")),
+            _ => Err(anyhow!("ERR unknown subcommand '{subcommand}'")),
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        match self {
+            Self::FlushAll => {
+                let engine = storage_engine()?;
+
+                for shard_idx in 0..engine.shard_count() {
+                    if let StorageResponse::Failed(msg) =
+                        engine.execute_on_shard(shard_idx, FlushStorage).await?
+                    {
+                        RedisType::SimpleError(msg)
+                            .write_resp_to_stream(output_buf, stream)
+                            .await?;
+                        return Ok(());
+                    }
+                }
+
+                RedisType::SimpleString("OK".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            Self::ShardBlock { key, duration_ms } => {
+                let engine = storage_engine()?;
+                let reply = match engine
+                    .execute(ShardBlockStorage {
+                        key: key.clone(),
+                        duration_ms: *duration_ms,
+                    })
+                    .await?
+                {
+                    StorageResponse::Success => RedisType::SimpleString("OK".to_string()),
+                    _ => RedisType::SimpleError(
+                        "Unknown error occurred during DEBUG SHARD-BLOCK".to_string(),
+                    ),
+                };
+
+                reply.write_resp_to_stream(output_buf, stream).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use futures::future::try_join_all;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{DeleteStorage, StorageResponse};
+
+use super::{RedisCommand, group_keys_by_shard, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/del/
+/// https://redis.io/docs/latest/commands/unlink/
+/// Removes the specified keys, a key is ignored if it does not exist.
+/// Returns the number of keys that were removed.
+///
+/// UNLINK is handled as an alias: values are dropped on the owning shard either way.
+/// Keys are grouped per owning shard and the per-shard removal counts are summed.
+/// Shards remove their keys independently, not atomically: while DEL runs, keys of one shard
+/// may already be gone when those of another shard are still readable.
+///
+#[derive(Debug)]
+pub struct DelCommand {
+    keys: Vec<String>,
+}
+
+impl RedisCommand for DelCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // DEL key [key ...]
+        if elements.len() < 2 {
+            return Err(anyhow!("Wrong number of arguments for DEL command"));
+        }
+
+        let keys = elements[1..]
+            .iter()
+            .map(|element| match element {
+                RedisType::BulkString(key) => Ok(bulk_str(key).into_owned()),
+                _ => Err(anyhow!("DEL argument is not a BulkString")),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { keys })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let groups = group_keys_by_shard(&engine, self.keys.iter().map(String::as_str));
+
+        let shard_requests = groups.into_iter().map(|(shard_idx, positions)| {
+            let engine = &engine;
+            let keys = positions
+                .iter()
+                .map(|&pos| self.keys[pos].clone())
+                .collect();
+            async move {
+                engine
+                    .execute_on_shard(shard_idx, DeleteStorage { keys })
+                    .await
+            }
+        });
+
+        let mut deleted = 0;
+        for resp in try_join_all(shard_requests).await? {
+            let StorageResponse::IntegerValue(count) = resp else {
+                RedisType::SimpleError("Unknown error occurred during DEL".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+                return Ok(());
+            };
+            deleted += count;
+        }
+
+        RedisType::Integer(deleted)
+            .write_resp_to_stream(output_buf, stream)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use anyhow::{Result, anyhow};
-use bytes::BytesMut;
+use bytes::{Bytes, BytesMut};
 use tokio::net::TcpStream;
 
 use crate::protocol::redis_serialization_protocol::RedisType;
@@ -8,7 +8,7 @@ use super::RedisCommand;
 
 #[derive(Debug)]
 pub struct EchoCommand {
-    argument: String,
+    argument: Bytes,
 }
 
 impl RedisCommand for EchoCommand {
@@ -0,0 +1,78 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use futures::future::try_join_all;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{ExistsStorage, StorageResponse};
+
+use super::{RedisCommand, group_keys_by_shard, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/exists/
+/// Returns the number of keys that exist from those specified as arguments.
+/// A key mentioned several times is counted several times, so `EXISTS k k` returns 2 if k exists.
+///
+/// Keys are grouped per owning shard and the per-shard counts are summed.
+/// Each shard counts at its own moment, so the total is not atomic when the keys span several shards.
+///
+#[derive(Debug)]
+pub struct ExistsCommand {
+    keys: Vec<String>,
+}
+
+impl RedisCommand for ExistsCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // EXISTS key [key ...]
+        if elements.len() < 2 {
+            return Err(anyhow!("Wrong number of arguments for EXISTS command"));
+        }
+
+        let keys = elements[1..]
+            .iter()
+            .map(|element| match element {
+                RedisType::BulkString(key) => Ok(bulk_str(key).into_owned()),
+                _ => Err(anyhow!("EXISTS argument is not a BulkString")),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { keys })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let groups = group_keys_by_shard(&engine, self.keys.iter().map(String::as_str));
+
+        let shard_requests = groups.into_iter().map(|(shard_idx, positions)| {
+            let engine = &engine;
+            let keys = positions
+                .iter()
+                .map(|&pos| self.keys[pos].clone())
+                .collect();
+            async move {
+                engine
+                    .execute_on_shard(shard_idx, ExistsStorage { keys })
+                    .await
+            }
+        });
+
+        let mut existing = 0;
+        for resp in try_join_all(shard_requests).await? {
+            let StorageResponse::IntegerValue(count) = resp else {
+                RedisType::SimpleError("Unknown error occurred during EXISTS".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+                return Ok(());
+            };
+            existing += count;
+        }
+
+        RedisType::Integer(existing)
+            .write_resp_to_stream(output_buf, stream)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{ExpireCondition, ExpireStorage, NOT_AN_INTEGER_ERROR, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/expire/
+/// Sets a timeout of `seconds` on key, replacing any previous one. A non-positive timeout deletes the key.
+/// The optional condition is checked against the current expiration of the key:
+/// - NX: set only when the key has no expiration.
+/// - XX: set only when the key already has an expiration.
+/// - GT: set only when the new expiration is greater than the current one.
+/// - LT: set only when the new expiration is less than the current one.
+///
+/// A key without expiration counts as an infinite time to live for GT and LT.
+/// Returns 1 if the timeout was set, 0 if the key does not exist or the condition was not met.
+///
+#[derive(Debug)]
+pub struct ExpireCommand {
+    key: String,
+    delay_ms: u64,
+    condition: Option<ExpireCondition>,
+}
+
+impl RedisCommand for ExpireCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // EXPIRE key seconds [NX | XX | GT | LT]
+        if !(3..=4).contains(&elements.len()) {
+            return Err(anyhow!("Wrong number of arguments for EXPIRE command"));
+        }
+
+        let (RedisType::BulkString(key), RedisType::BulkString(seconds)) =
+            (&elements[1], &elements[2])
+        else {
+            return Err(anyhow!("EXPIRE arguments are not BulkStrings"));
+        };
+
+        let seconds = bulk_str(seconds)
+            .parse::<i64>()
+            .map_err(|_| anyhow!(NOT_AN_INTEGER_ERROR))?;
+        let delay_ms = u64::try_from(seconds)
+            .unwrap_or(0)
+            .checked_mul(1000)
+            .ok_or_else(|| anyhow!("ERR invalid expire time in 'expire' command"))?;
+
+        let condition = match elements.get(3) {
+            None => None,
+            Some(RedisType::BulkString(option)) => match bulk_str(option).to_uppercase().as_str() {
+                "NX" => Some(ExpireCondition::NoExpiry),
+                "XX" => Some(ExpireCondition::HasExpiry),
+                "GT" => Some(ExpireCondition::GreaterThan),
+                "LT" => Some(ExpireCondition::LessThan),
+                _ => return Err(anyhow!("ERR Unsupported option {}", bulk_str(option))),
+            },
+            Some(_) => return Err(anyhow!("EXPIRE option is not a BulkString")),
+        };
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            delay_ms,
+            condition,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(ExpireStorage {
+                key: self.key.clone(),
+                delay_ms: self.delay_ms,
+                condition: self.condition,
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(applied) => {
+                RedisType::Integer(applied)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during EXPIRE".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
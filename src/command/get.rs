@@ -1,10 +1,11 @@
-use anyhow::{Result, anyhow};
+use anyhow::Result;
 use bytes::BytesMut;
 use tokio::net::TcpStream;
 
-use crate::protocol::redis_serialization_protocol::RedisType;
+use crate::protocol::redis_serialization_protocol::{RedisType, write_bulk_from_slice};
 use crate::storage::{GetStorage, StorageResponse};
 
+use super::argument_spec::bulk_arg;
 use super::{RedisCommand, storage_engine};
 
 #[derive(Debug)]
@@ -15,15 +16,11 @@ pub struct GetCommand {
 impl RedisCommand for GetCommand {
     fn parse(redis_type: &RedisType) -> Result<Self> {
         let elements = super::expect_cmd_array(redis_type)?;
-        if elements.len() < 2 {
-            return Err(anyhow!("No enough arguments for GET command"));
-        }
 
-        if let RedisType::BulkString(key) = &elements[1] {
-            Ok(Self { key: key.clone() })
-        } else {
-            Err(anyhow!("GET argument is not a BulkString"))
-        }
+        // GET key, checked against its argument spec during dispatch
+        Ok(Self {
+            key: bulk_arg(elements, 1)?.to_string(),
+        })
     }
 
     async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
@@ -40,8 +37,12 @@ impl RedisCommand for GetCommand {
                     .write_resp_to_stream(output_buf, stream)
                     .await?;
             }
-            StorageResponse::KeyValue { value } => {
-                RedisType::BulkString(value)
+            StorageResponse::StringValue { value } => {
+                // Values can grow large (APPEND, SETRANGE), write them without another copy
+                write_bulk_from_slice(&value, output_buf, stream).await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
                     .write_resp_to_stream(output_buf, stream)
                     .await?;
             }
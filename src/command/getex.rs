@@ -0,0 +1,90 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{ExpirationUpdate, GetExStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+const SYNTAX_ERROR: &str = "ERR syntax error";
+
+///
+/// https://redis.io/docs/latest/commands/getex/
+/// Get the value of key and optionally set its expiration. GETEX is similar to GET, but is a write command with additional options.
+/// GETEX key [EX seconds | PX milliseconds | EXAT unix-time-seconds | PXAT unix-time-milliseconds | PERSIST]
+///
+#[derive(Debug)]
+pub struct GetExCommand {
+    key: String,
+    expiration: ExpirationUpdate,
+}
+
+impl RedisCommand for GetExCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+        if elements.len() < 2 {
+            return Err(anyhow!("Not enough arguments for GETEX command"));
+        }
+
+        let RedisType::BulkString(key) = &elements[1] else {
+            return Err(anyhow!("GETEX arguments are not BulkString"));
+        };
+
+        // Only a single option is allowed, anything else is a syntax error.
+        let expiration = match &elements[2..] {
+            [] => ExpirationUpdate::Keep,
+            [RedisType::BulkString(arg)] if arg.eq_ignore_ascii_case(b"PERSIST") => {
+                ExpirationUpdate::Persist
+            }
+            [RedisType::BulkString(arg), RedisType::BulkString(arg_value)] => {
+                match super::parse_expiration_option(&bulk_str(arg), &bulk_str(arg_value), "getex")?
+                {
+                    Some(delay_in_ms) => ExpirationUpdate::ExpireInMs(delay_in_ms),
+                    None => return Err(anyhow!(SYNTAX_ERROR)),
+                }
+            }
+            _ => return Err(anyhow!(SYNTAX_ERROR)),
+        };
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            expiration,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(GetExStorage {
+                key: self.key.clone(),
+                expiration: self.expiration,
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::Null => {
+                RedisType::NullBulkString
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::StringValue { value } => {
+                RedisType::BulkString(value)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during GETEX".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{GetRangeStorage, NOT_AN_INTEGER_ERROR, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/getrange/
+/// Returns the substring of the string value stored at key, determined by the offsets start and end (both are inclusive).
+/// Negative offsets can be used in order to provide an offset starting from the end of the string.
+/// A missing key is interpreted as an empty string.
+///
+#[derive(Debug)]
+pub struct GetRangeCommand {
+    key: String,
+    start: i64,
+    end: i64,
+}
+
+impl RedisCommand for GetRangeCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // GETRANGE key start end
+        if elements.len() != 4 {
+            return Err(anyhow!("Wrong number of arguments for GETRANGE command"));
+        }
+
+        if let RedisType::BulkString(key) = &elements[1]
+            && let RedisType::BulkString(start_str) = &elements[2]
+            && let RedisType::BulkString(end_str) = &elements[3]
+        {
+            Ok(Self {
+                key: bulk_str(key).into_owned(),
+                start: bulk_str(start_str)
+                    .parse::<i64>()
+                    .map_err(|_| anyhow!(NOT_AN_INTEGER_ERROR))?,
+                end: bulk_str(end_str)
+                    .parse::<i64>()
+                    .map_err(|_| anyhow!(NOT_AN_INTEGER_ERROR))?,
+            })
+        } else {
+            Err(anyhow!(
+                "GETRANGE incorrect parameter types, expected BulkString, BulkString, BulkString"
+            ))
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(GetRangeStorage {
+                key: self.key.clone(),
+                start: self.start,
+                end: self.end,
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::StringValue { value } => {
+                RedisType::BulkString(value)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during GETRANGE".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{HashDeleteStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/hdel/
+/// Removes the specified fields from the hash stored at key, fields that do not exist are ignored.
+/// The key is deleted once its last field is removed.
+///
+/// Returns the number of fields that were removed, 0 when the key does not exist.
+///
+#[derive(Debug)]
+pub struct HDelCommand {
+    key: String,
+    fields: Vec<String>,
+}
+
+impl RedisCommand for HDelCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // HDEL key field [field ...]
+        if elements.len() < 3 {
+            return Err(anyhow!("Wrong number of arguments for HDEL command"));
+        }
+
+        let RedisType::BulkString(key) = &elements[1] else {
+            return Err(anyhow!("HDEL key is not a BulkString"));
+        };
+
+        let fields = elements[2..]
+            .iter()
+            .map(|element| match element {
+                RedisType::BulkString(field) => Ok(bulk_str(field).into_owned()),
+                _ => Err(anyhow!("HDEL field is not a BulkString")),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            fields,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(HashDeleteStorage {
+                key: self.key.clone(),
+                fields: self.fields.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(removed) => {
+                RedisType::Integer(removed)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during HDEL".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
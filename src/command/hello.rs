@@ -0,0 +1,126 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{
+    RedisType, RespVersion, bulk_str, with_resp_version,
+};
+
+use super::auth::check_password;
+
+///
+/// https://redis.io/docs/latest/commands/hello/
+/// Switches the reply protocol of the connection to `protover` (2 or 3) and returns a map of server metadata:
+/// server, version, proto, id, mode, role and modules. Without `protover` the protocol is left unchanged.
+/// Unsupported versions are rejected with NOPROTO and leave the connection as it is.
+///
+/// AUTH username password authenticates the connection first, like AUTH. When a password is configured,
+/// this is the only way to call HELLO on a connection that is not authenticated yet.
+///
+/// HELLO changes the state of the connection, so like AUTH it is executed by the connection loop.
+/// The reply is already encoded with the new protocol.
+///
+#[derive(Debug)]
+pub struct HelloCommand {
+    protover: Option<RespVersion>,
+    auth: Option<(String, String)>,
+}
+
+const NOPROTO_ERROR: &str = "NOPROTO unsupported protocol version";
+
+const HELLO_NOAUTH_ERROR: &str = "NOAUTH HELLO must be called with the client already authenticated, otherwise the HELLO <proto> AUTH <user> <pass> option can be used to authenticate the client and select the RESP protocol version at the same time";
+
+impl HelloCommand {
+    pub fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // HELLO [protover [AUTH username password]]
+        let Some(protover) = elements.get(1) else {
+            return Ok(Self {
+                protover: None,
+                auth: None,
+            });
+        };
+
+        let RedisType::BulkString(protover) = protover else {
+            return Err(anyhow!("HELLO protover is not a BulkString"));
+        };
+        let protover = bulk_str(protover)
+            .parse::<i64>()
+            .map_err(|_| anyhow!("ERR Protocol version is not an integer or out of range"))?;
+        let protover = RespVersion::from_protover(protover).ok_or(anyhow!(NOPROTO_ERROR))?;
+
+        let auth = match &elements[2..] {
+            [] => None,
+            [
+                RedisType::BulkString(option),
+                RedisType::BulkString(username),
+                RedisType::BulkString(password),
+            ] if option.eq_ignore_ascii_case(b"AUTH") => Some((
+                bulk_str(username).into_owned(),
+                bulk_str(password).into_owned(),
+            )),
+            [RedisType::BulkString(option), ..] => {
+                return Err(anyhow!(
+                    "ERR Syntax error in HELLO option '{}'",
+                    bulk_str(option)
+                ));
+            }
+            _ => return Err(anyhow!("ERR syntax error")),
+        };
+
+        Ok(Self {
+            protover: Some(protover),
+            auth,
+        })
+    }
+
+    /// Replies to the client, updating the authentication state and the protocol of the connection.
+    pub async fn execute(
+        &self,
+        requirepass: Option<&str>,
+        authenticated: &mut bool,
+        resp_version: &mut RespVersion,
+        client_id: u64,
+        output_buf: &mut BytesMut,
+        stream: &mut TcpStream,
+    ) -> Result<()> {
+        if let Some((username, password)) = &self.auth {
+            if let Err(error) = check_password(Some(username), password, requirepass) {
+                RedisType::SimpleError(error.to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+                return Ok(());
+            }
+            *authenticated = true;
+        }
+
+        if !*authenticated {
+            RedisType::SimpleError(HELLO_NOAUTH_ERROR.to_string())
+                .write_resp_to_stream(output_buf, stream)
+                .await?;
+            return Ok(());
+        }
+
+        if let Some(protover) = self.protover {
+            *resp_version = protover;
+        }
+
+        let bulk = |value: &str| RedisType::BulkString(value.to_string().into());
+        let reply = RedisType::Map(vec![
+            (bulk("server"), bulk("valkyrie")),
+            (bulk("version"), bulk(env!("CARGO_PKG_VERSION"))),
+            (bulk("proto"), RedisType::Integer(resp_version.protover())),
+            (bulk("id"), RedisType::Integer(client_id as i64)),
+            (bulk("mode"), bulk("standalone")),
+            (bulk("role"), bulk("master")),
+            (bulk("modules"), RedisType::Array(Vec::new())),
+        ]);
+
+        with_resp_version(
+            *resp_version,
+            reply.write_resp_to_stream(output_buf, stream),
+        )
+        .await
+    }
+}
@@ -0,0 +1,68 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{HashExistsStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/hexists/
+/// Returns 1 if field is an existing field in the hash stored at key,
+/// 0 if the field or the key does not exist.
+///
+#[derive(Debug)]
+pub struct HExistsCommand {
+    key: String,
+    field: String,
+}
+
+impl RedisCommand for HExistsCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // HEXISTS key field
+        if elements.len() != 3 {
+            return Err(anyhow!("Wrong number of arguments for HEXISTS command"));
+        }
+
+        match (&elements[1], &elements[2]) {
+            (RedisType::BulkString(key), RedisType::BulkString(field)) => Ok(Self {
+                key: bulk_str(key).into_owned(),
+                field: bulk_str(field).into_owned(),
+            }),
+            _ => Err(anyhow!("HEXISTS arguments are not BulkStrings")),
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(HashExistsStorage {
+                key: self.key.clone(),
+                field: self.field.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(exists) => {
+                RedisType::Integer(exists)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during HEXISTS".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
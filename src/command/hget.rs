@@ -0,0 +1,73 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{HashGetStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/hget/
+/// Returns the value associated with field in the hash stored at key,
+/// or nil when the field or the key does not exist.
+///
+#[derive(Debug)]
+pub struct HGetCommand {
+    key: String,
+    field: String,
+}
+
+impl RedisCommand for HGetCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // HGET key field
+        if elements.len() != 3 {
+            return Err(anyhow!("Wrong number of arguments for HGET command"));
+        }
+
+        match (&elements[1], &elements[2]) {
+            (RedisType::BulkString(key), RedisType::BulkString(field)) => Ok(Self {
+                key: bulk_str(key).into_owned(),
+                field: bulk_str(field).into_owned(),
+            }),
+            _ => Err(anyhow!("HGET arguments are not BulkStrings")),
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(HashGetStorage {
+                key: self.key.clone(),
+                field: self.field.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::KeyValue { value } => {
+                RedisType::BulkString(value.into())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Null => {
+                RedisType::NullBulkString
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during HGET".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{HashView, HashViewStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/hgetall/
+/// https://redis.io/docs/latest/commands/hkeys/
+/// https://redis.io/docs/latest/commands/hvals/
+/// Returns every field of the hash stored at key: HGETALL as alternating field and value,
+/// HKEYS only the fields and HVALS only the values. An empty array when key does not exist.
+///
+/// The order is unspecified, but HKEYS and HVALS of an unmodified hash list the fields in the same order.
+///
+#[derive(Debug)]
+pub struct HashViewCommand {
+    key: String,
+    view: HashView,
+}
+
+impl HashViewCommand {
+    fn name(&self) -> &'static str {
+        match self.view {
+            HashView::FieldsAndValues => "HGETALL",
+            HashView::Fields => "HKEYS",
+            HashView::Values => "HVALS",
+        }
+    }
+}
+
+impl RedisCommand for HashViewCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        let (view, name) = match super::upper_first_bulk_string(redis_type).as_deref() {
+            Some("HKEYS") => (HashView::Fields, "HKEYS"),
+            Some("HVALS") => (HashView::Values, "HVALS"),
+            _ => (HashView::FieldsAndValues, "HGETALL"),
+        };
+
+        // HGETALL key
+        if elements.len() != 2 {
+            return Err(anyhow!("Wrong number of arguments for {name} command"));
+        }
+
+        if let RedisType::BulkString(key) = &elements[1] {
+            Ok(Self {
+                key: bulk_str(key).into_owned(),
+                view,
+            })
+        } else {
+            Err(anyhow!("{name} key is not a BulkString"))
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(HashViewStorage {
+                key: self.key.clone(),
+                view: self.view,
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::ListValues { values } => {
+                let redis_values = values
+                    .into_iter()
+                    .map(|value| RedisType::BulkString(value.into()))
+                    .collect();
+
+                RedisType::Array(redis_values)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError(format!("Unknown error occurred during {}", self.name()))
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{HashIncrByStorage, NOT_AN_INTEGER_ERROR, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/hincrby/
+/// Increments the number stored at field in the hash stored at key by increment.
+/// A missing key or field is set to 0 before performing the operation. Returns the new value.
+/// An error is returned if the field holds a value that can not be represented as a 64-bit integer,
+/// or if the result would overflow.
+///
+#[derive(Debug)]
+pub struct HIncrByCommand {
+    key: String,
+    field: String,
+    delta: i64,
+}
+
+impl RedisCommand for HIncrByCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // HINCRBY key field increment
+        if elements.len() != 4 {
+            return Err(anyhow!("Wrong number of arguments for HINCRBY command"));
+        }
+
+        let (
+            RedisType::BulkString(key),
+            RedisType::BulkString(field),
+            RedisType::BulkString(arg_str),
+        ) = (&elements[1], &elements[2], &elements[3])
+        else {
+            return Err(anyhow!("HINCRBY arguments are not BulkStrings"));
+        };
+
+        let delta = bulk_str(arg_str)
+            .parse::<i64>()
+            .map_err(|_| anyhow!(NOT_AN_INTEGER_ERROR))?;
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            field: bulk_str(field).into_owned(),
+            delta,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(HashIncrByStorage {
+                key: self.key.clone(),
+                field: self.field.clone(),
+                delta: self.delta,
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(value) => {
+                RedisType::Integer(value)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during HINCRBY".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{
+    HashIncrByFloatStorage, IncrByFloatStorage, NOT_A_FLOAT_ERROR, StorageResponse,
+};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/hincrbyfloat/
+/// Increments the floating point number stored at field in the hash stored at key by increment.
+/// A missing key or field is set to 0 before performing the operation.
+/// The new value is returned as a bulk string.
+///
+#[derive(Debug)]
+pub struct HIncrByFloatCommand {
+    key: String,
+    field: String,
+    increment: f64,
+}
+
+impl RedisCommand for HIncrByFloatCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // HINCRBYFLOAT key field increment
+        if elements.len() != 4 {
+            return Err(anyhow!(
+                "Wrong number of arguments for HINCRBYFLOAT command"
+            ));
+        }
+
+        let (
+            RedisType::BulkString(key),
+            RedisType::BulkString(field),
+            RedisType::BulkString(increment_str),
+        ) = (&elements[1], &elements[2], &elements[3])
+        else {
+            return Err(anyhow!("HINCRBYFLOAT arguments are not BulkStrings"));
+        };
+
+        let increment = IncrByFloatStorage::parse_float(&bulk_str(increment_str))
+            .ok_or_else(|| anyhow!(NOT_A_FLOAT_ERROR))?;
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            field: bulk_str(field).into_owned(),
+            increment,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(HashIncrByFloatStorage {
+                key: self.key.clone(),
+                field: self.field.clone(),
+                increment: self.increment,
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::KeyValue { value } => {
+                RedisType::BulkString(value.into())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during HINCRBYFLOAT".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{HashLengthStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/hlen/
+/// Returns the number of fields contained in the hash stored at key, 0 when key does not exist.
+///
+#[derive(Debug)]
+pub struct HLenCommand {
+    key: String,
+}
+
+impl RedisCommand for HLenCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // HLEN key
+        if elements.len() != 2 {
+            return Err(anyhow!("Wrong number of arguments for HLEN command"));
+        }
+
+        if let RedisType::BulkString(key) = &elements[1] {
+            Ok(Self {
+                key: bulk_str(key).into_owned(),
+            })
+        } else {
+            Err(anyhow!("HLEN key is not a BulkString"))
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(HashLengthStorage {
+                key: self.key.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(length) => {
+                RedisType::Integer(length)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during HLEN".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{HashMultiGetStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/hmget/
+/// Returns the values associated with the specified fields in the hash stored at key,
+/// nil for every field that does not exist. A missing key reads as an empty hash.
+///
+#[derive(Debug)]
+pub struct HMGetCommand {
+    key: String,
+    fields: Vec<String>,
+}
+
+impl RedisCommand for HMGetCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // HMGET key field [field ...]
+        if elements.len() < 3 {
+            return Err(anyhow!("Wrong number of arguments for HMGET command"));
+        }
+
+        let RedisType::BulkString(key) = &elements[1] else {
+            return Err(anyhow!("HMGET key is not a BulkString"));
+        };
+
+        let fields = elements[2..]
+            .iter()
+            .map(|element| match element {
+                RedisType::BulkString(field) => Ok(bulk_str(field).into_owned()),
+                _ => Err(anyhow!("HMGET field is not a BulkString")),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            fields,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(HashMultiGetStorage {
+                key: self.key.clone(),
+                fields: self.fields.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::OptionalValues(values) => {
+                let values = values
+                    .into_iter()
+                    .map(|value| {
+                        value.map_or(RedisType::NullBulkString, |value| {
+                            RedisType::BulkString(value.into())
+                        })
+                    })
+                    .collect();
+
+                RedisType::Array(values)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during HMGET".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{HashSetStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/hset/
+/// Sets the specified fields to their respective values in the hash stored at key,
+/// creating the hash when key does not exist. Existing fields are overwritten.
+///
+/// Returns the number of fields that were added.
+///
+#[derive(Debug)]
+pub struct HSetCommand {
+    key: String,
+    fields: Vec<(String, String)>,
+}
+
+impl RedisCommand for HSetCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // HSET key field value [field value ...]
+        if elements.len() < 4 || elements.len() % 2 != 0 {
+            return Err(anyhow!("Wrong number of arguments for HSET command"));
+        }
+
+        let RedisType::BulkString(key) = &elements[1] else {
+            return Err(anyhow!("HSET key is not a BulkString"));
+        };
+
+        let fields = elements[2..]
+            .chunks_exact(2)
+            .map(|pair| match (&pair[0], &pair[1]) {
+                (RedisType::BulkString(field), RedisType::BulkString(value)) => {
+                    Ok((bulk_str(field).into_owned(), bulk_str(value).into_owned()))
+                }
+                _ => Err(anyhow!("HSET field or value is not a BulkString")),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            fields,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(HashSetStorage {
+                key: self.key.clone(),
+                fields: self.fields.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(added) => {
+                RedisType::Integer(added)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during HSET".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{IncrByStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/incr/
+/// Increments the number stored at key by one. If the key does not exist, it is set to 0 before performing the operation.
+/// An error is returned if the key contains a value of the wrong type or a string that can not be represented as a 64-bit integer.
+///
+#[derive(Debug)]
+pub struct IncrCommand {
+    key: String,
+}
+
+impl RedisCommand for IncrCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // INCR key
+        if elements.len() != 2 {
+            return Err(anyhow!("Wrong number of arguments for INCR command"));
+        }
+
+        if let RedisType::BulkString(key) = &elements[1] {
+            Ok(Self {
+                key: bulk_str(key).into_owned(),
+            })
+        } else {
+            Err(anyhow!("INCR argument is not a BulkString"))
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(IncrByStorage {
+                key: self.key.clone(),
+                delta: 1,
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(value) => {
+                RedisType::Integer(value)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during INCR".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{IncrByStorage, NOT_AN_INTEGER_ERROR, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/incrby/
+/// Increments the number stored at key by increment. If the key does not exist, it is set to 0 before performing the operation.
+/// An error is returned if the key contains a value of the wrong type, a string that can not be represented as a 64-bit integer,
+/// or if the result would overflow.
+///
+#[derive(Debug)]
+pub struct IncrByCommand {
+    key: String,
+    delta: i64,
+}
+
+impl RedisCommand for IncrByCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // INCRBY key increment
+        if elements.len() != 3 {
+            return Err(anyhow!("Wrong number of arguments for INCRBY command"));
+        }
+
+        let (RedisType::BulkString(key), RedisType::BulkString(arg_str)) =
+            (&elements[1], &elements[2])
+        else {
+            return Err(anyhow!(
+                "INCRBY incorrect parameter types, expected BulkString, BulkString"
+            ));
+        };
+
+        let delta = bulk_str(arg_str)
+            .parse::<i64>()
+            .map_err(|_| anyhow!(NOT_AN_INTEGER_ERROR))?;
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            delta,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(IncrByStorage {
+                key: self.key.clone(),
+                delta: self.delta,
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(value) => {
+                RedisType::Integer(value)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during INCRBY".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
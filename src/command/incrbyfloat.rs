@@ -0,0 +1,77 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{IncrByFloatStorage, NOT_A_FLOAT_ERROR, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/incrbyfloat/
+/// Increment the string representing a floating point number stored at key by the specified increment.
+/// If the key does not exist, it is set to 0 before performing the operation.
+/// The new value is returned as a bulk string.
+///
+#[derive(Debug)]
+pub struct IncrByFloatCommand {
+    key: String,
+    increment: f64,
+}
+
+impl RedisCommand for IncrByFloatCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // INCRBYFLOAT key increment
+        if elements.len() != 3 {
+            return Err(anyhow!("Wrong number of arguments for INCRBYFLOAT command"));
+        }
+
+        let (RedisType::BulkString(key), RedisType::BulkString(increment_str)) =
+            (&elements[1], &elements[2])
+        else {
+            return Err(anyhow!(
+                "INCRBYFLOAT incorrect parameter types, expected BulkString, BulkString"
+            ));
+        };
+
+        let increment = IncrByFloatStorage::parse_float(&bulk_str(increment_str))
+            .ok_or_else(|| anyhow!(NOT_A_FLOAT_ERROR))?;
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            increment,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(IncrByFloatStorage {
+                key: self.key.clone(),
+                increment: self.increment,
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::KeyValue { value } => {
+                RedisType::BulkString(value.into())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during INCRBYFLOAT".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
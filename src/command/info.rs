@@ -0,0 +1,63 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use std::fmt::Write;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+
+use super::{RedisCommand, blocked_clients};
+
+///
+/// https://redis.io/docs/latest/commands/info/
+/// Returns information and statistics about the server as a BulkString of
+/// `# Section` headers followed by `field:value` lines.
+/// Only the `clients` section is currently available.
+///
+#[derive(Debug)]
+pub struct InfoCommand {
+    section: Option<String>,
+}
+
+impl RedisCommand for InfoCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // INFO [section]
+        match elements.len() {
+            1 => Ok(Self { section: None }),
+            2 => {
+                if let RedisType::BulkString(section) = &elements[1] {
+                    Ok(Self {
+                        section: Some(bulk_str(section).to_lowercase()),
+                    })
+                } else {
+                    Err(anyhow!("INFO section is not a BulkString"))
+                }
+            }
+            _ => Err(anyhow!("Incorrect number of arguments for INFO command")),
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let mut info = String::new();
+
+        if self.includes_section("clients") {
+            info.push_str("# Clients\r\n");
+            write!(info, "blocked_clients:{}\r\n", blocked_clients())?;
+        }
+
+        RedisType::BulkString(info.into())
+            .write_resp_to_stream(output_buf, stream)
+            .await?;
+        Ok(())
+    }
+}
+
+impl InfoCommand {
+    fn includes_section(&self, name: &str) -> bool {
+        match self.section.as_deref() {
+            None | Some("all") | Some("default") | Some("everything") => true,
+            Some(section) => section == name,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{StorageResponse, TypeStorage};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/type/
+/// Returns the type of the value stored at key: string, list, stream, hash, set or zset, and none when key does not exist.
+///
+#[derive(Debug)]
+pub struct TypeCommand {
+    key: String,
+}
+
+impl RedisCommand for TypeCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // TYPE key
+        if elements.len() != 2 {
+            return Err(anyhow!("Wrong number of arguments for TYPE command"));
+        }
+
+        if let RedisType::BulkString(key) = &elements[1] {
+            Ok(Self {
+                key: bulk_str(key).into_owned(),
+            })
+        } else {
+            Err(anyhow!("TYPE key is not a BulkString"))
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(TypeStorage {
+                key: self.key.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::KeyValue { value } => {
+                RedisType::SimpleString(value)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during TYPE".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
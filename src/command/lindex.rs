@@ -0,0 +1,76 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{ListIndexStorage, NOT_AN_INTEGER_ERROR, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/lindex/
+/// Returns the element at index in the list stored at key. The index is zero-based,
+/// negative indices count from the tail (-1 is the last element).
+/// Returns nil when the index is out of range or the key does not exist.
+///
+#[derive(Debug)]
+pub struct LIndexCommand {
+    key: String,
+    index: i64,
+}
+
+impl RedisCommand for LIndexCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // LINDEX key index
+        if elements.len() != 3 {
+            return Err(anyhow!("Wrong number of arguments for LINDEX command"));
+        }
+
+        match (&elements[1], &elements[2]) {
+            (RedisType::BulkString(key), RedisType::BulkString(index)) => Ok(Self {
+                key: bulk_str(key).into_owned(),
+                index: bulk_str(index)
+                    .parse::<i64>()
+                    .map_err(|_| anyhow!(NOT_AN_INTEGER_ERROR))?,
+            }),
+            _ => Err(anyhow!("LINDEX arguments are not BulkStrings")),
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(ListIndexStorage {
+                key: self.key.clone(),
+                index: self.index,
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::KeyValue { value } => {
+                RedisType::BulkString(value.into())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Null => {
+                RedisType::NullBulkString
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during LINDEX".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
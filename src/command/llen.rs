@@ -2,7 +2,7 @@ use anyhow::{Result, anyhow};
 use bytes::BytesMut;
 use tokio::net::TcpStream;
 
-use crate::protocol::redis_serialization_protocol::RedisType;
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
 use crate::storage::{ListLengthStorage, StorageResponse};
 
 use super::{RedisCommand, storage_engine};
@@ -28,7 +28,9 @@ impl RedisCommand for LLenCommand {
         }
 
         if let RedisType::BulkString(key) = &elements[1] {
-            Ok(Self { key: key.clone() })
+            Ok(Self {
+                key: bulk_str(key).into_owned(),
+            })
         } else {
             Err(anyhow!("LLEN argument is not a BulkString"))
         }
@@ -44,7 +46,7 @@ impl RedisCommand for LLenCommand {
 
         match resp {
             StorageResponse::ListLength(len) => {
-                RedisType::Integer(len as i32)
+                RedisType::Integer(len as i64)
                     .write_resp_to_stream(output_buf, stream)
                     .await?;
             }
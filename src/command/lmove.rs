@@ -0,0 +1,205 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{
+    ListLeftPopStorage, ListLeftPushStorage, ListRightPopStorage, ListRightPushStorage,
+    StorageResponse, TypeStorage, WRONG_TYPE_ERROR,
+};
+
+use super::{RedisCommand, storage_engine};
+
+/// End of a list an element is popped from or pushed to.
+#[derive(Debug, Clone, Copy)]
+pub enum ListEnd {
+    Left,
+    Right,
+}
+
+impl ListEnd {
+    fn parse(value: &str) -> Result<Self> {
+        match value.to_uppercase().as_str() {
+            "LEFT" => Ok(Self::Left),
+            "RIGHT" => Ok(Self::Right),
+            _ => Err(anyhow!("ERR syntax error")),
+        }
+    }
+}
+
+///
+/// https://redis.io/docs/latest/commands/lmove/
+/// Pops an element from the `from` end of the list stored at source and pushes it to the `to` end
+/// of the list stored at destination. Returns the moved element, or nil when source does not exist.
+/// Source and destination may be the same key, which rotates the list (or leaves it as is when
+/// both ends are the same).
+///
+/// The two lists may live on different shards, so the element is popped from source and pushed to
+/// destination by two separate requests. The type of destination is checked before anything is
+/// popped, so a WRONGTYPE reply leaves source unchanged. The move is not atomic though: between
+/// the two requests the element is in neither list, and if another client stores a value of
+/// another type at destination meanwhile, the element is pushed back to the end of source it was
+/// popped from.
+///
+#[derive(Debug)]
+pub struct LMoveCommand {
+    source: String,
+    destination: String,
+    from: ListEnd,
+    to: ListEnd,
+}
+
+impl LMoveCommand {
+    pub fn new(source: String, destination: String, from: ListEnd, to: ListEnd) -> Self {
+        Self {
+            source,
+            destination,
+            from,
+            to,
+        }
+    }
+
+    /// Type name of the value at `key`, as reported by TYPE.
+    async fn key_type(key: &str) -> Result<String> {
+        let engine = storage_engine()?;
+        match engine
+            .execute(TypeStorage {
+                key: key.to_string(),
+            })
+            .await?
+        {
+            StorageResponse::KeyValue { value } => Ok(value),
+            _ => Err(anyhow!("Unknown error occurred during LMOVE")),
+        }
+    }
+
+    async fn pop(&self) -> Result<StorageResponse> {
+        let engine = storage_engine()?;
+        let key = self.source.clone();
+        match self.from {
+            ListEnd::Left => {
+                engine
+                    .execute(ListLeftPopStorage { key, count: None })
+                    .await
+            }
+            ListEnd::Right => {
+                engine
+                    .execute(ListRightPopStorage { key, count: None })
+                    .await
+            }
+        }
+    }
+
+    async fn push(key: &str, end: ListEnd, value: String) -> Result<StorageResponse> {
+        let engine = storage_engine()?;
+        let key = key.to_string();
+        let values = vec![value];
+        match end {
+            ListEnd::Left => {
+                engine
+                    .execute(ListLeftPushStorage {
+                        key,
+                        values,
+                        only_if_exists: false,
+                    })
+                    .await
+            }
+            ListEnd::Right => {
+                engine
+                    .execute(ListRightPushStorage {
+                        key,
+                        values,
+                        only_if_exists: false,
+                    })
+                    .await
+            }
+        }
+    }
+
+    /// Puts the popped element back to source when destination got another type after it was checked.
+    async fn restore_source(&self, value: String) -> Result<()> {
+        let resp = Self::push(&self.source, self.from, value).await?;
+
+        if !matches!(resp, StorageResponse::ListLength(_)) {
+            tracing::warn!("Failed to restore '{}' after LMOVE", self.source);
+        }
+        Ok(())
+    }
+}
+
+impl RedisCommand for LMoveCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // LMOVE source destination LEFT|RIGHT LEFT|RIGHT
+        if elements.len() != 5 {
+            return Err(anyhow!("Wrong number of arguments for LMOVE command"));
+        }
+
+        match (&elements[1], &elements[2], &elements[3], &elements[4]) {
+            (
+                RedisType::BulkString(source),
+                RedisType::BulkString(destination),
+                RedisType::BulkString(from),
+                RedisType::BulkString(to),
+            ) => Ok(Self::new(
+                bulk_str(source).into_owned(),
+                bulk_str(destination).into_owned(),
+                ListEnd::parse(&bulk_str(from))?,
+                ListEnd::parse(&bulk_str(to))?,
+            )),
+            _ => Err(anyhow!("LMOVE arguments are not BulkStrings")),
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let unknown_error =
+            || RedisType::SimpleError("Unknown error occurred during LMOVE".to_string());
+
+        // Like Redis, a missing source is reported before a destination of another type
+        if !matches!(
+            Self::key_type(&self.destination).await?.as_str(),
+            "list" | "none"
+        ) {
+            let reply = match Self::key_type(&self.source).await?.as_str() {
+                "none" => RedisType::NullBulkString,
+                _ => RedisType::SimpleError(WRONG_TYPE_ERROR.to_string()),
+            };
+            return reply.write_resp_to_stream(output_buf, stream).await;
+        }
+
+        let value = match self.pop().await? {
+            StorageResponse::KeyValue { value } => value,
+            StorageResponse::Null => {
+                return RedisType::NullBulkString
+                    .write_resp_to_stream(output_buf, stream)
+                    .await;
+            }
+            // The list pops only fail on a value of another type
+            StorageResponse::Failed(_) => {
+                return RedisType::SimpleError(WRONG_TYPE_ERROR.to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await;
+            }
+            _ => {
+                return unknown_error()
+                    .write_resp_to_stream(output_buf, stream)
+                    .await;
+            }
+        };
+
+        let reply = match Self::push(&self.destination, self.to, value.clone()).await? {
+            StorageResponse::ListLength(_) => RedisType::BulkString(value.into()),
+            StorageResponse::Failed(_) => {
+                self.restore_source(value).await?;
+                RedisType::SimpleError(WRONG_TYPE_ERROR.to_string())
+            }
+            _ => {
+                self.restore_source(value).await?;
+                unknown_error()
+            }
+        };
+
+        reply.write_resp_to_stream(output_buf, stream).await
+    }
+}
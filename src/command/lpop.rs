@@ -2,7 +2,7 @@ use anyhow::{Context, Result, anyhow};
 use bytes::BytesMut;
 use tokio::net::TcpStream;
 
-use crate::protocol::redis_serialization_protocol::RedisType;
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
 use crate::storage::{ListLeftPopStorage, StorageResponse};
 
 use super::{RedisCommand, storage_engine};
@@ -33,10 +33,10 @@ impl RedisCommand for LPopCommand {
             let count = if elements.len() >= 3 {
                 match &elements[2] {
                     RedisType::BulkString(count_str) => {
-                        let parsed = count_str.parse::<usize>().with_context(|| {
+                        let parsed = bulk_str(count_str).parse::<usize>().with_context(|| {
                             format!(
                                 "Failed to parse LPOP count parameter '{}' as unsigned integer",
-                                count_str
+                                bulk_str(count_str)
                             )
                         })?;
                         Some(parsed)
@@ -48,7 +48,7 @@ impl RedisCommand for LPopCommand {
             };
 
             Ok(Self {
-                key: key.clone(),
+                key: bulk_str(key).into_owned(),
                 count,
             })
         } else {
@@ -68,13 +68,18 @@ impl RedisCommand for LPopCommand {
         match resp {
             StorageResponse::KeyValue { value } => {
                 // Single element popped (no count provided)
-                RedisType::BulkString(value)
+                RedisType::BulkString(value.into())
                     .write_resp_to_stream(output_buf, stream)
                     .await?;
             }
             StorageResponse::ListValues { values } => {
                 // Count provided: return array of popped elements
-                let arr = RedisType::Array(values.into_iter().map(RedisType::BulkString).collect());
+                let arr = RedisType::Array(
+                    values
+                        .into_iter()
+                        .map(|value| RedisType::BulkString(value.into()))
+                        .collect(),
+                );
                 arr.write_resp_to_stream(output_buf, stream).await?;
             }
             StorageResponse::Null => {
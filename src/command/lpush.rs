@@ -2,7 +2,7 @@ use anyhow::{Result, anyhow};
 use bytes::BytesMut;
 use tokio::net::TcpStream;
 
-use crate::protocol::redis_serialization_protocol::RedisType;
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
 use crate::storage::{ListLeftPushStorage, StorageResponse};
 
 use super::{RedisCommand, storage_engine};
@@ -27,13 +27,13 @@ impl RedisCommand for LPushCommand {
             let mut values = Vec::new();
             for element in &elements[2..] {
                 match element {
-                    RedisType::BulkString(v) => values.push(v.clone()),
+                    RedisType::BulkString(v) => values.push(bulk_str(v).into_owned()),
                     RedisType::Integer(i) => values.push(i.to_string()),
                     _ => return Err(anyhow!("LPUSH argument is not BulkString or Integer")),
                 }
             }
             Ok(Self {
-                key: key.clone(),
+                key: bulk_str(key).into_owned(),
                 values,
             })
         } else {
@@ -47,12 +47,13 @@ impl RedisCommand for LPushCommand {
             .execute(ListLeftPushStorage {
                 key: self.key.clone(),
                 values: self.values.clone(),
+                only_if_exists: false,
             })
             .await?;
 
         match resp {
             StorageResponse::ListLength(len) => {
-                RedisType::Integer(len as i32)
+                RedisType::Integer(len as i64)
                     .write_resp_to_stream(output_buf, stream)
                     .await?;
             }
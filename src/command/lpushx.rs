@@ -0,0 +1,76 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{ListLeftPushStorage, StorageResponse, WRONG_TYPE_ERROR};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/lpushx/
+/// Same as LPUSH, but only pushes when key already holds a list and never creates it.
+/// Returns the new length of the list, or 0 when key does not exist.
+///
+#[derive(Debug)]
+pub struct LPushXCommand {
+    key: String,
+    values: Vec<String>,
+}
+
+impl RedisCommand for LPushXCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+        if elements.len() < 3 {
+            return Err(anyhow!("Not enough arguments for LPUSHX command"));
+        }
+
+        if let RedisType::BulkString(key) = &elements[1] {
+            let mut values = Vec::new();
+            for element in &elements[2..] {
+                match element {
+                    RedisType::BulkString(v) => values.push(bulk_str(v).into_owned()),
+                    RedisType::Integer(i) => values.push(i.to_string()),
+                    _ => return Err(anyhow!("LPUSHX argument is not BulkString or Integer")),
+                }
+            }
+            Ok(Self {
+                key: bulk_str(key).into_owned(),
+                values,
+            })
+        } else {
+            Err(anyhow!("LPUSHX key is not BulkString"))
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(ListLeftPushStorage {
+                key: self.key.clone(),
+                values: self.values.clone(),
+                only_if_exists: true,
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::ListLength(len) => {
+                RedisType::Integer(len as i64)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(_) => {
+                RedisType::SimpleError(WRONG_TYPE_ERROR.to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during LPUSHX".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
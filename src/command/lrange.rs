@@ -1,10 +1,11 @@
-use anyhow::{Context, Result, anyhow};
+use anyhow::Result;
 use bytes::BytesMut;
 use tokio::net::TcpStream;
 
 use crate::protocol::redis_serialization_protocol::RedisType;
 use crate::storage::{ListRangeStorage, StorageResponse};
 
+use super::argument_spec::{bulk_arg, integer_arg};
 use super::{RedisCommand, storage_engine};
 
 ///
@@ -16,43 +17,20 @@ use super::{RedisCommand, storage_engine};
 #[derive(Debug)]
 pub struct LRange {
     key: String,
-    start: i32,
-    end: i32,
+    start: i64,
+    end: i64,
 }
 
 impl RedisCommand for LRange {
     fn parse(redis_type: &RedisType) -> Result<Self> {
         let elements = super::expect_cmd_array(redis_type)?;
 
-        // LRANGE key start stop
-        if elements.len() < 4 {
-            return Err(anyhow!("Not enough arguments for LRANGE command"));
-        }
-
-        if let RedisType::BulkString(key) = &elements[1]
-            && let RedisType::BulkString(start_str) = &elements[2]
-            && let RedisType::BulkString(end_str) = &elements[3]
-        {
-            Ok(Self {
-                key: key.clone(),
-                start: start_str.parse::<i32>().with_context(|| {
-                    format!(
-                        "Failed to parse LRANGE start parameter '{}' as integer",
-                        start_str
-                    )
-                })?,
-                end: end_str.parse::<i32>().with_context(|| {
-                    format!(
-                        "Failed to parse LRANGE end parameter '{}' as integer",
-                        end_str
-                    )
-                })?,
-            })
-        } else {
-            Err(anyhow!(
-                "LRANGE incorrect parameter types, expected BulkString, BulkString, BulkString"
-            ))
-        }
+        // LRANGE key start stop, checked against its argument spec during dispatch
+        Ok(Self {
+            key: bulk_arg(elements, 1)?.to_string(),
+            start: integer_arg(elements, 2)?,
+            end: integer_arg(elements, 3)?,
+        })
     }
 
     async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
@@ -67,7 +45,10 @@ impl RedisCommand for LRange {
 
         match resp {
             StorageResponse::ListValues { values } => {
-                let redis_values = values.into_iter().map(RedisType::BulkString).collect();
+                let redis_values = values
+                    .into_iter()
+                    .map(|value| RedisType::BulkString(value.into()))
+                    .collect();
 
                 RedisType::Array(redis_values)
                     .write_resp_to_stream(output_buf, stream)
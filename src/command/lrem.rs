@@ -0,0 +1,81 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{ListRemoveStorage, NOT_AN_INTEGER_ERROR, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/lrem/
+/// Removes the first count occurrences of elements equal to element from the list stored at key.
+/// - count > 0: remove elements equal to element moving from head to tail.
+/// - count < 0: remove elements equal to element moving from tail to head.
+/// - count = 0: remove all elements equal to element.
+///
+/// Returns the number of removed elements, 0 when the key does not exist.
+///
+#[derive(Debug)]
+pub struct LRemCommand {
+    key: String,
+    count: i64,
+    value: String,
+}
+
+impl RedisCommand for LRemCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // LREM key count element
+        if elements.len() != 4 {
+            return Err(anyhow!("Wrong number of arguments for LREM command"));
+        }
+
+        match (&elements[1], &elements[2], &elements[3]) {
+            (
+                RedisType::BulkString(key),
+                RedisType::BulkString(count),
+                RedisType::BulkString(value),
+            ) => Ok(Self {
+                key: bulk_str(key).into_owned(),
+                count: bulk_str(count)
+                    .parse::<i64>()
+                    .map_err(|_| anyhow!(NOT_AN_INTEGER_ERROR))?,
+                value: bulk_str(value).into_owned(),
+            }),
+            _ => Err(anyhow!("LREM arguments are not BulkStrings")),
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(ListRemoveStorage {
+                key: self.key.clone(),
+                count: self.count,
+                value: self.value.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(removed) => {
+                RedisType::Integer(removed)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during LREM".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
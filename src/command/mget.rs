@@ -0,0 +1,93 @@
+use anyhow::{Result, anyhow};
+use bytes::{Bytes, BytesMut};
+use futures::future::try_join_all;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{MultiGetStorage, StorageResponse};
+
+use super::{RedisCommand, group_keys_by_shard, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/mget/
+/// Returns the values of all specified keys. For every key that does not hold a string value or does not exist, nil is returned.
+///
+/// Keys are grouped per owning shard, every shard answers its group with a single request and
+/// the values are put back in the order of the arguments.
+/// The reply is not an atomic snapshot: shards answer at different moments, so a concurrent
+/// MSET spanning several shards can be seen applied on some of them only.
+///
+#[derive(Debug)]
+pub struct MGetCommand {
+    keys: Vec<String>,
+}
+
+impl RedisCommand for MGetCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // MGET key [key ...]
+        if elements.len() < 2 {
+            return Err(anyhow!("Not enough arguments for MGET command"));
+        }
+
+        let keys = elements[1..]
+            .iter()
+            .map(|element| match element {
+                RedisType::BulkString(key) => Ok(bulk_str(key).into_owned()),
+                _ => Err(anyhow!("MGET argument is not a BulkString")),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { keys })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let groups = group_keys_by_shard(&engine, self.keys.iter().map(String::as_str));
+
+        let shard_requests = groups.into_iter().map(|(shard_idx, positions)| {
+            let engine = &engine;
+            let keys = positions
+                .iter()
+                .map(|&pos| self.keys[pos].clone())
+                .collect();
+            async move {
+                let resp = engine
+                    .execute_on_shard(shard_idx, MultiGetStorage { keys })
+                    .await?;
+                Ok::<_, anyhow::Error>((positions, resp))
+            }
+        });
+
+        let mut values: Vec<Option<Bytes>> = vec![None; self.keys.len()];
+
+        for (positions, resp) in try_join_all(shard_requests).await? {
+            let StorageResponse::OptionalStringValues(shard_values) = resp else {
+                RedisType::SimpleError("Unknown error occurred during MGET".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+                return Ok(());
+            };
+
+            for (pos, value) in positions.into_iter().zip(shard_values) {
+                values[pos] = value;
+            }
+        }
+
+        let values = values
+            .into_iter()
+            .map(|value| {
+                value.map_or(RedisType::NullBulkString, |value| {
+                    RedisType::BulkString(value)
+                })
+            })
+            .collect();
+
+        RedisType::Array(values)
+            .write_resp_to_stream(output_buf, stream)
+            .await?;
+
+        Ok(())
+    }
+}
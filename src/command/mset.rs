@@ -0,0 +1,82 @@
+use anyhow::{Result, anyhow};
+use bytes::{Bytes, BytesMut};
+use futures::future::try_join_all;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{MultiSetStorage, StorageResponse};
+
+use super::{RedisCommand, group_keys_by_shard, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/mset/
+/// Sets the given keys to their respective values, replacing existing values (and their expirations).
+///
+/// Keys are grouped per owning shard and every shard applies its group with a single request.
+/// Unlike Redis, the command is atomic per shard only: another client may observe a partially applied MSET
+/// for keys living on different shards.
+///
+#[derive(Debug)]
+pub struct MSetCommand {
+    pairs: Vec<(String, Bytes)>,
+}
+
+impl RedisCommand for MSetCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // MSET key value [key value ...]
+        if elements.len() < 3 || elements.len() % 2 == 0 {
+            return Err(anyhow!("Wrong number of arguments for MSET command"));
+        }
+
+        let pairs = elements[1..]
+            .chunks_exact(2)
+            .map(|pair| match (&pair[0], super::value_bytes(&pair[1])) {
+                (RedisType::BulkString(key), Some(value)) => {
+                    Ok((bulk_str(key).into_owned(), value))
+                }
+                _ => Err(anyhow!("MSET argument is not a BulkString")),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { pairs })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let groups = group_keys_by_shard(&engine, self.pairs.iter().map(|(key, _)| key.as_str()));
+
+        // Positions are kept in argument order, so a key repeated in the same call ends with its last value
+        let shard_requests = groups.into_iter().map(|(shard_idx, positions)| {
+            let engine = &engine;
+            let pairs = positions
+                .iter()
+                .map(|&pos| {
+                    let (key, value) = &self.pairs[pos];
+                    (key.clone(), value.clone())
+                })
+                .collect();
+            async move {
+                engine
+                    .execute_on_shard(shard_idx, MultiSetStorage { pairs })
+                    .await
+            }
+        });
+
+        for resp in try_join_all(shard_requests).await? {
+            if !matches!(resp, StorageResponse::Success) {
+                RedisType::SimpleError("Unknown error occurred during MSET".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+                return Ok(());
+            }
+        }
+
+        RedisType::SimpleString("OK".to_string())
+            .write_resp_to_stream(output_buf, stream)
+            .await?;
+
+        Ok(())
+    }
+}
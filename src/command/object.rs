@@ -0,0 +1,66 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{ObjectEncodingStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/object/
+/// Inspects the value stored at a key.
+///
+/// OBJECT ENCODING key - name of the internal representation of the value, Null when key does not exist.
+/// Names follow Redis for the closest representation used here: `int`, `embstr` or `raw` for strings,
+/// `quicklist` for lists, `hashtable` for hashes and sets, `skiplist` for sorted sets, `stream` for streams.
+///
+#[derive(Debug)]
+pub enum ObjectCommand {
+    Encoding { key: String },
+}
+
+impl RedisCommand for ObjectCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // OBJECT subcommand [arguments]
+        let Some(RedisType::BulkString(subcommand)) = elements.get(1) else {
+            return Err(anyhow!("Not enough arguments for OBJECT command"));
+        };
+        let subcommand = bulk_str(subcommand);
+
+        match (subcommand.to_uppercase().as_str(), elements.len()) {
+            ("ENCODING", 3) => match &elements[2] {
+                RedisType::BulkString(key) => Ok(Self::Encoding {
+                    key: bulk_str(key).into_owned(),
+                }),
+                _ => Err(anyhow!("OBJECT ENCODING key is not a BulkString")),
+            },
+            ("ENCODING", _) => Err(anyhow!("Wrong number of arguments for OBJECT ENCODING")),
+            _ => Err(anyhow!("ERR unknown subcommand '{subcommand}'")),
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        match self {
+            Self::Encoding { key } => {
+                let engine = storage_engine()?;
+                let reply = match engine
+                    .execute(ObjectEncodingStorage { key: key.clone() })
+                    .await?
+                {
+                    StorageResponse::KeyValue { value } => RedisType::BulkString(value.into()),
+                    StorageResponse::Null => RedisType::NullBulkString,
+                    _ => RedisType::SimpleError(
+                        "Unknown error occurred during OBJECT ENCODING".to_string(),
+                    ),
+                };
+
+                reply.write_resp_to_stream(output_buf, stream).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{PersistStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/persist/
+/// Removes the existing timeout on key, turning the key from volatile to persistent.
+/// Returns 1 if the timeout was removed, 0 if the key does not exist or has no associated timeout.
+///
+#[derive(Debug)]
+pub struct PersistCommand {
+    key: String,
+}
+
+impl RedisCommand for PersistCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // PERSIST key
+        if elements.len() != 2 {
+            return Err(anyhow!("Wrong number of arguments for PERSIST command"));
+        }
+
+        if let RedisType::BulkString(key) = &elements[1] {
+            Ok(Self {
+                key: bulk_str(key).into_owned(),
+            })
+        } else {
+            Err(anyhow!("PERSIST argument is not a BulkString"))
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(PersistStorage {
+                key: self.key.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(removed) => {
+                RedisType::Integer(removed)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during PERSIST".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
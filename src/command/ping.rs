@@ -1,5 +1,5 @@
 use anyhow::{Result, anyhow};
-use bytes::BytesMut;
+use bytes::{Bytes, BytesMut};
 use tokio::net::TcpStream;
 
 use crate::protocol::redis_serialization_protocol::RedisType;
@@ -10,7 +10,7 @@ use super::RedisCommand;
 
 #[derive(Debug)]
 pub struct PingCommand {
-    argument: Option<String>,
+    argument: Option<Bytes>,
 }
 
 impl RedisCommand for PingCommand {
@@ -0,0 +1,61 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{StorageResponse, TtlStorage};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/pttl/
+/// Returns the remaining time to live of a key that has a timeout, in milliseconds.
+/// Returns -2 if the key does not exist and -1 if the key exists but has no associated expire.
+///
+#[derive(Debug)]
+pub struct PTtlCommand {
+    key: String,
+}
+
+impl RedisCommand for PTtlCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // PTTL key
+        if elements.len() != 2 {
+            return Err(anyhow!("Wrong number of arguments for PTTL command"));
+        }
+
+        if let RedisType::BulkString(key) = &elements[1] {
+            Ok(Self {
+                key: bulk_str(key).into_owned(),
+            })
+        } else {
+            Err(anyhow!("PTTL argument is not a BulkString"))
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(TtlStorage {
+                key: self.key.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(ttl_ms) => {
+                RedisType::Integer(ttl_ms)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during PTTL".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use std::cell::RefCell;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{
+    ExistsStorage, RenamePutStorage, RenameTakeStorage, StorageResponse, StorageValue,
+};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/rename/
+/// https://redis.io/docs/latest/commands/renamenx/
+/// Renames source to destination, keeping its time to live. Returns an error when source does not exist.
+/// RENAME overwrites an existing destination and replies OK, RENAMENX replies 0 when destination
+/// already exists and 1 when the key was renamed.
+///
+/// Source and destination may live on different shards: the value is taken out of the source shard
+/// and stored into the destination shard by two separate requests. Another client can observe
+/// neither key in between, like any multi-shard command here this is not atomic.
+/// RENAMENX checks the destination first and leaves the source untouched when it exists. Only a
+/// destination written by another client between the check and the store makes it put the value back.
+///
+#[derive(Debug)]
+pub struct RenameCommand {
+    source: String,
+    destination: String,
+    only_if_absent: bool,
+}
+
+impl RenameCommand {
+    fn name(&self) -> &'static str {
+        if self.only_if_absent {
+            "RENAMENX"
+        } else {
+            "RENAME"
+        }
+    }
+
+    fn renamed_reply(&self) -> RedisType {
+        if self.only_if_absent {
+            RedisType::Integer(1)
+        } else {
+            RedisType::SimpleString("OK".to_string())
+        }
+    }
+
+    async fn key_exists(&self, key: &str) -> Result<bool> {
+        let engine = storage_engine()?;
+        match engine
+            .execute(ExistsStorage {
+                keys: vec![key.to_string()],
+            })
+            .await?
+        {
+            StorageResponse::IntegerValue(count) => Ok(count > 0),
+            _ => Err(anyhow!("Unknown error occurred during {}", self.name())),
+        }
+    }
+
+    /// Puts the value back under the source key when RENAMENX finds the destination taken after all.
+    /// A source written by another client meanwhile is kept, the renamed value is dropped then.
+    async fn restore_source(&self, value: StorageValue, ttl_ms: Option<u64>) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(RenamePutStorage {
+                key: self.source.clone(),
+                value: RefCell::new(Some(value)),
+                ttl_ms,
+                only_if_absent: true,
+            })
+            .await?;
+
+        if !matches!(resp, StorageResponse::IntegerValue(_)) {
+            tracing::warn!("Failed to restore '{}' after RENAMENX", self.source);
+        }
+        Ok(())
+    }
+}
+
+impl RedisCommand for RenameCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        let only_if_absent = matches!(
+            super::upper_first_bulk_string(redis_type).as_deref(),
+            Some("RENAMENX")
+        );
+        let name = if only_if_absent { "RENAMENX" } else { "RENAME" };
+
+        // RENAME key newkey
+        if elements.len() != 3 {
+            return Err(anyhow!("Wrong number of arguments for {name} command"));
+        }
+
+        match (&elements[1], &elements[2]) {
+            (RedisType::BulkString(source), RedisType::BulkString(destination)) => Ok(Self {
+                source: bulk_str(source).into_owned(),
+                destination: bulk_str(destination).into_owned(),
+                only_if_absent,
+            }),
+            _ => Err(anyhow!("{name} arguments are not BulkStrings")),
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let unknown_error =
+            || RedisType::SimpleError(format!("Unknown error occurred during {}", self.name()));
+        let no_such_key = || RedisType::SimpleError("ERR no such key".to_string());
+
+        // Renaming a key to itself only needs to check that it exists
+        if self.source == self.destination {
+            let reply = match engine
+                .execute(ExistsStorage {
+                    keys: vec![self.source.clone()],
+                })
+                .await?
+            {
+                StorageResponse::IntegerValue(0) => no_such_key(),
+                StorageResponse::IntegerValue(_) if self.only_if_absent => RedisType::Integer(0),
+                StorageResponse::IntegerValue(_) => self.renamed_reply(),
+                _ => unknown_error(),
+            };
+            return reply.write_resp_to_stream(output_buf, stream).await;
+        }
+
+        // RENAMENX onto an existing key must not take the source out, even for a moment
+        if self.only_if_absent && self.key_exists(&self.destination).await? {
+            let reply = if self.key_exists(&self.source).await? {
+                RedisType::Integer(0)
+            } else {
+                no_such_key()
+            };
+            return reply.write_resp_to_stream(output_buf, stream).await;
+        }
+
+        let (value, ttl_ms) = match engine
+            .execute(RenameTakeStorage {
+                key: self.source.clone(),
+            })
+            .await?
+        {
+            StorageResponse::TakenValue { value, ttl_ms } => (value, ttl_ms),
+            StorageResponse::Null => {
+                return no_such_key().write_resp_to_stream(output_buf, stream).await;
+            }
+            _ => {
+                return unknown_error()
+                    .write_resp_to_stream(output_buf, stream)
+                    .await;
+            }
+        };
+
+        let reply = match engine
+            .execute(RenamePutStorage {
+                key: self.destination.clone(),
+                value: RefCell::new(Some(value)),
+                ttl_ms,
+                only_if_absent: self.only_if_absent,
+            })
+            .await?
+        {
+            StorageResponse::IntegerValue(_) => self.renamed_reply(),
+            StorageResponse::TakenValue { value, ttl_ms } => {
+                self.restore_source(value, ttl_ms).await?;
+                RedisType::Integer(0)
+            }
+            _ => unknown_error(),
+        };
+
+        reply.write_resp_to_stream(output_buf, stream).await
+    }
+}
@@ -0,0 +1,48 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+
+use super::RedisCommand;
+use super::lmove::{LMoveCommand, ListEnd};
+
+///
+/// https://redis.io/docs/latest/commands/rpoplpush/
+/// Removes the last element of the list stored at source and pushes it to the head of the list
+/// stored at destination. Returns the moved element, or nil when source does not exist.
+/// Source and destination may be the same key, which rotates the list.
+///
+/// Same as `LMOVE source destination RIGHT LEFT`.
+///
+#[derive(Debug)]
+pub struct RPopLPushCommand {
+    inner: LMoveCommand,
+}
+
+impl RedisCommand for RPopLPushCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // RPOPLPUSH source destination
+        if elements.len() != 3 {
+            return Err(anyhow!("Wrong number of arguments for RPOPLPUSH command"));
+        }
+
+        match (&elements[1], &elements[2]) {
+            (RedisType::BulkString(source), RedisType::BulkString(destination)) => Ok(Self {
+                inner: LMoveCommand::new(
+                    bulk_str(source).into_owned(),
+                    bulk_str(destination).into_owned(),
+                    ListEnd::Right,
+                    ListEnd::Left,
+                ),
+            }),
+            _ => Err(anyhow!("RPOPLPUSH arguments are not BulkStrings")),
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        self.inner.execute(output_buf, stream).await
+    }
+}
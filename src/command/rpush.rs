@@ -1,10 +1,11 @@
-use anyhow::{Result, anyhow};
+use anyhow::Result;
 use bytes::BytesMut;
 use tokio::net::TcpStream;
 
-use crate::protocol::redis_serialization_protocol::RedisType;
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
 use crate::storage::{ListRightPushStorage, StorageResponse};
 
+use super::argument_spec::{bulk_arg, value_arg};
 use super::{RedisCommand, storage_engine};
 
 ///
@@ -20,28 +21,15 @@ impl RedisCommand for RPushCommand {
     fn parse(redis_type: &RedisType) -> Result<Self> {
         let elements = super::expect_cmd_array(redis_type)?;
 
-        // RPUSH key element [element ...]
-        if elements.len() < 3 {
-            return Err(anyhow!("Not enough arguments for RPUSH command"));
-        }
-
-        if let RedisType::BulkString(key) = &elements[1] {
-            let mut values = Vec::new();
-            for element in &elements[2..] {
-                match element {
-                    RedisType::BulkString(v) => values.push(v.clone()),
-                    RedisType::Integer(i) => values.push(i.to_string()),
-                    _ => return Err(anyhow!("RPUSH argument is not BulkString or Integer")),
-                }
-            }
+        // RPUSH key element [element ...], checked against its argument spec during dispatch
+        let values = (2..elements.len())
+            .map(|index| value_arg(elements, index).map(|value| bulk_str(&value).into_owned()))
+            .collect::<Result<Vec<_>>>()?;
 
-            Ok(Self {
-                key: key.clone(),
-                values,
-            })
-        } else {
-            Err(anyhow!("RPUSH key is not BulkString"))
-        }
+        Ok(Self {
+            key: bulk_arg(elements, 1)?.to_string(),
+            values,
+        })
     }
 
     async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
@@ -50,12 +38,13 @@ impl RedisCommand for RPushCommand {
             .execute(ListRightPushStorage {
                 key: self.key.clone(),
                 values: self.values.clone(),
+                only_if_exists: false,
             })
             .await?;
 
         match resp {
             StorageResponse::ListLength(len) => {
-                RedisType::Integer(len as i32)
+                RedisType::Integer(len as i64)
                     .write_resp_to_stream(output_buf, stream)
                     .await?;
             }
@@ -0,0 +1,79 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{ListRightPushStorage, StorageResponse, WRONG_TYPE_ERROR};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/rpushx/
+/// Same as RPUSH, but only pushes when key already holds a list and never creates it.
+/// Returns the new length of the list, or 0 when key does not exist.
+///
+#[derive(Debug)]
+pub struct RPushXCommand {
+    key: String,
+    values: Vec<String>,
+}
+
+impl RedisCommand for RPushXCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // RPUSHX key element [element ...]
+        if elements.len() < 3 {
+            return Err(anyhow!("Not enough arguments for RPUSHX command"));
+        }
+
+        if let RedisType::BulkString(key) = &elements[1] {
+            let mut values = Vec::new();
+            for element in &elements[2..] {
+                match element {
+                    RedisType::BulkString(v) => values.push(bulk_str(v).into_owned()),
+                    RedisType::Integer(i) => values.push(i.to_string()),
+                    _ => return Err(anyhow!("RPUSHX argument is not BulkString or Integer")),
+                }
+            }
+
+            Ok(Self {
+                key: bulk_str(key).into_owned(),
+                values,
+            })
+        } else {
+            Err(anyhow!("RPUSHX key is not BulkString"))
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(ListRightPushStorage {
+                key: self.key.clone(),
+                values: self.values.clone(),
+                only_if_exists: true,
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::ListLength(len) => {
+                RedisType::Integer(len as i64)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(_) => {
+                RedisType::SimpleError(WRONG_TYPE_ERROR.to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during RPUSHX".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{SetAddStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/sadd/
+/// Adds the specified members to the set stored at key, creating the set when key does not exist.
+/// Members which are already in the set are ignored.
+///
+/// Returns the number of members that were added.
+///
+#[derive(Debug)]
+pub struct SAddCommand {
+    key: String,
+    members: Vec<String>,
+}
+
+impl RedisCommand for SAddCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // SADD key member [member ...]
+        if elements.len() < 3 {
+            return Err(anyhow!("Wrong number of arguments for SADD command"));
+        }
+
+        let RedisType::BulkString(key) = &elements[1] else {
+            return Err(anyhow!("SADD key is not a BulkString"));
+        };
+
+        let members = elements[2..]
+            .iter()
+            .map(|element| match element {
+                RedisType::BulkString(member) => Ok(bulk_str(member).into_owned()),
+                _ => Err(anyhow!("SADD member is not a BulkString")),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            members,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(SetAddStorage {
+                key: self.key.clone(),
+                members: self.members.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(added) => {
+                RedisType::Integer(added)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during SADD".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,56 +1,62 @@
-use anyhow::{Context, Result, anyhow};
-use bytes::BytesMut;
+use anyhow::{Result, anyhow};
+use bytes::{Bytes, BytesMut};
 use tokio::net::TcpStream;
 
-use crate::protocol::redis_serialization_protocol::RedisType;
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
 use crate::storage::{SetStorage, StorageResponse};
 
+use super::argument_spec::{bulk_arg, value_arg};
 use super::{RedisCommand, storage_engine};
 
+const SYNTAX_ERROR: &str = "ERR syntax error";
+
 #[derive(Debug)]
 pub struct SetCommand {
     key: String,
-    value: String,
-    expiration_in_ms: u64,
+    value: Bytes,
+    expiration_in_ms: Option<u64>,
 }
 
 impl RedisCommand for SetCommand {
     fn parse(redis_type: &RedisType) -> Result<Self> {
         let elements = super::expect_cmd_array(redis_type)?;
-        if elements.len() < 3 {
-            return Err(anyhow!("Not enough arguments for SET command"));
-        }
 
-        if let RedisType::BulkString(key) = &elements[1]
-            && let RedisType::BulkString(value) = &elements[2]
-        {
-            let mut expiration_in_ms = 0_u64;
+        // SET key value [option ...], checked against its argument spec during dispatch
+        let key = bulk_arg(elements, 1)?;
+        let value = value_arg(elements, 2)?;
+
+        let mut expiration_in_ms = None;
+
+        // Optional EX seconds / PX milliseconds / EXAT unix-seconds / PXAT unix-milliseconds.
+        // Only a single expiration option is allowed, anything else is a syntax error.
+        let mut idx = 3;
+        while idx < elements.len() {
+            let (RedisType::BulkString(arg), Some(RedisType::BulkString(arg_value))) =
+                (&elements[idx], elements.get(idx + 1))
+            else {
+                return Err(anyhow!(SYNTAX_ERROR));
+            };
 
-            // Optional EX seconds / PX milliseconds
-            if elements.len() >= 5
-                && let (RedisType::BulkString(arg), RedisType::BulkString(arg_value)) =
-                    (&elements[3], &elements[4])
-            {
-                if arg.eq_ignore_ascii_case("EX") {
-                    expiration_in_ms = 1000
-                        * arg_value.parse::<u64>().with_context(|| {
-                            format!("Can't convert EX value '{arg_value}' to number")
-                        })?;
-                } else if arg.eq_ignore_ascii_case("PX") {
-                    expiration_in_ms = arg_value.parse::<u64>().with_context(|| {
-                        format!("Can't convert PX value '{arg_value}' to number")
-                    })?;
-                }
+            if expiration_in_ms.is_some() {
+                return Err(anyhow!(SYNTAX_ERROR));
             }
 
-            Ok(Self {
-                key: key.clone(),
-                value: value.clone(),
-                expiration_in_ms,
-            })
-        } else {
-            Err(anyhow!("SET arguments are not BulkString"))
+            let Some(delay_in_ms) =
+                super::parse_expiration_option(&bulk_str(arg), &bulk_str(arg_value), "set")?
+            else {
+                return Err(anyhow!(SYNTAX_ERROR));
+            };
+
+            // Absolute unix time in the past gives 0, which expires the key right away
+            expiration_in_ms = Some(delay_in_ms);
+            idx += 2;
         }
+
+        Ok(Self {
+            key: key.into_owned(),
+            value,
+            expiration_in_ms,
+        })
     }
 
     async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
@@ -0,0 +1,73 @@
+use anyhow::{Result, anyhow};
+use bytes::{Bytes, BytesMut};
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{SetNxStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/setnx/
+/// Set key to hold string value if key does not exist. When key already holds a value (of any type), no operation is performed.
+/// Returns 1 if the key was set, 0 if the key was not set.
+///
+#[derive(Debug)]
+pub struct SetNxCommand {
+    key: String,
+    value: Bytes,
+}
+
+impl RedisCommand for SetNxCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // SETNX key value
+        if elements.len() != 3 {
+            return Err(anyhow!("Wrong number of arguments for SETNX command"));
+        }
+
+        if let RedisType::BulkString(key) = &elements[1]
+            && let Some(value) = super::value_bytes(&elements[2])
+        {
+            Ok(Self {
+                key: bulk_str(key).into_owned(),
+                value,
+            })
+        } else {
+            Err(anyhow!(
+                "SETNX incorrect parameter types, expected BulkString, BulkString or Integer"
+            ))
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(SetNxStorage {
+                key: self.key.clone(),
+                value: self.value.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(is_set) => {
+                RedisType::Integer(is_set)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during SETNX".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use anyhow::{Result, anyhow};
+use bytes::{Bytes, BytesMut};
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{NOT_AN_INTEGER_ERROR, SetRangeStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+// Same limit as the Redis default `proto-max-bulk-len`
+const MAX_STRING_SIZE: usize = 512 * 1024 * 1024;
+
+///
+/// https://redis.io/docs/latest/commands/setrange/
+/// Overwrites part of the string stored at key, starting at the specified offset, for the entire length of value.
+/// If the offset is larger than the current length of the string, the string is padded with zero-bytes.
+/// Non-existing keys are considered as empty strings. Setting an empty value is a no-op which returns the current length.
+///
+#[derive(Debug)]
+pub struct SetRangeCommand {
+    key: String,
+    offset: usize,
+    value: Bytes,
+}
+
+impl RedisCommand for SetRangeCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // SETRANGE key offset value
+        if elements.len() != 4 {
+            return Err(anyhow!("Wrong number of arguments for SETRANGE command"));
+        }
+
+        if let RedisType::BulkString(key) = &elements[1]
+            && let RedisType::BulkString(offset_str) = &elements[2]
+            && let Some(value) = super::value_bytes(&elements[3])
+        {
+            let offset = bulk_str(offset_str)
+                .parse::<i64>()
+                .map_err(|_| anyhow!(NOT_AN_INTEGER_ERROR))?;
+
+            if offset < 0 {
+                return Err(anyhow!("ERR offset is out of range"));
+            }
+
+            let offset = offset as usize;
+            if !value.is_empty() && offset.saturating_add(value.len()) > MAX_STRING_SIZE {
+                return Err(anyhow!(
+                    "ERR string exceeds maximum allowed size (proto-max-bulk-len)"
+                ));
+            }
+
+            Ok(Self {
+                key: bulk_str(key).into_owned(),
+                offset,
+                value,
+            })
+        } else {
+            Err(anyhow!(
+                "SETRANGE incorrect parameter types, expected BulkString, BulkString, BulkString"
+            ))
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(SetRangeStorage {
+                key: self.key.clone(),
+                offset: self.offset,
+                value: self.value.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(len) => {
+                RedisType::Integer(len)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during SETRANGE".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
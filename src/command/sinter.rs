@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use futures::future::try_join_all;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{SetMembersStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+/// Set algebra applied to the operand sets, in argument order.
+#[derive(Debug, Clone, Copy)]
+pub enum SetOperation {
+    Intersection,
+    Union,
+    Difference,
+}
+
+///
+/// https://redis.io/docs/latest/commands/sinter/
+/// https://redis.io/docs/latest/commands/sunion/
+/// https://redis.io/docs/latest/commands/sdiff/
+/// Returns the members of the intersection (SINTER), the union (SUNION) or the difference between
+/// the first set and all the following ones (SDIFF), in unspecified order.
+/// Keys that do not exist are considered to be empty sets.
+///
+/// The operand sets may live on different shards, so each one is fetched with its own request and the
+/// result is computed here. The sets are read at different moments, the result is not an atomic snapshot.
+///
+#[derive(Debug)]
+pub struct SetOperationCommand {
+    keys: Vec<String>,
+    operation: SetOperation,
+}
+
+impl SetOperationCommand {
+    fn name(&self) -> &'static str {
+        match self.operation {
+            SetOperation::Intersection => "SINTER",
+            SetOperation::Union => "SUNION",
+            SetOperation::Difference => "SDIFF",
+        }
+    }
+
+    fn combine(&self, sets: Vec<Vec<String>>) -> Vec<String> {
+        let mut sets = sets.into_iter();
+        let mut result: HashSet<String> = sets.next().unwrap_or_default().into_iter().collect();
+
+        for members in sets {
+            match self.operation {
+                SetOperation::Intersection => {
+                    let members: HashSet<String> = members.into_iter().collect();
+                    result.retain(|member| members.contains(member));
+                }
+                SetOperation::Union => result.extend(members),
+                SetOperation::Difference => {
+                    for member in &members {
+                        result.remove(member);
+                    }
+                }
+            }
+        }
+
+        result.into_iter().collect()
+    }
+}
+
+impl RedisCommand for SetOperationCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        let (operation, name) = match super::upper_first_bulk_string(redis_type).as_deref() {
+            Some("SUNION") => (SetOperation::Union, "SUNION"),
+            Some("SDIFF") => (SetOperation::Difference, "SDIFF"),
+            _ => (SetOperation::Intersection, "SINTER"),
+        };
+
+        // SINTER key [key ...]
+        if elements.len() < 2 {
+            return Err(anyhow!("Wrong number of arguments for {name} command"));
+        }
+
+        let keys = elements[1..]
+            .iter()
+            .map(|element| match element {
+                RedisType::BulkString(key) => Ok(bulk_str(key).into_owned()),
+                _ => Err(anyhow!("{name} key is not a BulkString")),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { keys, operation })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let requests = self
+            .keys
+            .iter()
+            .map(|key| engine.execute(SetMembersStorage { key: key.clone() }));
+
+        let mut sets = Vec::with_capacity(self.keys.len());
+        for resp in try_join_all(requests).await? {
+            match resp {
+                StorageResponse::ListValues { values } => sets.push(values),
+                StorageResponse::Failed(msg) => {
+                    RedisType::SimpleError(msg)
+                        .write_resp_to_stream(output_buf, stream)
+                        .await?;
+                    return Ok(());
+                }
+                _ => {
+                    RedisType::SimpleError(format!(
+                        "Unknown error occurred during {}",
+                        self.name()
+                    ))
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+                    return Ok(());
+                }
+            }
+        }
+
+        let members = self
+            .combine(sets)
+            .into_iter()
+            .map(|value| RedisType::BulkString(value.into()))
+            .collect();
+
+        RedisType::Array(members)
+            .write_resp_to_stream(output_buf, stream)
+            .await?;
+
+        Ok(())
+    }
+}
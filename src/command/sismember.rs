@@ -0,0 +1,68 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{SetIsMemberStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/sismember/
+/// Returns 1 if member is a member of the set stored at key,
+/// 0 if it is not a member or the key does not exist.
+///
+#[derive(Debug)]
+pub struct SIsMemberCommand {
+    key: String,
+    member: String,
+}
+
+impl RedisCommand for SIsMemberCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // SISMEMBER key member
+        if elements.len() != 3 {
+            return Err(anyhow!("Wrong number of arguments for SISMEMBER command"));
+        }
+
+        match (&elements[1], &elements[2]) {
+            (RedisType::BulkString(key), RedisType::BulkString(member)) => Ok(Self {
+                key: bulk_str(key).into_owned(),
+                member: bulk_str(member).into_owned(),
+            }),
+            _ => Err(anyhow!("SISMEMBER arguments are not BulkStrings")),
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(SetIsMemberStorage {
+                key: self.key.clone(),
+                member: self.member.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(is_member) => {
+                RedisType::Integer(is_member)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during SISMEMBER".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{NOT_AN_INTEGER_ERROR, SetPopStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/spop/
+/// Removes and returns random members of the set stored at key, deleting the key once the set is empty.
+/// - Without count: returns one member as BulkString, or Null if the key does not exist.
+/// - With count: returns an Array of up to `count` distinct members, empty if the key does not exist.
+///
+#[derive(Debug)]
+pub struct SPopCommand {
+    key: String,
+    count: Option<usize>,
+}
+
+impl RedisCommand for SPopCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // SPOP key [count]
+        if !(2..=3).contains(&elements.len()) {
+            return Err(anyhow!("Wrong number of arguments for SPOP command"));
+        }
+
+        let RedisType::BulkString(key) = &elements[1] else {
+            return Err(anyhow!("SPOP key is not a BulkString"));
+        };
+
+        let count = match elements.get(2) {
+            Some(RedisType::BulkString(count_str)) => {
+                let count = bulk_str(count_str)
+                    .parse::<i64>()
+                    .map_err(|_| anyhow!(NOT_AN_INTEGER_ERROR))?;
+                let count = usize::try_from(count)
+                    .map_err(|_| anyhow!("ERR value is out of range, must be positive"))?;
+                Some(count)
+            }
+            Some(_) => return Err(anyhow!("SPOP count is not a BulkString")),
+            None => None,
+        };
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            count,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(SetPopStorage {
+                key: self.key.clone(),
+                count: self.count,
+            })
+            .await?;
+
+        let reply = match resp {
+            StorageResponse::KeyValue { value } => RedisType::BulkString(value.into()),
+            StorageResponse::ListValues { values } => RedisType::Array(
+                values
+                    .into_iter()
+                    .map(|value| RedisType::BulkString(value.into()))
+                    .collect(),
+            ),
+            StorageResponse::Null => RedisType::NullBulkString,
+            StorageResponse::Failed(msg) => RedisType::SimpleError(msg),
+            _ => RedisType::SimpleError("Unknown error occurred during SPOP".to_string()),
+        };
+
+        reply.write_resp_to_stream(output_buf, stream).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{
+    RedisType, bulk_str, write_bulk_array_from_iter,
+};
+use crate::storage::set_random_members_storage::repeated_picks;
+use crate::storage::{NOT_AN_INTEGER_ERROR, SetRandomMembersStorage, StorageResponse};
+use crate::utils::random::{Prng, with_thread_prng};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/srandmember/
+/// Returns random members of the set stored at key without removing them.
+/// - Without count: returns one member as BulkString, or Null if the key does not exist.
+/// - Positive count: returns an Array of up to `count` distinct members.
+/// - Negative count: returns an Array of exactly `-count` members, possibly repeated.
+///
+/// With a count, a missing key gives an empty Array. Like Redis, a count below `-i64::MAX / 2`
+/// is rejected, and a large negative count is written out as it is picked rather than built first.
+///
+#[derive(Debug)]
+pub struct SRandMemberCommand {
+    key: String,
+    count: Option<i64>,
+}
+
+impl RedisCommand for SRandMemberCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // SRANDMEMBER key [count]
+        if !(2..=3).contains(&elements.len()) {
+            return Err(anyhow!("Wrong number of arguments for SRANDMEMBER command"));
+        }
+
+        let RedisType::BulkString(key) = &elements[1] else {
+            return Err(anyhow!("SRANDMEMBER key is not a BulkString"));
+        };
+
+        let count = match elements.get(2) {
+            Some(RedisType::BulkString(count_str)) => Some(
+                bulk_str(count_str)
+                    .parse::<i64>()
+                    .map_err(|_| anyhow!(NOT_AN_INTEGER_ERROR))?,
+            ),
+            Some(_) => return Err(anyhow!("SRANDMEMBER count is not a BulkString")),
+            None => None,
+        };
+        if count.is_some_and(|count| count < -(i64::MAX / 2)) {
+            return Err(anyhow!("ERR value is out of range"));
+        }
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            count,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(SetRandomMembersStorage {
+                key: self.key.clone(),
+                count: self.count,
+            })
+            .await?;
+
+        let reply = match resp {
+            StorageResponse::KeyValue { value } => RedisType::BulkString(value.into()),
+            StorageResponse::ListValues { values } => RedisType::Array(
+                values
+                    .into_iter()
+                    .map(|value| RedisType::BulkString(value.into()))
+                    .collect(),
+            ),
+            StorageResponse::MembersToSample { members, count } => {
+                let prng = Prng::new(with_thread_prng(Prng::next_u64));
+                let picks = repeated_picks(&members, prng);
+                return write_bulk_array_from_iter(count, picks, output_buf, stream).await;
+            }
+            StorageResponse::Null => RedisType::NullBulkString,
+            StorageResponse::Failed(msg) => RedisType::SimpleError(msg),
+            _ => RedisType::SimpleError("Unknown error occurred during SRANDMEMBER".to_string()),
+        };
+
+        reply.write_resp_to_stream(output_buf, stream).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{SetRemoveStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/srem/
+/// Removes the specified members from the set stored at key, members that are not in the set are ignored.
+/// The key is deleted once its last member is removed.
+///
+/// Returns the number of members that were removed, 0 when the key does not exist.
+///
+#[derive(Debug)]
+pub struct SRemCommand {
+    key: String,
+    members: Vec<String>,
+}
+
+impl RedisCommand for SRemCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // SREM key member [member ...]
+        if elements.len() < 3 {
+            return Err(anyhow!("Wrong number of arguments for SREM command"));
+        }
+
+        let RedisType::BulkString(key) = &elements[1] else {
+            return Err(anyhow!("SREM key is not a BulkString"));
+        };
+
+        let members = elements[2..]
+            .iter()
+            .map(|element| match element {
+                RedisType::BulkString(member) => Ok(bulk_str(member).into_owned()),
+                _ => Err(anyhow!("SREM member is not a BulkString")),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            members,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(SetRemoveStorage {
+                key: self.key.clone(),
+                members: self.members.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(removed) => {
+                RedisType::Integer(removed)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during SREM".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
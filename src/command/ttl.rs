@@ -0,0 +1,67 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{StorageResponse, TtlStorage};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/ttl/
+/// Returns the remaining time to live of a key that has a timeout, in seconds.
+/// Returns -2 if the key does not exist and -1 if the key exists but has no associated expire.
+///
+#[derive(Debug)]
+pub struct TtlCommand {
+    key: String,
+}
+
+impl RedisCommand for TtlCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // TTL key
+        if elements.len() != 2 {
+            return Err(anyhow!("Wrong number of arguments for TTL command"));
+        }
+
+        if let RedisType::BulkString(key) = &elements[1] {
+            Ok(Self {
+                key: bulk_str(key).into_owned(),
+            })
+        } else {
+            Err(anyhow!("TTL argument is not a BulkString"))
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(TtlStorage {
+                key: self.key.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(ttl_ms) => {
+                // Rounded to the closest second, like Redis
+                let ttl = if ttl_ms < 0 {
+                    ttl_ms
+                } else {
+                    (ttl_ms + 500) / 1000
+                };
+                RedisType::Integer(ttl)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during TTL".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{StorageResponse, StreamAddStorage, StreamIdSpec};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/xadd/
+/// Appends the specified stream entry to the stream at the specified key.
+/// If the key does not exist, the stream is created.
+/// Only the `*`, `<ms>-*` and explicit `<ms>-<seq>` ID forms are supported (no MAXLEN/MINID trimming).
+///
+#[derive(Debug)]
+pub struct XAddCommand {
+    key: String,
+    id: StreamIdSpec,
+    fields: Vec<(String, String)>,
+}
+
+impl RedisCommand for XAddCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // XADD key id field value [field value ...]
+        if elements.len() < 5 || (elements.len() - 3) % 2 != 0 {
+            return Err(anyhow!("Wrong number of arguments for XADD command"));
+        }
+
+        let (RedisType::BulkString(key), RedisType::BulkString(id)) = (&elements[1], &elements[2])
+        else {
+            return Err(anyhow!("XADD key or ID is not BulkString"));
+        };
+
+        let mut fields = Vec::with_capacity((elements.len() - 3) / 2);
+        for pair in elements[3..].chunks_exact(2) {
+            if let (RedisType::BulkString(field), RedisType::BulkString(value)) =
+                (&pair[0], &pair[1])
+            {
+                fields.push((bulk_str(field).into_owned(), bulk_str(value).into_owned()));
+            } else {
+                return Err(anyhow!("XADD field or value is not BulkString"));
+            }
+        }
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            id: StreamIdSpec::parse(&bulk_str(id))?,
+            fields,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(StreamAddStorage {
+                key: self.key.clone(),
+                id: self.id,
+                fields: self.fields.clone(),
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::KeyValue { value } => {
+                // The ID of the added entry
+                RedisType::BulkString(value.into())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during XADD".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
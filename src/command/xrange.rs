@@ -0,0 +1,121 @@
+use anyhow::{Context, Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{StorageResponse, StreamId, StreamRangeStorage, parse_stream_range_bound};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/xrange/
+/// Returns the stream entries matching a given range of IDs.
+/// `-` and `+` are the minimum and maximum possible IDs, both bounds are inclusive.
+///
+#[derive(Debug)]
+pub struct XRangeCommand {
+    key: String,
+    start: StreamId,
+    end: StreamId,
+    count: Option<usize>,
+}
+
+impl RedisCommand for XRangeCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // XRANGE key start end [COUNT count]
+        if elements.len() != 4 && elements.len() != 6 {
+            return Err(anyhow!("Wrong number of arguments for XRANGE command"));
+        }
+
+        let (
+            RedisType::BulkString(key),
+            RedisType::BulkString(start_str),
+            RedisType::BulkString(end_str),
+        ) = (&elements[1], &elements[2], &elements[3])
+        else {
+            return Err(anyhow!(
+                "XRANGE incorrect parameter types, expected BulkString, BulkString, BulkString"
+            ));
+        };
+
+        let count = if elements.len() == 6 {
+            match (&elements[4], &elements[5]) {
+                (RedisType::BulkString(option), RedisType::BulkString(count_str))
+                    if option.eq_ignore_ascii_case(b"COUNT") =>
+                {
+                    Some(bulk_str(count_str).parse::<usize>().with_context(|| {
+                        format!(
+                            "Failed to parse XRANGE count parameter '{}' as unsigned integer",
+                            bulk_str(count_str)
+                        )
+                    })?)
+                }
+                _ => return Err(anyhow!("ERR syntax error")),
+            }
+        } else {
+            None
+        };
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            start: parse_stream_range_bound(&bulk_str(start_str), true)?,
+            end: parse_stream_range_bound(&bulk_str(end_str), false)?,
+            count,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(StreamRangeStorage {
+                key: self.key.clone(),
+                start: self.start,
+                end: self.end,
+                count: self.count,
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::StreamEntries { entries } => {
+                // [[id, [field, value, ...]], ...]
+                let redis_entries = entries
+                    .into_iter()
+                    .map(|(id, fields)| {
+                        let flat_fields = fields
+                            .into_iter()
+                            .flat_map(|(field, value)| {
+                                [
+                                    RedisType::BulkString(field.into()),
+                                    RedisType::BulkString(value.into()),
+                                ]
+                            })
+                            .collect();
+
+                        RedisType::Array(vec![
+                            RedisType::BulkString(id.to_string().into()),
+                            RedisType::Array(flat_fields),
+                        ])
+                    })
+                    .collect();
+
+                RedisType::Array(redis_entries)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during XRANGE".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
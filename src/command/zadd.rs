@@ -0,0 +1,116 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{
+    NOT_A_FLOAT_ERROR, ScoreUpdate, SortedSetAddStorage, StorageResponse, parse_score,
+};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/zadd/
+/// Adds the members with their scores to the sorted set stored at key, creating it when key does not exist.
+/// A member already in the sorted set gets its score updated.
+/// Scores are floats, `-inf` and `+inf` included (not NaN), compared as described in `compare_scores`.
+///
+/// Options:
+/// GT -- Only update existing members if the new score is greater than the current score.
+/// LT -- Only update existing members if the new score is less than the current score.
+/// Neither prevents adding new members.
+///
+/// Returns the number of members that were added, score updates are not counted.
+///
+#[derive(Debug)]
+pub struct ZAddCommand {
+    key: String,
+    entries: Vec<(f64, String)>,
+    update: ScoreUpdate,
+}
+
+impl RedisCommand for ZAddCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // ZADD key [GT | LT] score member [score member ...]
+        if elements.len() < 4 {
+            return Err(anyhow!("Wrong number of arguments for ZADD command"));
+        }
+
+        let RedisType::BulkString(key) = &elements[1] else {
+            return Err(anyhow!("ZADD key is not a BulkString"));
+        };
+
+        let mut update = ScoreUpdate::Always;
+        let mut first_score = 2;
+        while let Some(RedisType::BulkString(option)) = elements.get(first_score) {
+            let option = match bulk_str(option).to_uppercase().as_str() {
+                "GT" => ScoreUpdate::GreaterThan,
+                "LT" => ScoreUpdate::LessThan,
+                _ => break,
+            };
+            if update != ScoreUpdate::Always && update != option {
+                return Err(anyhow!(
+                    "ERR GT, LT, and/or NX options at the same time are not compatible"
+                ));
+            }
+            update = option;
+            first_score += 1;
+        }
+
+        let pairs = &elements[first_score..];
+        if pairs.is_empty() || pairs.len() % 2 != 0 {
+            return Err(anyhow!("Wrong number of arguments for ZADD command"));
+        }
+
+        let entries = pairs
+            .chunks_exact(2)
+            .map(|pair| match pair {
+                [RedisType::BulkString(score), RedisType::BulkString(member)] => {
+                    let score =
+                        parse_score(&bulk_str(score)).ok_or_else(|| anyhow!(NOT_A_FLOAT_ERROR))?;
+                    Ok((score, bulk_str(member).into_owned()))
+                }
+                _ => Err(anyhow!("ZADD argument is not a BulkString")),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            entries,
+            update,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(SortedSetAddStorage {
+                key: self.key.clone(),
+                entries: self.entries.clone(),
+                update: self.update,
+            })
+            .await?;
+
+        match resp {
+            StorageResponse::IntegerValue(added) => {
+                RedisType::Integer(added)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            StorageResponse::Failed(msg) => {
+                RedisType::SimpleError(msg)
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+            _ => {
+                RedisType::SimpleError("Unknown error occurred during ZADD".to_string())
+                    .write_resp_to_stream(output_buf, stream)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
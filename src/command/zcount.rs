@@ -0,0 +1,64 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{
+    MIN_MAX_NOT_A_FLOAT_ERROR, ScoreRange, SortedSetCountStorage, StorageResponse,
+};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/zcount/
+/// Returns the number of members of the sorted set stored at key with a score between min and max,
+/// 0 when key does not exist. Bounds follow ZRANGEBYSCORE: `(` makes a bound exclusive, `-inf` and `+inf` are accepted.
+///
+#[derive(Debug)]
+pub struct ZCountCommand {
+    key: String,
+    range: ScoreRange,
+}
+
+impl RedisCommand for ZCountCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // ZCOUNT key min max
+        if elements.len() != 4 {
+            return Err(anyhow!("Wrong number of arguments for ZCOUNT command"));
+        }
+
+        let (RedisType::BulkString(key), RedisType::BulkString(min), RedisType::BulkString(max)) =
+            (&elements[1], &elements[2], &elements[3])
+        else {
+            return Err(anyhow!("ZCOUNT arguments are not BulkStrings"));
+        };
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            range: ScoreRange::parse(&bulk_str(min), &bulk_str(max))
+                .ok_or(anyhow!(MIN_MAX_NOT_A_FLOAT_ERROR))?,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(SortedSetCountStorage {
+                key: self.key.clone(),
+                range: self.range,
+            })
+            .await?;
+
+        let reply = match resp {
+            StorageResponse::IntegerValue(count) => RedisType::Integer(count),
+            StorageResponse::Failed(msg) => RedisType::SimpleError(msg),
+            _ => RedisType::SimpleError("Unknown error occurred during ZCOUNT".to_string()),
+        };
+
+        reply.write_resp_to_stream(output_buf, stream).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{NOT_AN_INTEGER_ERROR, SortedSetPopStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/zpopmin/
+/// Removes and returns up to count members with the lowest scores in the sorted set stored at key,
+/// deleting the key once the sorted set is empty. count defaults to 1.
+/// Returns an Array of member, score pairs starting with the lowest score, empty if the key does not exist.
+///
+#[derive(Debug)]
+pub struct ZPopMinCommand {
+    key: String,
+    count: usize,
+}
+
+impl RedisCommand for ZPopMinCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // ZPOPMIN key [count]
+        if !(2..=3).contains(&elements.len()) {
+            return Err(anyhow!("Wrong number of arguments for ZPOPMIN command"));
+        }
+
+        let RedisType::BulkString(key) = &elements[1] else {
+            return Err(anyhow!("ZPOPMIN key is not a BulkString"));
+        };
+
+        let count = match elements.get(2) {
+            Some(RedisType::BulkString(count_str)) => {
+                let count = bulk_str(count_str)
+                    .parse::<i64>()
+                    .map_err(|_| anyhow!(NOT_AN_INTEGER_ERROR))?;
+                usize::try_from(count)
+                    .map_err(|_| anyhow!("ERR value is out of range, must be positive"))?
+            }
+            Some(_) => return Err(anyhow!("ZPOPMIN count is not a BulkString")),
+            None => 1,
+        };
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            count,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(SortedSetPopStorage {
+                key: self.key.clone(),
+                count: self.count,
+                max: false,
+            })
+            .await?;
+
+        let reply = match resp {
+            StorageResponse::ListValues { values } => RedisType::Array(
+                values
+                    .into_iter()
+                    .map(|value| RedisType::BulkString(value.into()))
+                    .collect(),
+            ),
+            StorageResponse::Failed(msg) => RedisType::SimpleError(msg),
+            _ => RedisType::SimpleError("Unknown error occurred during ZPOPMIN".to_string()),
+        };
+
+        reply.write_resp_to_stream(output_buf, stream).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{NOT_AN_INTEGER_ERROR, SortedSetRangeStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/zrange/
+/// https://redis.io/docs/latest/commands/zrevrange/
+/// Returns the members of the sorted set stored at key between the ranks start and stop (both inclusive).
+/// ZRANGE ranks members from the lowest to the highest score, ZREVRANGE from the highest to the lowest;
+/// members with the same score are ordered lexicographically (reversed for ZREVRANGE).
+/// Negative indexes count from the end, like LRANGE. An empty array when key does not exist.
+///
+/// WITHSCORES interleaves every member with its score.
+///
+#[derive(Debug)]
+pub struct ZRangeCommand {
+    key: String,
+    start: i64,
+    end: i64,
+    reverse: bool,
+    with_scores: bool,
+}
+
+impl ZRangeCommand {
+    fn name(&self) -> &'static str {
+        if self.reverse { "ZREVRANGE" } else { "ZRANGE" }
+    }
+}
+
+impl RedisCommand for ZRangeCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        let reverse = super::upper_first_bulk_string(redis_type).as_deref() == Some("ZREVRANGE");
+        let name = if reverse { "ZREVRANGE" } else { "ZRANGE" };
+
+        // ZRANGE key start stop [WITHSCORES]
+        if !(4..=5).contains(&elements.len()) {
+            return Err(anyhow!("Wrong number of arguments for {name} command"));
+        }
+
+        let (
+            RedisType::BulkString(key),
+            RedisType::BulkString(start_str),
+            RedisType::BulkString(end_str),
+        ) = (&elements[1], &elements[2], &elements[3])
+        else {
+            return Err(anyhow!("{name} arguments are not BulkStrings"));
+        };
+
+        let with_scores = match elements.get(4) {
+            Some(RedisType::BulkString(option)) if option.eq_ignore_ascii_case(b"WITHSCORES") => {
+                true
+            }
+            Some(_) => return Err(anyhow!("ERR syntax error")),
+            None => false,
+        };
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            start: bulk_str(start_str)
+                .parse::<i64>()
+                .map_err(|_| anyhow!(NOT_AN_INTEGER_ERROR))?,
+            end: bulk_str(end_str)
+                .parse::<i64>()
+                .map_err(|_| anyhow!(NOT_AN_INTEGER_ERROR))?,
+            reverse,
+            with_scores,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(SortedSetRangeStorage {
+                key: self.key.clone(),
+                start: self.start,
+                end: self.end,
+                reverse: self.reverse,
+                with_scores: self.with_scores,
+            })
+            .await?;
+
+        let reply = match resp {
+            StorageResponse::ListValues { values } => RedisType::Array(
+                values
+                    .into_iter()
+                    .map(|value| RedisType::BulkString(value.into()))
+                    .collect(),
+            ),
+            StorageResponse::Failed(msg) => RedisType::SimpleError(msg),
+            _ => RedisType::SimpleError(format!("Unknown error occurred during {}", self.name())),
+        };
+
+        reply.write_resp_to_stream(output_buf, stream).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{
+    MIN_MAX_NOT_A_FLOAT_ERROR, NOT_AN_INTEGER_ERROR, ScoreRange, StorageResponse,
+    ZRangeByScoreStorage,
+};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/zrangebyscore/
+/// Returns the members of the sorted set stored at key with a score between min and max,
+/// ordered from the lowest to the highest score, members with the same score ordered lexicographically.
+/// Bounds are inclusive unless prefixed with `(`, `-inf` and `+inf` leave a side unbounded.
+/// An empty array when key does not exist.
+///
+/// WITHSCORES interleaves every member with its score.
+/// LIMIT offset count skips offset matching members and returns at most count of them (all when count is negative).
+///
+#[derive(Debug)]
+pub struct ZRangeByScoreCommand {
+    key: String,
+    range: ScoreRange,
+    limit: Option<(i64, i64)>,
+    with_scores: bool,
+}
+
+fn parse_limit_argument(value: Option<&RedisType>) -> Result<i64> {
+    match value {
+        Some(RedisType::BulkString(value)) => bulk_str(value)
+            .parse::<i64>()
+            .map_err(|_| anyhow!(NOT_AN_INTEGER_ERROR)),
+        _ => Err(anyhow!("ERR syntax error")),
+    }
+}
+
+impl RedisCommand for ZRangeByScoreCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // ZRANGEBYSCORE key min max [WITHSCORES] [LIMIT offset count]
+        if elements.len() < 4 {
+            return Err(anyhow!(
+                "Wrong number of arguments for ZRANGEBYSCORE command"
+            ));
+        }
+
+        let (RedisType::BulkString(key), RedisType::BulkString(min), RedisType::BulkString(max)) =
+            (&elements[1], &elements[2], &elements[3])
+        else {
+            return Err(anyhow!("ZRANGEBYSCORE arguments are not BulkStrings"));
+        };
+
+        let range = ScoreRange::parse(&bulk_str(min), &bulk_str(max))
+            .ok_or(anyhow!(MIN_MAX_NOT_A_FLOAT_ERROR))?;
+
+        let mut limit = None;
+        let mut with_scores = false;
+        let mut index = 4;
+        while index < elements.len() {
+            let RedisType::BulkString(option) = &elements[index] else {
+                return Err(anyhow!("ERR syntax error"));
+            };
+
+            match bulk_str(option).to_uppercase().as_str() {
+                "WITHSCORES" => {
+                    with_scores = true;
+                    index += 1;
+                }
+                "LIMIT" => {
+                    let offset = parse_limit_argument(elements.get(index + 1))?;
+                    let count = parse_limit_argument(elements.get(index + 2))?;
+                    limit = Some((offset, count));
+                    index += 3;
+                }
+                _ => return Err(anyhow!("ERR syntax error")),
+            }
+        }
+
+        Ok(Self {
+            key: bulk_str(key).into_owned(),
+            range,
+            limit,
+            with_scores,
+        })
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(ZRangeByScoreStorage {
+                key: self.key.clone(),
+                range: self.range,
+                limit: self.limit,
+                with_scores: self.with_scores,
+            })
+            .await?;
+
+        let reply = match resp {
+            StorageResponse::ListValues { values } => RedisType::Array(
+                values
+                    .into_iter()
+                    .map(|value| RedisType::BulkString(value.into()))
+                    .collect(),
+            ),
+            StorageResponse::Failed(msg) => RedisType::SimpleError(msg),
+            _ => RedisType::SimpleError("Unknown error occurred during ZRANGEBYSCORE".to_string()),
+        };
+
+        reply.write_resp_to_stream(output_buf, stream).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use anyhow::{Result, anyhow};
+use bytes::BytesMut;
+use tokio::net::TcpStream;
+
+use crate::protocol::redis_serialization_protocol::{RedisType, bulk_str};
+use crate::storage::{SortedSetScoreStorage, StorageResponse};
+
+use super::{RedisCommand, storage_engine};
+
+///
+/// https://redis.io/docs/latest/commands/zscore/
+/// Returns the score of member in the sorted set stored at key as a BulkString,
+/// Null when key or member does not exist.
+///
+#[derive(Debug)]
+pub struct ZScoreCommand {
+    key: String,
+    member: String,
+}
+
+impl RedisCommand for ZScoreCommand {
+    fn parse(redis_type: &RedisType) -> Result<Self> {
+        let elements = super::expect_cmd_array(redis_type)?;
+
+        // ZSCORE key member
+        if elements.len() != 3 {
+            return Err(anyhow!("Wrong number of arguments for ZSCORE command"));
+        }
+
+        match (&elements[1], &elements[2]) {
+            (RedisType::BulkString(key), RedisType::BulkString(member)) => Ok(Self {
+                key: bulk_str(key).into_owned(),
+                member: bulk_str(member).into_owned(),
+            }),
+            _ => Err(anyhow!("ZSCORE arguments are not BulkStrings")),
+        }
+    }
+
+    async fn execute(&self, output_buf: &mut BytesMut, stream: &mut TcpStream) -> Result<()> {
+        let engine = storage_engine()?;
+        let resp = engine
+            .execute(SortedSetScoreStorage {
+                key: self.key.clone(),
+                member: self.member.clone(),
+            })
+            .await?;
+
+        let reply = match resp {
+            StorageResponse::KeyValue { value } => RedisType::BulkString(value.into()),
+            StorageResponse::Null => RedisType::NullBulkString,
+            StorageResponse::Failed(msg) => RedisType::SimpleError(msg),
+            _ => RedisType::SimpleError("Unknown error occurred during ZSCORE".to_string()),
+        };
+
+        reply.write_resp_to_stream(output_buf, stream).await?;
+
+        Ok(())
+    }
+}
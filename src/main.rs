@@ -2,7 +2,13 @@ mod startup_arguments;
 
 use std::sync::Arc;
 
-use crate::{startup_arguments::StartupArguments, storage::StorageEngine};
+use crate::{
+    command::{ensure_storage_engine, invalidate_keys, set_read_only},
+    protocol::redis_serialization_protocol::set_reply_limits,
+    startup_arguments::{LogFormat, StartupArguments},
+    storage::{StorageEngine, set_expire_jitter_ms, set_keys_changed_hook},
+    utils::thread_utils::configure_cpu_affinity,
+};
 
 mod command;
 mod network;
@@ -10,34 +16,59 @@ mod protocol;
 mod storage;
 mod utils;
 
-fn main() -> anyhow::Result<()> {
-    // Initialize logging
-    tracing_subscriber::fmt()
+fn init_logging(log_format: LogFormat) {
+    let subscriber = tracing_subscriber::fmt()
         .with_env_filter(
             tracing_subscriber::EnvFilter::try_from_default_env()
                 .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("valkyrie=debug")),
         )
         .with_thread_names(true)
         .with_target(false)
-        .init();
+        .with_writer(std::io::stderr);
+
+    match log_format {
+        LogFormat::Text => subscriber.init(),
+        LogFormat::Json => subscriber.json().init(),
+    }
+}
 
+fn main() -> anyhow::Result<()> {
     let arguments = StartupArguments::parse_args();
 
+    // Initialize logging
+    init_logging(arguments.log_format);
+
     tracing::info!("StartupArguments: {arguments}");
 
+    // Must be configured before the shard threads start and pin themselves
+    configure_cpu_affinity(!arguments.no_cpu_affinity, arguments.cpu_affinity_offset);
+
     let storage_affinity_cores = 0..arguments.shards;
-    let storage = Arc::new(StorageEngine::new(arguments.shards, storage_affinity_cores));
+    let storage = Arc::new(StorageEngine::new(
+        arguments.shards,
+        storage_affinity_cores,
+        arguments.initial_keyspace_size,
+    ));
+
+    set_expire_jitter_ms(arguments.expire_jitter_ms);
+    // Keys the shards remove on their own are invalidated for client tracking
+    set_keys_changed_hook(invalidate_keys);
+
+    // Provide StorageEngine to command implementations once, before any connection is accepted
+    ensure_storage_engine(storage);
+    set_read_only(arguments.read_only);
+    set_reply_limits(arguments.max_reply_elements, arguments.max_reply_bytes);
 
     #[cfg(target_os = "linux")]
     {
         use crate::network::reuse::start_reuseport_tcp_handlers;
-        start_reuseport_tcp_handlers(&arguments, storage)?;
+        start_reuseport_tcp_handlers(&arguments)?;
     }
 
     #[cfg(any(target_os = "windows", target_os = "macos"))]
     {
         use crate::network::dispatcher::start_dispatcher_tcp_handlers;
-        start_dispatcher_tcp_handlers(&arguments, storage)?;
+        start_dispatcher_tcp_handlers(&arguments)?;
     }
 
     Ok(())
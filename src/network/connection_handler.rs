@@ -1,13 +1,24 @@
 use std::net::SocketAddr;
+use std::pin::pin;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use std::time::Duration;
 
 use bytes::BytesMut;
+use futures::future::{Either, select};
 use tokio::io::AsyncReadExt;
 use tokio::net::TcpStream;
+use tokio::time::Instant;
 
-use crate::command::{dispatch_and_execute, ensure_storage_engine};
-use crate::protocol::redis_serialization_protocol::{RedisType, try_parse_frame};
-use crate::storage::StorageEngine;
+use crate::command::{
+    AuthCommand, ClientCommand, HelloCommand, NOAUTH_ERROR, TrackingConnection,
+    dispatch_and_execute, invalidate_written_keys, is_allowed_before_auth, is_auth_command,
+    is_blocking_command, is_client_command, is_hello_command, track_read_keys, try_fast_path_reply,
+};
+use crate::protocol::redis_serialization_protocol::{
+    RedisType, RespVersion, flush_output, try_parse_frame, with_resp_version,
+};
 
 use std::net::TcpListener as StdTcpListener;
 
@@ -45,8 +56,20 @@ pub fn build_tcp_listener(addr: SocketAddr) -> anyhow::Result<StdTcpListener> {
     Ok(listener)
 }
 
-pub async fn run_client_connection(stream: TcpStream, storage_engine: Arc<StorageEngine>) {
-    if let Err(error) = handle_tcp_connection_from_client(stream, storage_engine).await {
+pub async fn run_client_connection(
+    stream: TcpStream,
+    read_buffer_size: usize,
+    first_command_timeout_ms: u64,
+    requirepass: Option<Arc<str>>,
+) {
+    if let Err(error) = handle_tcp_connection_from_client(
+        stream,
+        read_buffer_size,
+        first_command_timeout_ms,
+        requirepass,
+    )
+    .await
+    {
         // Expected client disconnects are not errors but normal cases.
         if let Some(io_err) = error.downcast_ref::<std::io::Error>() {
             match io_err.kind() {
@@ -66,61 +89,254 @@ pub async fn run_client_connection(stream: TcpStream, storage_engine: Arc<Storag
     }
 }
 
-const INITIAL_READ_CAPACITY: usize = 1024; // Initial buffer with 1 KB. Grows on demand. RESP frames are typically small.
+// Connection IDs reported by HELLO, unique for the lifetime of the server.
+static NEXT_CLIENT_ID: AtomicU64 = AtomicU64::new(1);
+
 const MAX_REQUEST_SIZE: usize = 64 * 1024; // fail-safe limit to avoid unbounded memory usage
 
 const DEFAULT_WRITE_CAPACITY: usize = 1024;
 
+/// Size of the next socket read, adapted to the recent throughput of the connection.
+/// Reads that fill the whole reserved space double the next chunk (up to `MAX_REQUEST_SIZE`),
+/// so busy pipelining clients are served with fewer syscalls; mostly idle reads shrink it
+/// back towards the configured `--read-buffer-size`.
+struct ReadChunkSize {
+    base: usize,
+    current: usize,
+}
+
+impl ReadChunkSize {
+    fn new(read_buffer_size: usize) -> Self {
+        let base = read_buffer_size.clamp(1, MAX_REQUEST_SIZE);
+        Self {
+            base,
+            current: base,
+        }
+    }
+
+    fn record_read(&mut self, bytes_read: usize) {
+        if bytes_read >= self.current {
+            self.current = (self.current * 2).min(MAX_REQUEST_SIZE);
+        } else if bytes_read < self.current / 4 {
+            self.current = (self.current / 2).max(self.base);
+        }
+    }
+}
+
 async fn handle_tcp_connection_from_client(
     mut stream: TcpStream,
-    storage_engine: Arc<StorageEngine>,
+    read_buffer_size: usize,
+    first_command_timeout_ms: u64,
+    requirepass: Option<Arc<str>>,
 ) -> anyhow::Result<()> {
-    let mut input_buf = BytesMut::with_capacity(INITIAL_READ_CAPACITY);
+    // Until the first complete frame is parsed, reads are bounded by a single deadline, so a client
+    // trickling a byte at a time (slow-loris) can't keep the connection open either. Cleared afterwards.
+    let mut first_command_deadline = (first_command_timeout_ms > 0)
+        .then(|| Instant::now() + Duration::from_millis(first_command_timeout_ms));
 
-    let mut output_buf = BytesMut::with_capacity(DEFAULT_WRITE_CAPACITY);
+    let client_id = NEXT_CLIENT_ID.fetch_add(1, Ordering::Relaxed);
 
-    // Provide StorageEngine to command implementations (initialized once)
-    ensure_storage_engine(storage_engine.clone());
+    // Client tracking invalidation messages for this connection, sent while it waits for a request.
+    let (_tracking_connection, mut invalidations) = TrackingConnection::register(client_id);
+
+    // Without `--requirepass` every connection starts authenticated.
+    let mut authenticated = requirepass.is_none();
+
+    // Replies are encoded for this protocol, switched by HELLO.
+    let mut resp_version = RespVersion::default();
+
+    let mut read_chunk = ReadChunkSize::new(read_buffer_size);
+    let mut input_buf = BytesMut::with_capacity(read_chunk.current);
+
+    // Replies are queued here while the pipelined commands already read are executed,
+    // and written out together once the next read would have to wait for the client.
+    let mut output_buf = BytesMut::with_capacity(DEFAULT_WRITE_CAPACITY);
 
     'outer: loop {
         // Incremental parsing: parse a single complete frame (if available).
         // Do not reparse bytes already consumed; keep leftovers for the next iteration.
         let received_redis_type = loop {
-            if let Some((parsed_redis_type, consumed_bytes_cnt)) = try_parse_frame(&input_buf) {
-                // Drop the consumed prefix; keep any pipelined bytes in the buffer.
-                let _ = input_buf.split_to(consumed_bytes_cnt);
-                break parsed_redis_type;
-            }
-
-            // Need more bytes to complete a frame.
-            let n = stream.read_buf(&mut input_buf).await?;
-
             // Guardrail: avoid unbounded memory growth on malformed or huge requests.
-            if input_buf.len() > MAX_REQUEST_SIZE {
+            // The limit applies to a single frame (complete or still incomplete), so a large
+            // read of small pipelined commands is not mistaken for an oversized request.
+            let request_size = match try_parse_frame(&input_buf) {
+                Some((parsed_redis_type, consumed_bytes_cnt))
+                    if consumed_bytes_cnt <= MAX_REQUEST_SIZE =>
+                {
+                    // Drop the consumed prefix; keep any pipelined bytes in the buffer.
+                    let _ = input_buf.split_to(consumed_bytes_cnt);
+                    first_command_deadline = None;
+                    break parsed_redis_type;
+                }
+                Some((_, consumed_bytes_cnt)) => consumed_bytes_cnt,
+                None => input_buf.len(),
+            };
+
+            if request_size > MAX_REQUEST_SIZE {
                 RedisType::SimpleError("Request too large".to_string())
                     .write_resp_to_stream(&mut output_buf, &mut stream)
                     .await?;
                 break 'outer;
             }
 
+            // Need more bytes to complete a frame, every command of the batch has been executed.
+            flush_output(&mut output_buf, &mut stream).await?;
+
+            input_buf.reserve(read_chunk.current);
+            let n = match first_command_deadline {
+                Some(deadline) => {
+                    match tokio::time::timeout_at(deadline, stream.read_buf(&mut input_buf)).await {
+                        Ok(read_result) => read_result?,
+                        Err(_) => {
+                            tracing::debug!(
+                                "No complete command within {first_command_timeout_ms} ms, closing connection"
+                            );
+                            break 'outer;
+                        }
+                    }
+                }
+                None => loop {
+                    let invalidation = {
+                        let read = pin!(stream.read_buf(&mut input_buf));
+                        match select(read, pin!(invalidations.recv())).await {
+                            Either::Left((read_result, _)) => break read_result?,
+                            Either::Right((invalidation, _)) => invalidation,
+                        }
+                    };
+
+                    // The registration holds the sender, the queue can't be closed before the connection
+                    let Some(message) = invalidation else {
+                        break stream.read_buf(&mut input_buf).await?;
+                    };
+                    with_resp_version(
+                        resp_version,
+                        message.write_resp_to_stream(&mut output_buf, &mut stream),
+                    )
+                    .await?;
+                    flush_output(&mut output_buf, &mut stream).await?;
+                },
+            };
+            read_chunk.record_read(n);
+
             if n == 0 {
                 // connection closed by the client
                 break 'outer;
             }
         };
 
-        if let Err(error) =
-            dispatch_and_execute(&received_redis_type, &mut output_buf, &mut stream).await
-        {
-            tracing::warn!("Unsupported command received: {error:?}");
+        // Framing errors leave the rest of the buffer out of sync: reply and close, like Redis does.
+        if let Some(msg) = received_redis_type.protocol_error() {
+            tracing::warn!("Protocol error from client: {msg}");
 
-            RedisType::SimpleError(error.to_string())
+            RedisType::SimpleError(msg.to_string())
                 .write_resp_to_stream(&mut output_buf, &mut stream)
                 .await?;
+            break 'outer;
+        }
+
+        // Blank inline lines (and `*0`) are skipped without a reply, like Redis does.
+        if matches!(&received_redis_type, RedisType::Array(elements) if elements.is_empty()) {
+            continue;
         }
-    }
 
-    output_buf.clear();
+        // AUTH changes the state of this connection, so it is answered here rather than dispatched.
+        if is_auth_command(&received_redis_type) {
+            match AuthCommand::parse(&received_redis_type) {
+                Ok(auth) => {
+                    if auth
+                        .execute(requirepass.as_deref(), &mut output_buf, &mut stream)
+                        .await?
+                    {
+                        authenticated = true;
+                    }
+                }
+                Err(error) => {
+                    RedisType::SimpleError(error.to_string())
+                        .write_resp_to_stream(&mut output_buf, &mut stream)
+                        .await?;
+                }
+            }
+            continue;
+        }
+
+        if is_hello_command(&received_redis_type) {
+            match HelloCommand::parse(&received_redis_type) {
+                Ok(hello) => {
+                    hello
+                        .execute(
+                            requirepass.as_deref(),
+                            &mut authenticated,
+                            &mut resp_version,
+                            client_id,
+                            &mut output_buf,
+                            &mut stream,
+                        )
+                        .await?;
+                }
+                Err(error) => {
+                    RedisType::SimpleError(error.to_string())
+                        .write_resp_to_stream(&mut output_buf, &mut stream)
+                        .await?;
+                }
+            }
+            continue;
+        }
+
+        if !authenticated && !is_allowed_before_auth(&received_redis_type) {
+            RedisType::SimpleError(NOAUTH_ERROR.to_string())
+                .write_resp_to_stream(&mut output_buf, &mut stream)
+                .await?;
+            continue;
+        }
+
+        if is_client_command(&received_redis_type) {
+            match ClientCommand::parse(&received_redis_type) {
+                Ok(client) => {
+                    client
+                        .execute(client_id, resp_version, &mut output_buf, &mut stream)
+                        .await?;
+                }
+                Err(error) => {
+                    RedisType::SimpleError(error.to_string())
+                        .write_resp_to_stream(&mut output_buf, &mut stream)
+                        .await?;
+                }
+            }
+            continue;
+        }
+
+        // PING/ECHO never touch storage: reply directly, skipping the full dispatch.
+        if let Some(reply) = try_fast_path_reply(&received_redis_type) {
+            reply
+                .write_resp_to_stream(&mut output_buf, &mut stream)
+                .await?;
+            continue;
+        }
+
+        // The replies of the commands pipelined before must not wait for the blocking one.
+        if is_blocking_command(&received_redis_type) {
+            flush_output(&mut output_buf, &mut stream).await?;
+        }
+
+        track_read_keys(client_id, &received_redis_type);
+
+        match with_resp_version(
+            resp_version,
+            dispatch_and_execute(&received_redis_type, &mut output_buf, &mut stream),
+        )
+        .await
+        {
+            Ok(()) => invalidate_written_keys(&received_redis_type),
+            Err(error) => {
+                tracing::warn!("Unsupported command received: {error:?}");
+
+                RedisType::SimpleError(error.to_string())
+                    .write_resp_to_stream(&mut output_buf, &mut stream)
+                    .await?;
+            }
+        }
+    }
 
-    Ok(())
+    // Replies of the commands executed before the connection is closed, e.g. a protocol error
+    flush_output(&mut output_buf, &mut stream).await
 }
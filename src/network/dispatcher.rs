@@ -9,17 +9,18 @@ use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
 
 use crate::network::connection_handler::{build_tcp_listener, run_client_connection};
 use crate::startup_arguments::StartupArguments;
-use crate::storage::StorageEngine;
 use crate::utils::thread_utils::pin_current_thread_to_cpu;
 
-pub fn start_dispatcher_tcp_handlers(
-    arguments: &StartupArguments,
-    storage_engine: Arc<StorageEngine>,
-) -> anyhow::Result<()> {
+pub fn start_dispatcher_tcp_handlers(arguments: &StartupArguments) -> anyhow::Result<()> {
     let tcp_affinity_cores = arguments.shards..arguments.shards + arguments.tcp_handlers;
 
-    let tcp_handler_channels =
-        start_tcp_handler_threads(arguments.tcp_handlers, tcp_affinity_cores, storage_engine);
+    let tcp_handler_channels = start_tcp_handler_threads(
+        arguments.tcp_handlers,
+        tcp_affinity_cores,
+        arguments.read_buffer_size,
+        arguments.first_command_timeout_ms,
+        arguments.requirepass.as_deref().map(Arc::from),
+    );
 
     let maybe_listener = build_tcp_listener(arguments.address);
 
@@ -64,17 +65,18 @@ pub fn start_dispatcher_tcp_handlers(
 fn start_tcp_handler_threads(
     tcp_handlers_count: usize,
     core_affinity_range: std::ops::Range<usize>,
-    storage_engine: Arc<StorageEngine>,
+    read_buffer_size: usize,
+    first_command_timeout_ms: u64,
+    requirepass: Option<Arc<str>>,
 ) -> Vec<UnboundedSender<StdTcpStream>> {
     let mut tcp_handlers = Vec::with_capacity(tcp_handlers_count);
 
     for handler_id in 0..tcp_handlers_count {
         let core_affinity_range_copy = core_affinity_range.clone();
+        let requirepass = requirepass.clone();
 
         let (stream_sender, mut stream_receiver) = unbounded_channel::<StdTcpStream>();
 
-        let storage_engine_copy = Arc::clone(&storage_engine);
-
         let _ = thread::Builder::new()
             .name(format!("tcp-handler-{handler_id}"))
             .spawn(move || {
@@ -95,7 +97,9 @@ fn start_tcp_handler_threads(
                                 Ok(stream) => {
                                     tokio::spawn(run_client_connection(
                                         stream,
-                                        Arc::clone(&storage_engine_copy),
+                                        read_buffer_size,
+                                        first_command_timeout_ms,
+                                        requirepass.clone(),
                                     ));
                                 }
                                 Err(error) => {
@@ -8,13 +8,9 @@ use tokio::net::TcpListener;
 
 use crate::network::connection_handler::{build_tcp_listener, run_client_connection};
 use crate::startup_arguments::StartupArguments;
-use crate::storage::StorageEngine;
 use crate::utils::thread_utils::pin_current_thread_to_cpu;
 
-pub fn start_reuseport_tcp_handlers(
-    arguments: &StartupArguments,
-    storage_engine: Arc<StorageEngine>,
-) -> anyhow::Result<()> {
+pub fn start_reuseport_tcp_handlers(arguments: &StartupArguments) -> anyhow::Result<()> {
     tracing::info!(
         "Starting {} TCP handlers with SO_REUSEPORT",
         arguments.tcp_handlers
@@ -26,7 +22,9 @@ pub fn start_reuseport_tcp_handlers(
         arguments.address,
         arguments.tcp_handlers,
         tcp_affinity_cores,
-        storage_engine,
+        arguments.read_buffer_size,
+        arguments.first_command_timeout_ms,
+        arguments.requirepass.as_deref().map(Arc::from),
     );
 
     for h in tcp_handlers {
@@ -39,7 +37,9 @@ fn start_tcp_handler_threads(
     address: SocketAddr,
     tcp_handlers_count: usize,
     core_affinity_range: std::ops::Range<usize>,
-    storage_engine: Arc<StorageEngine>,
+    read_buffer_size: usize,
+    first_command_timeout_ms: u64,
+    requirepass: Option<Arc<str>>,
 ) -> Vec<JoinHandle<()>> {
     //
     // Build one listener per tcp-handler. Each gets its own accept loop.
@@ -55,7 +55,7 @@ fn start_tcp_handler_threads(
 
     for (handler_id, single_listener) in listeners.into_iter().enumerate() {
         let core_affinity_range_copy = core_affinity_range.clone();
-        let storage_engine_copy = Arc::clone(&storage_engine);
+        let requirepass = requirepass.clone();
 
         let tcp_handler = thread::Builder::new()
             .name(format!("tcp-handler-{handler_id}"))
@@ -79,7 +79,9 @@ fn start_tcp_handler_threads(
                                         // Each shard owns its accepted connections; no cross-shard handoff.
                                         tokio::spawn(run_client_connection(
                                             stream,
-                                            Arc::clone(&storage_engine_copy),
+                                            read_buffer_size,
+                                            first_command_timeout_ms,
+                                            requirepass.clone(),
                                         ));
                                     }
                                     Err(error) => {
@@ -1,18 +1,157 @@
-use bytes::{BufMut, BytesMut};
+use std::borrow::Cow;
+use std::future::Future;
+use std::io::IoSlice;
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use bytes::{BufMut, Bytes, BytesMut};
 use tokio::{io::AsyncWriteExt, net::TcpStream};
 
-#[derive(Debug, PartialEq)]
+// Replies for malformed framing, same wording as Redis. The connection is closed after sending one.
+const PROTOCOL_ERROR_PREFIX: &str = "ERR Protocol error: ";
+const INVALID_MULTIBULK_LENGTH_ERROR: &str = "ERR Protocol error: invalid multibulk length";
+const INVALID_BULK_LENGTH_ERROR: &str = "ERR Protocol error: invalid bulk length";
+const INVALID_INTEGER_ERROR: &str = "ERR Protocol error: invalid integer";
+const NESTING_TOO_DEEP_ERROR: &str = "ERR Protocol error: too many nested arrays";
+const BULK_TERMINATOR_ERROR: &str = "ERR Protocol error: expected CRLF after bulk string payload";
+const UNBALANCED_QUOTES_ERROR: &str = "ERR Protocol error: unbalanced quotes in request";
+const INVALID_BIG_NUMBER_ERROR: &str = "ERR Protocol error: invalid big number";
+
+/// Leading bytes of RESP values, a request starting with anything else is an inline command.
+const RESP_TYPE_MARKERS: &[u8] = b"+-:$*|(";
+
+/// Arrays nested deeper than this are rejected, so a request can't exhaust the stack of the parser.
+/// Requests are flat arrays, deeper nesting only appears in replies.
+const MAX_NESTING_DEPTH: usize = 32;
+
+// Set once at startup from `--max-reply-elements`/`--max-reply-bytes`, 0 means unlimited.
+static MAX_REPLY_ELEMENTS: AtomicUsize = AtomicUsize::new(0);
+static MAX_REPLY_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+pub const REPLY_TOO_LARGE_ERROR: &str = "ERR reply is too large";
+
+pub fn set_reply_limits(max_elements: usize, max_bytes: usize) {
+    MAX_REPLY_ELEMENTS.store(max_elements, Ordering::Relaxed);
+    MAX_REPLY_BYTES.store(max_bytes, Ordering::Relaxed);
+}
+
+/// What is left of the reply limits while a reply is encoded. Elements are the entries of arrays
+/// and maps (nested ones included), bytes are everything sent for the reply.
+#[derive(Debug)]
+struct ReplyBudget {
+    elements: usize,
+    bytes: usize,
+}
+
+/// The reply went over `--max-reply-elements` or `--max-reply-bytes`, encoding was stopped.
+#[derive(Debug)]
+struct ReplyTooLarge;
+
+impl ReplyBudget {
+    fn unlimited() -> Self {
+        Self {
+            elements: usize::MAX,
+            bytes: usize::MAX,
+        }
+    }
+
+    fn from_limits() -> Self {
+        let limit = |value: usize| if value == 0 { usize::MAX } else { value };
+        Self {
+            elements: limit(MAX_REPLY_ELEMENTS.load(Ordering::Relaxed)),
+            bytes: limit(MAX_REPLY_BYTES.load(Ordering::Relaxed)),
+        }
+    }
+
+    fn spend_elements(&mut self, count: usize) -> Result<(), ReplyTooLarge> {
+        self.elements = self.elements.checked_sub(count).ok_or(ReplyTooLarge)?;
+        Ok(())
+    }
+
+    fn spend_bytes(&mut self, count: usize) -> Result<(), ReplyTooLarge> {
+        self.bytes = self.bytes.checked_sub(count).ok_or(ReplyTooLarge)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum RedisType {
     SimpleString(String),
-    BulkString(String),
+    /// Binary-safe payload, commands decode it as UTF-8 only where they need text (names, keys, numbers).
+    BulkString(Bytes),
     NullBulkString,
     Array(Vec<RedisType>),
     NullArray,
-    Integer(i32),
+    Integer(i64),
     InvalidType(String),
     SimpleError(String),
     #[allow(dead_code)]
     Null,
+    /// Key-value pairs: a RESP3 map, or a flat array of alternating keys and values for RESP2 clients.
+    Map(Vec<(RedisType, RedisType)>),
+    /// Metadata about `reply` (e.g. client-side caching hints) sent in front of it to RESP3 clients.
+    /// RESP2 has no attributes, those clients only get the reply.
+    Attribute {
+        attributes: Vec<(RedisType, RedisType)>,
+        reply: Box<RedisType>,
+    },
+    /// Out-of-band message (e.g. a client tracking invalidation), a RESP3 push or a plain array for RESP2 clients.
+    Push(Vec<RedisType>),
+    /// Integer beyond the 64-bit range, kept as its decimal digits. RESP2 clients get a bulk string.
+    BigNumber(String),
+}
+
+///
+/// Reply protocol of a connection, negotiated with HELLO. Connections start with RESP2.
+/// https://redis.io/docs/latest/develop/reference/protocol-spec/#resp-versions
+///
+/// RESP3 changes how some replies are framed: nulls are `_` and maps are `%` instead of flat arrays.
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RespVersion {
+    #[default]
+    Resp2,
+    Resp3,
+}
+
+impl RespVersion {
+    pub fn from_protover(protover: i64) -> Option<Self> {
+        match protover {
+            2 => Some(Self::Resp2),
+            3 => Some(Self::Resp3),
+            _ => None,
+        }
+    }
+
+    pub fn protover(&self) -> i64 {
+        match self {
+            Self::Resp2 => 2,
+            Self::Resp3 => 3,
+        }
+    }
+}
+
+tokio::task_local! {
+    // Protocol of the connection whose command is being executed, set by the connection handler.
+    static RESP_VERSION: RespVersion;
+}
+
+/// Runs `future` (usually the execution of one command) with replies encoded for `version`.
+pub async fn with_resp_version<F: Future>(version: RespVersion, future: F) -> F::Output {
+    RESP_VERSION.scope(version, future).await
+}
+
+/// RESP2 outside of `with_resp_version`.
+fn current_resp_version() -> RespVersion {
+    RESP_VERSION
+        .try_with(|version| *version)
+        .unwrap_or_default()
+}
+
+/// Text of a bulk string argument, for the ones that are names, keys, members or numbers.
+/// Invalid UTF-8 sequences are replaced with U+FFFD, only string values are stored as raw bytes.
+pub fn bulk_str(value: &[u8]) -> Cow<'_, str> {
+    String::from_utf8_lossy(value)
 }
 
 /// Trait to encode a RedisType into RESP bytes.
@@ -23,10 +162,7 @@ pub trait ToRespBytes {
 
 impl From<&str> for RedisType {
     fn from(value: &str) -> Self {
-        let mut forward_buf = ForwardBuf {
-            buf: &BytesMut::from(value.as_bytes()),
-            offset: 0,
-        };
+        let mut forward_buf = ForwardBuf::new(value.as_bytes());
         try_parse_type_forward(&mut forward_buf).expect("")
     }
 }
@@ -41,6 +177,25 @@ const RESP_TERMINATOR: &[u8] = b"\r\n";
 
 impl ToRespBytes for RedisType {
     fn write_resp_to_buf(&self, out_buf: &mut BytesMut) {
+        self.encode(out_buf, current_resp_version());
+    }
+}
+
+impl RedisType {
+    fn encode(&self, out_buf: &mut BytesMut, version: RespVersion) {
+        // Can't fail without limits
+        let _ = self.encode_within(out_buf, version, &mut ReplyBudget::unlimited());
+    }
+
+    /// Encodes the value, stopping as soon as it goes over `budget`. `out_buf` then holds a partial reply.
+    fn encode_within(
+        &self,
+        out_buf: &mut BytesMut,
+        version: RespVersion,
+        budget: &mut ReplyBudget,
+    ) -> Result<(), ReplyTooLarge> {
+        let start = out_buf.len();
+
         match self {
             // -Error message\r\n
             RedisType::SimpleError(error_msg) => {
@@ -54,8 +209,7 @@ impl ToRespBytes for RedisType {
                 out_buf.extend_from_slice(s.as_bytes());
                 out_buf.extend_from_slice(RESP_TERMINATOR);
             }
-            RedisType::BulkString(s) => {
-                let data = s.as_bytes();
+            RedisType::BulkString(data) => {
                 let len = data.len().to_string();
                 out_buf.put_u8(b'$');
                 out_buf.extend_from_slice(len.as_bytes());
@@ -63,19 +217,92 @@ impl ToRespBytes for RedisType {
                 out_buf.extend_from_slice(data);
                 out_buf.extend_from_slice(RESP_TERMINATOR);
             }
+            //  _\r\n in RESP3
+            RedisType::NullBulkString | RedisType::NullArray | RedisType::Null
+                if version == RespVersion::Resp3 =>
+            {
+                out_buf.extend_from_slice(b"_\r\n");
+            }
             RedisType::NullBulkString => {
                 out_buf.extend_from_slice(b"$-1\r\n");
             }
-            RedisType::Array(elements) => {
+            // >2\r\n... in RESP3
+            // https://redis.io/docs/latest/develop/reference/protocol-spec/#pushes
+            RedisType::Array(elements) | RedisType::Push(elements) => {
+                budget.spend_elements(elements.len())?;
+
                 let len = elements.len().to_string();
                 // Prefix with header, then concatenate element encodings.
-                out_buf.put_u8(b'*');
+                out_buf.put_u8(match self {
+                    RedisType::Push(_) if version == RespVersion::Resp3 => b'>',
+                    _ => b'*',
+                });
                 out_buf.extend_from_slice(len.as_bytes());
                 out_buf.extend_from_slice(RESP_TERMINATOR);
+                budget.spend_bytes(out_buf.len() - start)?;
+
                 for single_element in elements {
-                    single_element.write_resp_to_buf(out_buf);
+                    single_element.encode_within(out_buf, version, budget)?;
                 }
+                return Ok(());
             }
+            // %<number-of-entries>\r\n<key-1><value-1>...
+            // https://redis.io/docs/latest/develop/reference/protocol-spec/#maps
+            RedisType::Map(entries) => {
+                budget.spend_elements(entries.len())?;
+
+                let len = match version {
+                    RespVersion::Resp2 => entries.len() * 2,
+                    RespVersion::Resp3 => entries.len(),
+                };
+                out_buf.put_u8(match version {
+                    RespVersion::Resp2 => b'*',
+                    RespVersion::Resp3 => b'%',
+                });
+                out_buf.extend_from_slice(len.to_string().as_bytes());
+                out_buf.extend_from_slice(RESP_TERMINATOR);
+                budget.spend_bytes(out_buf.len() - start)?;
+
+                for (key, value) in entries {
+                    key.encode_within(out_buf, version, budget)?;
+                    value.encode_within(out_buf, version, budget)?;
+                }
+                return Ok(());
+            }
+            // |<number-of-entries>\r\n<key-1><value-1>...<reply>
+            // https://redis.io/docs/latest/develop/reference/protocol-spec/#attributes
+            RedisType::Attribute { attributes, reply } => {
+                if version == RespVersion::Resp3 {
+                    budget.spend_elements(attributes.len())?;
+
+                    out_buf.put_u8(b'|');
+                    out_buf.extend_from_slice(attributes.len().to_string().as_bytes());
+                    out_buf.extend_from_slice(RESP_TERMINATOR);
+                    budget.spend_bytes(out_buf.len() - start)?;
+
+                    for (key, value) in attributes {
+                        key.encode_within(out_buf, version, budget)?;
+                        value.encode_within(out_buf, version, budget)?;
+                    }
+                }
+                return reply.encode_within(out_buf, version, budget);
+            }
+            // (<big-number>\r\n
+            // https://redis.io/docs/latest/develop/reference/protocol-spec/#big-numbers
+            RedisType::BigNumber(digits) => match version {
+                RespVersion::Resp2 => {
+                    out_buf.put_u8(b'$');
+                    out_buf.extend_from_slice(digits.len().to_string().as_bytes());
+                    out_buf.extend_from_slice(RESP_TERMINATOR);
+                    out_buf.extend_from_slice(digits.as_bytes());
+                    out_buf.extend_from_slice(RESP_TERMINATOR);
+                }
+                RespVersion::Resp3 => {
+                    out_buf.put_u8(b'(');
+                    out_buf.extend_from_slice(digits.as_bytes());
+                    out_buf.extend_from_slice(RESP_TERMINATOR);
+                }
+            },
             RedisType::NullArray => {
                 out_buf.extend_from_slice(b"*-1\r\n");
             }
@@ -98,33 +325,359 @@ impl ToRespBytes for RedisType {
                 out_buf.extend_from_slice(RESP_TERMINATOR);
             }
         }
+
+        budget.spend_bytes(out_buf.len() - start)
     }
 }
 
+/// Arrays with at least this many elements are written with vectored I/O.
+const VECTORED_WRITE_MIN_ELEMENTS: usize = 64;
+
+/// Bulk string replies with at least this many bytes are written with vectored I/O.
+const VECTORED_WRITE_MIN_BULK_BYTES: usize = 16 * 1024;
+
+/// Replies queued in the output buffer are written out once they reach this size,
+/// even if more pipelined commands are still waiting to be executed.
+const MAX_PENDING_OUTPUT_BYTES: usize = 64 * 1024;
+
+/// Part of an encoded reply: either a range of framing bytes (markers, lengths, terminators and
+/// small values) inside the shared output buffer, or a bulk string payload borrowed in place.
+#[derive(Debug)]
+enum RespSegment<'a> {
+    Framing(Range<usize>),
+    Payload(&'a [u8]),
+}
+
 // Helper to write into an existing TcpStream.
 impl RedisType {
+    /// Returns the framing error of a malformed request, either the request itself or one of its elements.
+    /// Unlike command-level errors, the connection cannot be trusted to be in sync after one of these.
+    pub fn protocol_error(&self) -> Option<&str> {
+        let is_protocol_error = |msg: &str| msg.starts_with(PROTOCOL_ERROR_PREFIX);
+        match self {
+            RedisType::InvalidType(msg) if is_protocol_error(msg) => Some(msg),
+            RedisType::Array(elements) => elements.iter().find_map(|element| match element {
+                RedisType::InvalidType(msg) if is_protocol_error(msg) => Some(msg.as_str()),
+                _ => None,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Appends the reply to `out_buf`, the replies of pipelined commands are written out together
+    /// by the connection handler with `flush_output` once the batch is executed.
+    /// Large replies, and a buffer growing past `MAX_PENDING_OUTPUT_BYTES`, are written right away.
+    ///
+    /// Replies going over `--max-reply-elements`/`--max-reply-bytes` are dropped while being encoded
+    /// and `-ERR reply is too large` is sent instead, nothing of the partial reply reaches the client.
     pub async fn write_resp_to_stream(
         &self,
         out_buf: &mut BytesMut,
         stream: &mut TcpStream,
     ) -> anyhow::Result<()> {
-        out_buf.clear();
-        self.write_resp_to_buf(out_buf);
-        stream.write_all(out_buf).await?;
+        let pending = out_buf.len();
+        let mut budget = ReplyBudget::from_limits();
+
+        if let RedisType::Array(elements) = self
+            && elements.len() >= VECTORED_WRITE_MIN_ELEMENTS
+        {
+            // Large replies (e.g. LRANGE) skip copying every bulk string payload into `out_buf`,
+            // the replies queued before this one go out first in the same vectored write
+            let mut segments = Vec::with_capacity(elements.len() * 3 + 1);
+            push_framing(&mut segments, 0..pending);
+            if self
+                .collect_resp_segments(out_buf, &mut segments, current_resp_version(), &mut budget)
+                .is_err()
+            {
+                return write_reply_too_large(pending, out_buf, stream).await;
+            }
+
+            let mut slices: Vec<IoSlice<'_>> = segments
+                .iter()
+                .map(|segment| match segment {
+                    RespSegment::Framing(range) => IoSlice::new(&out_buf[range.clone()]),
+                    RespSegment::Payload(payload) => IoSlice::new(payload),
+                })
+                .collect();
+
+            write_all_vectored(stream, &mut slices).await?;
+            out_buf.clear();
+            return Ok(());
+        }
+
+        if self
+            .encode_within(out_buf, current_resp_version(), &mut budget)
+            .is_err()
+        {
+            return write_reply_too_large(pending, out_buf, stream).await;
+        }
+        flush_output_over_limit(out_buf, stream).await
+    }
+
+    /// Same encoding as `encode`, but bulk string payloads are referenced instead of copied.
+    /// Adjacent framing bytes are merged into a single segment.
+    fn collect_resp_segments<'a>(
+        &'a self,
+        framing_buf: &mut BytesMut,
+        segments: &mut Vec<RespSegment<'a>>,
+        version: RespVersion,
+        budget: &mut ReplyBudget,
+    ) -> Result<(), ReplyTooLarge> {
+        let framing_start = framing_buf.len();
+
+        match self {
+            RedisType::Array(elements) => {
+                budget.spend_elements(elements.len())?;
+
+                framing_buf.put_u8(b'*');
+                framing_buf.extend_from_slice(elements.len().to_string().as_bytes());
+                framing_buf.extend_from_slice(RESP_TERMINATOR);
+                budget.spend_bytes(framing_buf.len() - framing_start)?;
+                push_framing(segments, framing_start..framing_buf.len());
+
+                for single_element in elements {
+                    single_element.collect_resp_segments(framing_buf, segments, version, budget)?;
+                }
+            }
+            RedisType::BulkString(value) if !value.is_empty() => {
+                framing_buf.put_u8(b'$');
+                framing_buf.extend_from_slice(value.len().to_string().as_bytes());
+                framing_buf.extend_from_slice(RESP_TERMINATOR);
+                let header_len = framing_buf.len() - framing_start;
+                budget.spend_bytes(header_len + value.len() + RESP_TERMINATOR.len())?;
+                push_framing(segments, framing_start..framing_buf.len());
+
+                segments.push(RespSegment::Payload(value));
+
+                let terminator_start = framing_buf.len();
+                framing_buf.extend_from_slice(RESP_TERMINATOR);
+                push_framing(segments, terminator_start..framing_buf.len());
+            }
+            _ => {
+                self.encode_within(framing_buf, version, budget)?;
+                push_framing(segments, framing_start..framing_buf.len());
+            }
+        }
+
         Ok(())
     }
 }
 
-pub fn try_parse_frame(buf: &BytesMut) -> Option<(RedisType, usize)> {
+/// Writes `value` as a bulk string reply without building a `RedisType::BulkString` for it.
+/// Large values (e.g. a multi-megabyte GET) are written in place next to their encoded header,
+/// instead of being copied into `out_buf` first, so the reply doesn't hold the value twice.
+pub async fn write_bulk_from_slice(
+    value: &[u8],
+    out_buf: &mut BytesMut,
+    stream: &mut TcpStream,
+) -> anyhow::Result<()> {
+    let pending = out_buf.len();
+
+    out_buf.put_u8(b'$');
+    out_buf.extend_from_slice(value.len().to_string().as_bytes());
+    out_buf.extend_from_slice(RESP_TERMINATOR);
+
+    if ReplyBudget::from_limits()
+        .spend_bytes(out_buf.len() - pending + value.len() + RESP_TERMINATOR.len())
+        .is_err()
+    {
+        return write_reply_too_large(pending, out_buf, stream).await;
+    }
+
+    if value.len() < VECTORED_WRITE_MIN_BULK_BYTES {
+        out_buf.extend_from_slice(value);
+        out_buf.extend_from_slice(RESP_TERMINATOR);
+        return flush_output_over_limit(out_buf, stream).await;
+    }
+
+    let mut slices = [
+        IoSlice::new(out_buf),
+        IoSlice::new(value),
+        IoSlice::new(RESP_TERMINATOR),
+    ];
+    write_all_vectored(stream, &mut slices).await?;
+    out_buf.clear();
+    Ok(())
+}
+
+/// Writes an array of `len` bulk strings taken from `elements`, encoding them one at a time instead of
+/// building a `RedisType::Array` first: the memory used doesn't grow with `len` (e.g. SRANDMEMBER -count).
+/// With `--max-reply-bytes` set, `elements` is walked twice, once to check the limit before anything is sent.
+pub async fn write_bulk_array_from_iter<'a, I>(
+    len: usize,
+    elements: I,
+    out_buf: &mut BytesMut,
+    stream: &mut TcpStream,
+) -> anyhow::Result<()>
+where
+    I: Iterator<Item = &'a [u8]> + Clone,
+{
+    let pending = out_buf.len();
+    let header = format!("*{len}\r\n");
+    let mut budget = ReplyBudget::from_limits();
+    let bytes_limited = budget.bytes != usize::MAX;
+
+    let within_limits = budget.spend_elements(len).is_ok()
+        && budget.spend_bytes(header.len()).is_ok()
+        && (!bytes_limited
+            || elements.clone().take(len).all(|element| {
+                let frame_len = 1 + element.len().to_string().len() + element.len() + 4;
+                budget.spend_bytes(frame_len).is_ok()
+            }));
+    if !within_limits {
+        return write_reply_too_large(pending, out_buf, stream).await;
+    }
+
+    out_buf.extend_from_slice(header.as_bytes());
+    for element in elements.take(len) {
+        out_buf.put_u8(b'$');
+        out_buf.extend_from_slice(element.len().to_string().as_bytes());
+        out_buf.extend_from_slice(RESP_TERMINATOR);
+        out_buf.extend_from_slice(element);
+        out_buf.extend_from_slice(RESP_TERMINATOR);
+        flush_output_over_limit(out_buf, stream).await?;
+    }
+    Ok(())
+}
+
+/// Writes out every reply queued in `out_buf` with a single write.
+pub async fn flush_output(out_buf: &mut BytesMut, stream: &mut TcpStream) -> anyhow::Result<()> {
+    if !out_buf.is_empty() {
+        stream.write_all(out_buf).await?;
+        out_buf.clear();
+    }
+    Ok(())
+}
+
+async fn flush_output_over_limit(
+    out_buf: &mut BytesMut,
+    stream: &mut TcpStream,
+) -> anyhow::Result<()> {
+    if out_buf.len() >= MAX_PENDING_OUTPUT_BYTES {
+        flush_output(out_buf, stream).await?;
+    }
+    Ok(())
+}
+
+/// Replaces a reply that went over the limits, whatever was encoded of it after `pending` is discarded.
+async fn write_reply_too_large(
+    pending: usize,
+    out_buf: &mut BytesMut,
+    stream: &mut TcpStream,
+) -> anyhow::Result<()> {
+    tracing::warn!("Reply dropped, it exceeds the configured reply limits");
+
+    out_buf.truncate(pending);
+    RedisType::SimpleError(REPLY_TOO_LARGE_ERROR.to_string()).write_resp_to_buf(out_buf);
+    flush_output_over_limit(out_buf, stream).await
+}
+
+fn push_framing(segments: &mut Vec<RespSegment<'_>>, range: Range<usize>) {
+    if let Some(RespSegment::Framing(last)) = segments.last_mut()
+        && last.end == range.start
+    {
+        last.end = range.end;
+        return;
+    }
+    segments.push(RespSegment::Framing(range));
+}
+
+async fn write_all_vectored(
+    stream: &mut TcpStream,
+    mut slices: &mut [IoSlice<'_>],
+) -> std::io::Result<()> {
+    while !slices.is_empty() {
+        let written = stream.write_vectored(slices).await?;
+        if written == 0 {
+            return Err(std::io::ErrorKind::WriteZero.into());
+        }
+        IoSlice::advance_slices(&mut slices, written);
+    }
+    Ok(())
+}
+
+/// Parses one request: a RESP value, or an inline command (`PING\r\n`) as sent by telnet, nc
+/// or health checkers, which is returned as an Array of BulkStrings like a regular request.
+pub fn try_parse_frame(buf: &[u8]) -> Option<(RedisType, usize)> {
     if buf.is_empty() {
         return None;
     }
+    if !RESP_TYPE_MARKERS.contains(&buf[0]) {
+        return try_parse_inline(buf);
+    }
     // Parse from the current buffer start and return how many bytes were consumed.
-    let mut fwd = ForwardBuf { buf, offset: 0 };
+    let mut fwd = ForwardBuf::new(buf);
     let parsed_redis_type = try_parse_type_forward(&mut fwd)?;
     Some((parsed_redis_type, fwd.offset))
 }
 
+///
+/// Inline command: space separated arguments up to CRLF.
+/// https://redis.io/docs/latest/develop/reference/protocol-spec/#inline-commands
+///
+/// An argument may be double-quoted to contain spaces, with `\"`, `\\`, `\n`, `\r` and `\t` escapes inside.
+///
+fn try_parse_inline(buf: &[u8]) -> Option<(RedisType, usize)> {
+    let mut fwd = ForwardBuf::new(buf);
+    let line = fwd.consume_part_bytes()?;
+
+    let parsed_redis_type = match split_inline_arguments(&line) {
+        Some(arguments) => RedisType::Array(
+            arguments
+                .into_iter()
+                .map(|argument| RedisType::BulkString(argument.into()))
+                .collect(),
+        ),
+        None => RedisType::InvalidType(UNBALANCED_QUOTES_ERROR.to_owned()),
+    };
+    Some((parsed_redis_type, fwd.offset))
+}
+
+/// None when a quoted argument is not closed, or is directly followed by something else than a space.
+fn split_inline_arguments(line: &[u8]) -> Option<Vec<Vec<u8>>> {
+    let mut arguments = Vec::new();
+    let mut bytes = line.iter().copied().peekable();
+
+    loop {
+        while bytes.next_if(|byte| byte.is_ascii_whitespace()).is_some() {}
+        let Some(first) = bytes.next() else {
+            return Some(arguments);
+        };
+
+        let mut argument = Vec::new();
+        if first == b'"' {
+            loop {
+                match bytes.next()? {
+                    b'"' => break,
+                    b'\\' => argument.push(match bytes.next()? {
+                        b'n' => b'\n',
+                        b'r' => b'\r',
+                        b't' => b'\t',
+                        escaped => escaped,
+                    }),
+                    byte => argument.push(byte),
+                }
+            }
+            if bytes.peek().is_some_and(|byte| !byte.is_ascii_whitespace()) {
+                return None;
+            }
+        } else {
+            argument.push(first);
+            while let Some(byte) = bytes.next_if(|byte| !byte.is_ascii_whitespace()) {
+                argument.push(byte);
+            }
+        }
+        arguments.push(argument);
+    }
+}
+
+/// Entry point for fuzzers and the property tests below: parses untrusted bytes, which must never
+/// panic, loop forever or consume more bytes than given, whatever they contain.
+#[allow(dead_code)]
+pub fn parse_for_fuzz(data: &[u8]) -> Option<(RedisType, usize)> {
+    try_parse_frame(data)
+}
+
 /// Used inside unit tests ONLY.
 #[allow(dead_code)]
 fn try_parse_type(buf: &BytesMut) -> Option<RedisType> {
@@ -162,15 +715,24 @@ fn try_parse_type_forward(buf: &mut ForwardBuf) -> Option<RedisType> {
 
                 if len < 0 {
                     return Some(RedisType::InvalidType(
-                        format!("Invalid array length {len}").to_owned(),
+                        INVALID_MULTIBULK_LENGTH_ERROR.to_owned(),
                     ));
                 }
 
-                let mut elements = Vec::with_capacity(len as usize);
+                if buf.depth >= MAX_NESTING_DEPTH {
+                    return Some(RedisType::InvalidType(NESTING_TOO_DEEP_ERROR.to_owned()));
+                }
+
+                // The declared length is untrusted, every element takes at least one buffered byte
+                let mut elements = Vec::with_capacity((len as usize).min(buf.remaining()));
 
                 // Read all array elements recursively
+                buf.depth += 1;
                 for _i in 0..len {
                     match try_parse_type_forward(buf) {
+                        Some(RedisType::InvalidType(msg)) if msg == NESTING_TOO_DEEP_ERROR => {
+                            return Some(RedisType::InvalidType(msg));
+                        }
                         Some(elem) => elements.push(elem),
                         None => {
                             // Incomplete input: propagate None so the caller can read more bytes.
@@ -178,13 +740,14 @@ fn try_parse_type_forward(buf: &mut ForwardBuf) -> Option<RedisType> {
                         }
                     }
                 }
+                buf.depth -= 1;
 
                 Some(RedisType::Array(elements))
             } else {
                 tracing::warn!("Can't parse array length {arr_length}");
 
                 Some(RedisType::InvalidType(
-                    format!("Array length not a number {arr_length}").to_owned(),
+                    INVALID_MULTIBULK_LENGTH_ERROR.to_owned(),
                 ))
             }
         }
@@ -201,30 +764,26 @@ fn try_parse_type_forward(buf: &mut ForwardBuf) -> Option<RedisType> {
                 }
 
                 if len < 0 {
-                    return Some(RedisType::InvalidType(
-                        format!("Invalid bulk string length {len}").to_owned(),
-                    ));
+                    return Some(RedisType::InvalidType(INVALID_BULK_LENGTH_ERROR.to_owned()));
                 }
 
-                if let Some(str_value) = buf.consume_part() {
-                    // Note: For compatibility with previous implementation, we do not enforce
-                    // that str_value.len() == len here.
-                    Some(RedisType::BulkString(str_value))
-                } else {
-                    tracing::warn!("Can't fully read BulkString");
-                    None
+                match buf.consume_bulk(len as usize)? {
+                    Some(value) => Some(RedisType::BulkString(value)),
+                    None => Some(RedisType::InvalidType(BULK_TERMINATOR_ERROR.to_owned())),
                 }
             } else {
-                Some(RedisType::InvalidType(
-                    format!("Bulk string length not a number {len_value}").to_owned(),
-                ))
+                tracing::warn!("Can't parse bulk string length {len_value}");
+
+                Some(RedisType::InvalidType(INVALID_BULK_LENGTH_ERROR.to_owned()))
             }
         }
         // Integer :[<+|->]<value>\r\n
         // https://redis.io/docs/latest/develop/reference/protocol-spec/#integers
         b':' => {
             if let Some(integer_as_str) = buf.consume_part() {
-                if let Ok(integer_value) = integer_as_str.parse::<i32>() {
+                if integer_as_str.is_empty() {
+                    Some(RedisType::InvalidType(INVALID_INTEGER_ERROR.to_owned()))
+                } else if let Ok(integer_value) = integer_as_str.parse::<i64>() {
                     Some(RedisType::Integer(integer_value))
                 } else {
                     Some(RedisType::InvalidType(
@@ -235,22 +794,84 @@ fn try_parse_type_forward(buf: &mut ForwardBuf) -> Option<RedisType> {
                 Some(RedisType::InvalidType("Can't read integer".to_owned()))
             }
         }
-        _ => {
-            todo!("Unsupported type marker byte: '{}'", marker_byte as char)
+        //
+        // Attributes: |<number-of-entries>\r\n<key-1><value-1>...<key-n><value-n>, then the value they describe
+        // https://redis.io/docs/latest/develop/reference/protocol-spec/#attributes
+        //
+        b'|' => {
+            let Ok(len) = buf.consume_part()?.parse::<usize>() else {
+                return Some(RedisType::InvalidType(
+                    INVALID_MULTIBULK_LENGTH_ERROR.to_owned(),
+                ));
+            };
+
+            if buf.depth >= MAX_NESTING_DEPTH {
+                return Some(RedisType::InvalidType(NESTING_TOO_DEEP_ERROR.to_owned()));
+            }
+
+            // Every entry takes at least two buffered bytes
+            let mut attributes = Vec::with_capacity(len.min(buf.remaining()));
+
+            buf.depth += 1;
+            for _i in 0..len {
+                let key = try_parse_type_forward(buf)?;
+                let value = try_parse_type_forward(buf)?;
+                if let Some(nesting_error) = [&key, &value].into_iter().find(|element| {
+                    matches!(element, RedisType::InvalidType(msg) if msg == NESTING_TOO_DEEP_ERROR)
+                }) {
+                    return Some(nesting_error.clone());
+                }
+                attributes.push((key, value));
+            }
+            let reply = try_parse_type_forward(buf)?;
+            buf.depth -= 1;
+
+            Some(RedisType::Attribute {
+                attributes,
+                reply: Box::new(reply),
+            })
+        }
+        // Big numbers: ([+|-]<number>\r\n
+        // https://redis.io/docs/latest/develop/reference/protocol-spec/#big-numbers
+        b'(' => {
+            let digits = buf.consume_part()?;
+            let unsigned = digits.strip_prefix(['+', '-']).unwrap_or(&digits);
+            if unsigned.is_empty() || !unsigned.bytes().all(|byte| byte.is_ascii_digit()) {
+                return Some(RedisType::InvalidType(INVALID_BIG_NUMBER_ERROR.to_owned()));
+            }
+            Some(RedisType::BigNumber(digits))
         }
+        _ => Some(RedisType::InvalidType(format!(
+            "{PROTOCOL_ERROR_PREFIX}expected '$', got '{}'",
+            marker_byte as char
+        ))),
     }
 }
 
 struct ForwardBuf<'a> {
-    buf: &'a BytesMut,
+    buf: &'a [u8],
     offset: usize,
+    // Arrays being parsed around the current position
+    depth: usize,
 }
 
-impl ForwardBuf<'_> {
+impl<'a> ForwardBuf<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self {
+            buf,
+            offset: 0,
+            depth: 0,
+        }
+    }
+
     fn at_end(&self) -> bool {
         self.offset >= self.buf.len()
     }
 
+    fn remaining(&self) -> usize {
+        self.buf.len().saturating_sub(self.offset)
+    }
+
     fn consume_byte(&mut self) -> u8 {
         let value = self.buf[self.offset];
         self.offset += 1;
@@ -271,15 +892,37 @@ impl ForwardBuf<'_> {
 
     // Reads from current offset to CRLF and advances offset past CRLF
     fn consume_part(&mut self) -> Option<String> {
+        self.consume_part_bytes()
+            .map(|part| String::from_utf8_lossy(&part).into_owned())
+    }
+
+    fn consume_part_bytes(&mut self) -> Option<Bytes> {
         let pos = self.find_delimiters_position();
         if pos < 0 {
             return None;
         }
         let end = pos as usize;
-        let s = String::from_utf8_lossy(&self.buf[self.offset..end]).into_owned();
+        let part = Bytes::copy_from_slice(&self.buf[self.offset..end]);
         // advance past content and CRLF
         self.offset = end + 2;
-        Some(s)
+        Some(part)
+    }
+
+    // Reads a bulk string payload of exactly `len` bytes and its CRLF, the payload itself may contain any byte.
+    // None until the whole payload is buffered, Some(None) when the declared length is not followed by CRLF.
+    fn consume_bulk(&mut self, len: usize) -> Option<Option<Bytes>> {
+        let end = self.offset.checked_add(len)?;
+        if self.buf.len() < end.checked_add(RESP_TERMINATOR.len())? {
+            return None;
+        }
+
+        if &self.buf[end..end + RESP_TERMINATOR.len()] != RESP_TERMINATOR {
+            return Some(None);
+        }
+
+        let payload = Bytes::copy_from_slice(&self.buf[self.offset..end]);
+        self.offset = end + RESP_TERMINATOR.len();
+        Some(Some(payload))
     }
 }
 
@@ -312,8 +955,8 @@ mod tests {
     //
     #[test]
     fn parse_bulk_string() {
-        assert_for_content("$4\r\nbulk\r\n", RedisType::BulkString("bulk".to_owned()));
-        assert_for_content("$0\r\n\r\n", RedisType::BulkString("".to_owned()));
+        assert_for_content("$4\r\nbulk\r\n", RedisType::BulkString("bulk".into()));
+        assert_for_content("$0\r\n\r\n", RedisType::BulkString("".into()));
     }
 
     #[test]
@@ -321,6 +964,24 @@ mod tests {
         assert_for_content("$-1\r\n", RedisType::NullBulkString);
     }
 
+    #[test]
+    fn parse_bulk_string_binary() {
+        // Payload is read by its declared length, so CRLF and non UTF-8 bytes are kept as is
+        let buf = BytesMut::from(&b"$4\r\n\x00\xff\r\n\r\n"[..]);
+        assert_eq!(
+            Some((
+                RedisType::BulkString(Bytes::from_static(b"\x00\xff\r\n")),
+                10
+            )),
+            try_parse_frame(&buf)
+        );
+
+        assert_for_content("$5\r\na\r\nbc\r\n", RedisType::BulkString("a\r\nbc".into()));
+
+        // Not fully buffered yet, even though a CRLF is already there
+        assert_none_for_content("$5\r\na\r\nb");
+    }
+
     #[test]
     fn parse_bulk_string_incorrect() {
         assert_none_for_content("$4\r\nbulk");
@@ -329,16 +990,28 @@ mod tests {
 
         assert_none_for_content("$4\r\nbulk\n");
 
-        assert_none_for_content("$4\r\nbulk\n\r");
+        // The declared length must be followed by CRLF
+        assert_for_content(
+            "$4\r\nbulk\n\r",
+            RedisType::InvalidType(BULK_TERMINATOR_ERROR.to_owned()),
+        );
+        assert_for_content(
+            "$3\r\nhello\r\n",
+            RedisType::InvalidType(BULK_TERMINATOR_ERROR.to_owned()),
+        );
+        assert_for_content(
+            "$6\r\nhello\r\n+OK\r\n",
+            RedisType::InvalidType(BULK_TERMINATOR_ERROR.to_owned()),
+        );
 
         assert_for_content(
             "$-2\r\nbulk\r\n",
-            RedisType::InvalidType("Invalid bulk string length -2".to_owned()),
+            RedisType::InvalidType("ERR Protocol error: invalid bulk length".to_owned()),
         );
 
         assert_for_content(
             "$abc\r\nbulk\r\n",
-            RedisType::InvalidType("Bulk string length not a number abc".to_owned()),
+            RedisType::InvalidType("ERR Protocol error: invalid bulk length".to_owned()),
         );
     }
 
@@ -364,8 +1037,8 @@ mod tests {
             "*3\r\n+PONG\r\n$4\r\nbulk\r\n$0\r\n\r\n",
             RedisType::Array(vec![
                 RedisType::SimpleString("PONG".to_owned()),
-                RedisType::BulkString("bulk".to_owned()),
-                RedisType::BulkString("".to_owned()),
+                RedisType::BulkString("bulk".into()),
+                RedisType::BulkString("".into()),
             ]),
         );
 
@@ -390,15 +1063,28 @@ mod tests {
         assert_for_content(
             "*5\r\n$5\r\nhello\r\n$-1\r\n$5\r\nworld\r\n$8\r\nvalkyrie\r\n$11\r\nis the best\r\n",
             RedisType::Array(vec![
-                RedisType::BulkString("hello".to_owned()),
+                RedisType::BulkString("hello".into()),
                 RedisType::NullBulkString,
-                RedisType::BulkString("world".to_owned()),
-                RedisType::BulkString("valkyrie".to_owned()),
-                RedisType::BulkString("is the best".to_owned()),
+                RedisType::BulkString("world".into()),
+                RedisType::BulkString("valkyrie".into()),
+                RedisType::BulkString("is the best".into()),
             ]),
         );
     }
 
+    #[test]
+    fn parse_array_bulk_length_mismatch_is_protocol_error() {
+        let parsed = parse_raw_value("*2\r\n$4\r\nECHO\r\n$2\r\nhello\r\n").expect("parsed");
+        assert_eq!(
+            parsed,
+            RedisType::Array(vec![
+                RedisType::BulkString("ECHO".into()),
+                RedisType::InvalidType(BULK_TERMINATOR_ERROR.to_owned()),
+            ])
+        );
+        assert_eq!(parsed.protocol_error(), Some(BULK_TERMINATOR_ERROR));
+    }
+
     #[test]
     fn parse_array_null() {
         assert_for_content("*-1\r\n", RedisType::NullArray);
@@ -414,7 +1100,7 @@ mod tests {
                     RedisType::SimpleString("A".to_owned()),
                     RedisType::SimpleString("B".to_owned()),
                 ]),
-                RedisType::BulkString("abc".to_owned()),
+                RedisType::BulkString("abc".into()),
             ]),
         );
 
@@ -424,7 +1110,7 @@ mod tests {
             RedisType::Array(vec![
                 RedisType::Array(vec![RedisType::SimpleString("X".to_owned())]),
                 RedisType::NullArray,
-                RedisType::BulkString("".to_owned()),
+                RedisType::BulkString("".into()),
             ]),
         );
     }
@@ -449,13 +1135,13 @@ mod tests {
         // Invalid negative length (except -1)
         assert_for_content(
             "*-2\r\n",
-            RedisType::InvalidType("Invalid array length -2".to_owned()),
+            RedisType::InvalidType("ERR Protocol error: invalid multibulk length".to_owned()),
         );
 
         // Non-numeric length
         assert_for_content(
             "*abc\r\n",
-            RedisType::InvalidType("Array length not a number abc".to_owned()),
+            RedisType::InvalidType("ERR Protocol error: invalid multibulk length".to_owned()),
         );
     }
 
@@ -472,16 +1158,17 @@ mod tests {
         assert_for_content(":2147483647\r\n", RedisType::Integer(2147483647));
         assert_for_content(":-2147483647\r\n", RedisType::Integer(-2147483647));
         assert_for_content(":-2147483648\r\n", RedisType::Integer(-2147483648));
+
+        // Beyond the i32 range
+        assert_for_content(":2147483648\r\n", RedisType::Integer(2147483648));
+        assert_for_content(":-2147483649\r\n", RedisType::Integer(-2147483649));
+        assert_for_content(":5000000000\r\n", RedisType::Integer(5_000_000_000));
+        assert_for_content(":9223372036854775807\r\n", RedisType::Integer(i64::MAX));
+        assert_for_content(":-9223372036854775808\r\n", RedisType::Integer(i64::MIN));
     }
 
     #[test]
     fn parse_integer_incorrect() {
-        // Empty integer after marker
-        assert_for_content(
-            ":\r\n",
-            RedisType::InvalidType("Invalid integer ".to_owned()),
-        );
-
         // Sign only
         assert_for_content(
             ":+\r\n",
@@ -531,18 +1218,148 @@ mod tests {
             RedisType::InvalidType("Can't read integer".to_owned()),
         );
 
-        // // Overflow/underflow: values outside i32 range
+        // // Overflow/underflow: values outside i64 range
         assert_for_content(
-            ":2147483648\r\n",
-            RedisType::InvalidType("Invalid integer 2147483648".to_owned()),
+            ":9223372036854775808\r\n",
+            RedisType::InvalidType("Invalid integer 9223372036854775808".to_owned()),
         );
 
         assert_for_content(
-            ":-2147483649\r\n",
-            RedisType::InvalidType("Invalid integer -2147483649".to_owned()),
+            ":-9223372036854775809\r\n",
+            RedisType::InvalidType("Invalid integer -9223372036854775809".to_owned()),
+        );
+    }
+
+    #[test]
+    fn parse_empty_length_fields() {
+        assert_for_content(
+            "*\r\n",
+            RedisType::InvalidType("ERR Protocol error: invalid multibulk length".to_owned()),
+        );
+        assert_for_content(
+            "$\r\n",
+            RedisType::InvalidType("ERR Protocol error: invalid bulk length".to_owned()),
+        );
+        assert_for_content(
+            ":\r\n",
+            RedisType::InvalidType("ERR Protocol error: invalid integer".to_owned()),
+        );
+
+        // Inside an array the error is kept as the element
+        assert_for_content(
+            "*1\r\n$\r\n",
+            RedisType::Array(vec![RedisType::InvalidType(
+                "ERR Protocol error: invalid bulk length".to_owned(),
+            )]),
+        );
+
+        // A lone marker is still incomplete input
+        assert_none_for_content("*");
+        assert_none_for_content("$");
+    }
+
+    #[test]
+    fn parse_unknown_type_marker() {
+        // RESP3 markers other than attributes and big numbers are not accepted in requests
+        assert_for_content(
+            "*1\r\n%1\r\n+a\r\n+b\r\n",
+            RedisType::Array(vec![RedisType::InvalidType(
+                "ERR Protocol error: expected '$', got '%'".to_owned(),
+            )]),
+        );
+        assert_for_content(
+            "*1\r\n#t\r\n",
+            RedisType::Array(vec![RedisType::InvalidType(
+                "ERR Protocol error: expected '$', got '#'".to_owned(),
+            )]),
+        );
+        // A simple error is a reply, not a request
+        assert_for_content(
+            "-ERR\r\n",
+            RedisType::InvalidType("ERR Protocol error: expected '$', got '-'".to_owned()),
+        );
+        assert_for_content(
+            "*1\r\n!oops\r\n",
+            RedisType::Array(vec![RedisType::InvalidType(
+                "ERR Protocol error: expected '$', got '!'".to_owned(),
+            )]),
         );
     }
 
+    //
+    // Inline commands
+    //
+    fn inline(arguments: &[&str]) -> RedisType {
+        RedisType::Array(
+            arguments
+                .iter()
+                .map(|argument| RedisType::BulkString(Bytes::copy_from_slice(argument.as_bytes())))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn parse_inline_command() {
+        assert_for_content("PING\r\n", inline(&["PING"]));
+        assert_for_content("ECHO hi\r\n", inline(&["ECHO", "hi"]));
+        assert_for_content("  SET  key   value \r\n", inline(&["SET", "key", "value"]));
+
+        // Anything that is not a RESP type marker starts an inline command
+        assert_for_content(" PING\r\n", inline(&["PING"]));
+        assert_for_content("%1\r\n", inline(&["%1"]));
+
+        // Blank line
+        assert_for_content("\r\n", inline(&[]));
+
+        // Pipelined: only the first line is consumed
+        let buf = BytesMut::from("PING\r\nECHO hi\r\n");
+        assert_eq!(Some((inline(&["PING"]), 6)), try_parse_frame(&buf));
+    }
+
+    #[test]
+    fn parse_inline_command_quoted() {
+        assert_for_content(
+            "SET key \"hello world\"\r\n",
+            inline(&["SET", "key", "hello world"]),
+        );
+        assert_for_content("ECHO \"\"\r\n", inline(&["ECHO", ""]));
+        assert_for_content(
+            "ECHO \"say \\\"hi\\\"\\n\"\r\n",
+            inline(&["ECHO", "say \"hi\"\n"]),
+        );
+
+        let unbalanced = RedisType::InvalidType(UNBALANCED_QUOTES_ERROR.to_owned());
+        assert_for_content("ECHO \"hi\r\n", unbalanced.clone());
+        assert_for_content("ECHO \"hi\"there\r\n", unbalanced.clone());
+        assert_eq!(unbalanced.protocol_error(), Some(UNBALANCED_QUOTES_ERROR));
+    }
+
+    #[test]
+    fn parse_inline_command_incomplete() {
+        assert_none_for_content("PING");
+        assert_none_for_content("PING\r");
+        assert_none_for_content("ECHO \"hi");
+    }
+
+    #[test]
+    fn protocol_error_of_request() {
+        let malformed = RedisType::Array(vec![
+            RedisType::BulkString("ECHO".into()),
+            RedisType::InvalidType("ERR Protocol error: invalid bulk length".to_owned()),
+        ]);
+        assert_eq!(
+            malformed.protocol_error(),
+            Some("ERR Protocol error: invalid bulk length")
+        );
+
+        // Value-level parse failures are not framing errors
+        let invalid_integer = RedisType::InvalidType("Invalid integer 12a".to_owned());
+        assert_eq!(invalid_integer.protocol_error(), None);
+
+        let valid = RedisType::Array(vec![RedisType::BulkString("PING".into())]);
+        assert_eq!(valid.protocol_error(), None);
+    }
+
     //
     // Assertion helpers
     //
@@ -576,7 +1393,7 @@ mod tests {
     #[test]
     fn encode_bulk_string() {
         let mut buf = BytesMut::new();
-        RedisType::BulkString("bulk".to_owned()).write_resp_to_buf(&mut buf);
+        RedisType::BulkString("bulk".into()).write_resp_to_buf(&mut buf);
         assert_eq!(b"$4\r\nbulk\r\n", &buf[..]);
     }
 
@@ -594,6 +1411,24 @@ mod tests {
         assert_eq!(b":-42\r\n", &buf[..]);
     }
 
+    #[test]
+    fn encode_integer_beyond_i32() {
+        let mut buf = BytesMut::new();
+        RedisType::Integer(i64::MAX).write_resp_to_buf(&mut buf);
+        assert_eq!(b":9223372036854775807\r\n", &buf[..]);
+
+        let mut buf = BytesMut::new();
+        RedisType::Integer(i64::MIN).write_resp_to_buf(&mut buf);
+        assert_eq!(b":-9223372036854775808\r\n", &buf[..]);
+    }
+
+    #[test]
+    fn encode_bulk_string_binary() {
+        let mut buf = BytesMut::new();
+        RedisType::BulkString(Bytes::from_static(b"\x00\xff\r\n")).write_resp_to_buf(&mut buf);
+        assert_eq!(b"$4\r\n\x00\xff\r\n\r\n", &buf[..]);
+    }
+
     #[test]
     fn encode_array_simple() {
         let mut buf = BytesMut::new();
@@ -610,8 +1445,8 @@ mod tests {
         let mut buf = BytesMut::new();
         RedisType::Array(vec![
             RedisType::SimpleString("PONG".to_owned()),
-            RedisType::BulkString("bulk".to_owned()),
-            RedisType::BulkString("".to_owned()),
+            RedisType::BulkString("bulk".into()),
+            RedisType::BulkString("".into()),
         ])
         .write_resp_to_buf(&mut buf);
         assert_eq!(b"*3\r\n+PONG\r\n$4\r\nbulk\r\n$0\r\n\r\n", &buf[..]);
@@ -625,7 +1460,7 @@ mod tests {
                 RedisType::SimpleString("A".to_owned()),
                 RedisType::SimpleString("B".to_owned()),
             ]),
-            RedisType::BulkString("abc".to_owned()),
+            RedisType::BulkString("abc".into()),
             RedisType::NullArray,
             RedisType::NullBulkString,
         ])
@@ -640,6 +1475,261 @@ mod tests {
         assert_eq!(expected, &buf[..]);
     }
 
+    #[test]
+    fn encode_large_array_segments_match_buffer_encoding() {
+        let mut elements: Vec<RedisType> = (0..1_000)
+            .map(|i| RedisType::BulkString(format!("value-{i}").repeat(i % 7).into()))
+            .collect();
+        elements.push(RedisType::NullBulkString);
+        elements.push(RedisType::Integer(i64::MAX));
+        elements.push(RedisType::Array(vec![
+            RedisType::BulkString("nested".into()),
+            RedisType::Array(vec![]),
+        ]));
+        let array = RedisType::Array(elements);
+
+        let mut expected = BytesMut::new();
+        array.write_resp_to_buf(&mut expected);
+
+        let mut framing_buf = BytesMut::new();
+        let mut segments = Vec::new();
+        array
+            .collect_resp_segments(
+                &mut framing_buf,
+                &mut segments,
+                RespVersion::Resp2,
+                &mut ReplyBudget::unlimited(),
+            )
+            .expect("no limits");
+
+        let mut actual = Vec::new();
+        for segment in &segments {
+            match segment {
+                RespSegment::Framing(range) => {
+                    actual.extend_from_slice(&framing_buf[range.clone()])
+                }
+                RespSegment::Payload(payload) => actual.extend_from_slice(payload),
+            }
+        }
+
+        assert_eq!(expected.as_ref(), actual.as_slice());
+
+        // Framing is merged: no two framing segments are adjacent
+        assert!(
+            segments
+                .windows(2)
+                .all(|pair| !matches!(pair, [RespSegment::Framing(_), RespSegment::Framing(_)]))
+        );
+    }
+
+    #[test]
+    fn encode_nulls_for_resp3() {
+        for null in [
+            RedisType::NullBulkString,
+            RedisType::NullArray,
+            RedisType::Null,
+        ] {
+            let mut buf = BytesMut::new();
+            null.encode(&mut buf, RespVersion::Resp3);
+            assert_eq!(b"_\r\n", &buf[..]);
+        }
+    }
+
+    #[test]
+    fn encode_map_for_each_version() {
+        let map = RedisType::Map(vec![
+            (RedisType::BulkString("proto".into()), RedisType::Integer(3)),
+            (
+                RedisType::BulkString("modules".into()),
+                RedisType::Array(vec![]),
+            ),
+        ]);
+
+        let mut buf = BytesMut::new();
+        map.encode(&mut buf, RespVersion::Resp2);
+        assert_eq!(
+            b"*4\r\n$5\r\nproto\r\n:3\r\n$7\r\nmodules\r\n*0\r\n",
+            &buf[..]
+        );
+
+        let mut buf = BytesMut::new();
+        map.encode(&mut buf, RespVersion::Resp3);
+        assert_eq!(
+            b"%2\r\n$5\r\nproto\r\n:3\r\n$7\r\nmodules\r\n*0\r\n",
+            &buf[..]
+        );
+    }
+
+    fn caching_hint() -> RedisType {
+        RedisType::Attribute {
+            attributes: vec![(
+                RedisType::BulkString("key-popularity".into()),
+                RedisType::Map(vec![(
+                    RedisType::BulkString("a".into()),
+                    RedisType::BulkString("0.1923".into()),
+                )]),
+            )],
+            reply: Box::new(RedisType::Array(vec![
+                RedisType::Integer(2039123),
+                RedisType::Integer(9543892),
+            ])),
+        }
+    }
+
+    #[test]
+    fn encode_attribute_for_each_version() {
+        // RESP2 has no attributes, only the reply is sent
+        let mut buf = BytesMut::new();
+        caching_hint().encode(&mut buf, RespVersion::Resp2);
+        assert_eq!(b"*2\r\n:2039123\r\n:9543892\r\n", &buf[..]);
+
+        let mut buf = BytesMut::new();
+        caching_hint().encode(&mut buf, RespVersion::Resp3);
+        assert_eq!(
+            &b"|1\r\n$14\r\nkey-popularity\r\n%1\r\n$1\r\na\r\n$6\r\n0.1923\r\n*2\r\n:2039123\r\n:9543892\r\n"[..],
+            &buf[..]
+        );
+    }
+
+    #[test]
+    fn attribute_round_trip() {
+        // Maps are not parsed, so the round trip uses flat attribute values
+        let attribute = RedisType::Attribute {
+            attributes: vec![
+                (
+                    RedisType::SimpleString("ttl".to_owned()),
+                    RedisType::Integer(3600),
+                ),
+                (
+                    RedisType::BulkString("keys".into()),
+                    RedisType::Array(vec![RedisType::BulkString("user:1".into())]),
+                ),
+            ],
+            reply: Box::new(RedisType::BulkString("value".into())),
+        };
+
+        let mut buf = BytesMut::new();
+        attribute.encode(&mut buf, RespVersion::Resp3);
+        assert_eq!(Some((attribute, buf.len())), try_parse_frame(&buf));
+
+        // Empty attributes, and an attribute nested in an array
+        let nested = RedisType::Array(vec![
+            RedisType::Attribute {
+                attributes: vec![],
+                reply: Box::new(RedisType::Integer(1)),
+            },
+            RedisType::SimpleString("OK".to_owned()),
+        ]);
+        let mut buf = BytesMut::new();
+        nested.encode(&mut buf, RespVersion::Resp3);
+        assert_eq!(Some((nested, buf.len())), try_parse_frame(&buf));
+    }
+
+    #[test]
+    fn parse_attribute_incomplete_or_incorrect() {
+        // The reply the attribute describes is part of the frame
+        assert_none_for_content("|1\r\n+ttl\r\n:1\r\n");
+        assert_none_for_content("|1\r\n+ttl\r\n");
+
+        assert_for_content(
+            "|-1\r\n+OK\r\n",
+            RedisType::InvalidType(INVALID_MULTIBULK_LENGTH_ERROR.to_owned()),
+        );
+    }
+
+    #[test]
+    fn big_number_round_trip() {
+        for digits in ["3492890328409238509324850943850943825024385", "-12", "0"] {
+            let big_number = RedisType::BigNumber(digits.to_owned());
+
+            let mut buf = BytesMut::new();
+            big_number.encode(&mut buf, RespVersion::Resp3);
+            assert_eq!(format!("({digits}\r\n").as_bytes(), &buf[..]);
+            assert_eq!(Some((big_number.clone(), buf.len())), try_parse_frame(&buf));
+
+            let mut buf = BytesMut::new();
+            big_number.encode(&mut buf, RespVersion::Resp2);
+            assert_eq!(
+                format!("${}\r\n{digits}\r\n", digits.len()).as_bytes(),
+                &buf[..]
+            );
+        }
+
+        for invalid in ["(\r\n", "(-\r\n", "(12a\r\n", "(1.5\r\n"] {
+            assert_for_content(
+                invalid,
+                RedisType::InvalidType(INVALID_BIG_NUMBER_ERROR.to_owned()),
+            );
+        }
+    }
+
+    #[test]
+    fn encode_push_for_each_version() {
+        let push = RedisType::Push(vec![
+            RedisType::BulkString("invalidate".into()),
+            RedisType::Array(vec![RedisType::BulkString("key".into())]),
+        ]);
+
+        let mut buf = BytesMut::new();
+        push.encode(&mut buf, RespVersion::Resp2);
+        assert_eq!(b"*2\r\n$10\r\ninvalidate\r\n*1\r\n$3\r\nkey\r\n", &buf[..]);
+
+        let mut buf = BytesMut::new();
+        push.encode(&mut buf, RespVersion::Resp3);
+        assert_eq!(b">2\r\n$10\r\ninvalidate\r\n*1\r\n$3\r\nkey\r\n", &buf[..]);
+    }
+
+    #[test]
+    fn encode_stops_when_over_budget() {
+        let array = RedisType::Array(vec![
+            RedisType::BulkString("a".into()),
+            RedisType::Array(vec![RedisType::Integer(1), RedisType::Integer(2)]),
+        ]);
+        let mut buf = BytesMut::new();
+
+        // Nested entries count as elements: 2 + 2
+        let mut budget = ReplyBudget {
+            elements: 4,
+            bytes: usize::MAX,
+        };
+        assert!(
+            array
+                .encode_within(&mut buf, RespVersion::Resp2, &mut budget)
+                .is_ok()
+        );
+
+        let mut budget = ReplyBudget {
+            elements: 3,
+            bytes: usize::MAX,
+        };
+        assert!(
+            array
+                .encode_within(&mut buf, RespVersion::Resp2, &mut budget)
+                .is_err()
+        );
+
+        // *2\r\n$1\r\na\r\n*2\r\n:1\r\n:2\r\n is 23 bytes
+        let mut budget = ReplyBudget {
+            elements: usize::MAX,
+            bytes: 23,
+        };
+        assert!(
+            array
+                .encode_within(&mut buf, RespVersion::Resp2, &mut budget)
+                .is_ok()
+        );
+
+        let mut budget = ReplyBudget {
+            elements: usize::MAX,
+            bytes: 22,
+        };
+        assert!(
+            array
+                .encode_within(&mut buf, RespVersion::Resp2, &mut budget)
+                .is_err()
+        );
+    }
+
     #[test]
     fn encode_invalid_type_as_error() {
         let mut buf = BytesMut::new();
@@ -661,10 +1751,10 @@ mod tests {
 
         // Bulk String: $<length>\r\n<data>\r\n
         assert_eq!(
-            RedisType::BulkString("bulk".to_string()),
+            RedisType::BulkString("bulk".into()),
             "$4\r\nbulk\r\n".into()
         );
-        assert_eq!(RedisType::BulkString("".to_string()), "$0\r\n\r\n".into());
+        assert_eq!(RedisType::BulkString("".into()), "$0\r\n\r\n".into());
 
         // Null Bulk String: $-1\r\n
         assert_eq!(RedisType::NullBulkString, "$-1\r\n".into());
@@ -681,7 +1771,7 @@ mod tests {
         // Arrays with mixed elements
         assert_eq!(
             RedisType::Array(vec![
-                RedisType::BulkString("hello".to_owned()),
+                RedisType::BulkString("hello".into()),
                 RedisType::Integer(42),
             ]),
             "*2\r\n$5\r\nhello\r\n:42\r\n".into()
@@ -694,9 +1784,102 @@ mod tests {
         assert_eq!(
             RedisType::Array(vec![
                 RedisType::Array(vec![RedisType::SimpleString("A".to_owned())]),
-                RedisType::BulkString("abc".to_owned()),
+                RedisType::BulkString("abc".into()),
             ]),
             "*2\r\n*1\r\n+A\r\n$3\r\nabc\r\n".into()
         );
     }
 }
+
+#[cfg(test)]
+mod property_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Values the parser reads back as they were encoded (RESP2, no errors or RESP3-only types).
+    fn parsable_value() -> impl Strategy<Value = RedisType> {
+        let leaf = prop_oneof![
+            "[a-zA-Z0-9 ]{0,16}".prop_map(RedisType::SimpleString),
+            proptest::collection::vec(any::<u8>(), 0..64)
+                .prop_map(|value| RedisType::BulkString(value.into())),
+            any::<i64>().prop_map(RedisType::Integer),
+            Just(RedisType::NullBulkString),
+            Just(RedisType::NullArray),
+        ];
+        leaf.prop_recursive(4, 64, 8, |inner| {
+            proptest::collection::vec(inner, 0..8).prop_map(RedisType::Array)
+        })
+    }
+
+    fn encode(value: &RedisType) -> Vec<u8> {
+        let mut buf = BytesMut::new();
+        value.encode(&mut buf, RespVersion::Resp2);
+        buf.to_vec()
+    }
+
+    proptest! {
+        #[test]
+        fn arbitrary_bytes_never_panic_or_over_read(data in proptest::collection::vec(any::<u8>(), 0..256)) {
+            if let Some((_, consumed)) = parse_for_fuzz(&data) {
+                prop_assert!(consumed > 0);
+                prop_assert!(consumed <= data.len());
+            }
+        }
+
+        #[test]
+        fn resp_looking_bytes_never_panic_or_over_read(
+            parts in proptest::collection::vec(
+                prop_oneof![
+                    Just(b"*".to_vec()),
+                    Just(b"$".to_vec()),
+                    Just(b":".to_vec()),
+                    Just(b"+".to_vec()),
+                    Just(b"\r\n".to_vec()),
+                    Just(b"-1".to_vec()),
+                    "[0-9]{1,20}".prop_map(String::into_bytes),
+                    proptest::collection::vec(any::<u8>(), 0..4),
+                ],
+                0..32,
+            )
+        ) {
+            let data = parts.concat();
+            if let Some((_, consumed)) = parse_for_fuzz(&data) {
+                prop_assert!(consumed > 0);
+                prop_assert!(consumed <= data.len());
+            }
+        }
+
+        #[test]
+        fn encoded_values_round_trip(value in parsable_value()) {
+            let encoded = encode(&value);
+            prop_assert_eq!(Some((value, encoded.len())), parse_for_fuzz(&encoded));
+        }
+
+        #[test]
+        fn truncated_values_are_rejected(value in parsable_value(), cut in any::<prop::sample::Index>()) {
+            let encoded = encode(&value);
+            let truncated = &encoded[..cut.index(encoded.len())];
+
+            // Either incomplete (None) or an error, never the full value and never past the end
+            if let Some((parsed, consumed)) = parse_for_fuzz(truncated) {
+                prop_assert!(consumed <= truncated.len());
+                prop_assert_ne!(parsed, value);
+            }
+        }
+    }
+
+    #[test]
+    fn huge_declared_lengths_are_not_preallocated() {
+        assert_eq!(None, parse_for_fuzz(b"*9223372036854775807\r\n"));
+        assert_eq!(None, parse_for_fuzz(b"$9223372036854775807\r\nabc\r\n"));
+    }
+
+    #[test]
+    fn deep_nesting_is_rejected() {
+        let data = "*1\r\n".repeat(10_000);
+        assert_eq!(
+            Some(RedisType::InvalidType(NESTING_TOO_DEEP_ERROR.to_owned())),
+            parse_for_fuzz(data.as_bytes()).map(|(parsed, _)| parsed)
+        );
+    }
+}
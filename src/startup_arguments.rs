@@ -1,7 +1,7 @@
 use clap::{Parser, ValueEnum};
 use std::fmt::{Display, Formatter};
 use std::net::SocketAddr;
-#[derive(Debug, Clone, Copy, Parser)]
+#[derive(Debug, Clone, Parser)]
 #[command(name = "valkyrie", about = "High-performance Key-Value storage")]
 pub struct StartupArguments {
     // #[arg(long = "mode", value_enum, default_value_t = Mode::ReusePort, help = "Runtime mode: reuseport or dispatcher")]
@@ -26,13 +26,90 @@ pub struct StartupArguments {
         help = "Number of storage shards"
     )]
     pub shards: usize,
+
+    #[arg(
+        long = "initial-keyspace-size",
+        default_value_t = 0,
+        help = "Expected number of keys, used to pre-size each shard's map (split evenly across shards)"
+    )]
+    pub initial_keyspace_size: usize,
+
+    #[arg(
+        long = "read-buffer-size",
+        default_value_t = 1024,
+        help = "Initial read buffer size of a client connection in bytes (capped at 64KB)"
+    )]
+    pub read_buffer_size: usize,
+
+    #[arg(
+        long = "first-command-timeout-ms",
+        default_value_t = 0,
+        help = "Close connections that don't send a complete first command within this many milliseconds, off (0) by default"
+    )]
+    pub first_command_timeout_ms: u64,
+
+    #[arg(
+        long = "read-only",
+        default_value_t = false,
+        help = "Reject every command that writes to the keyspace"
+    )]
+    pub read_only: bool,
+
+    #[arg(
+        long = "expire-jitter-ms",
+        default_value_t = 0,
+        help = "Upper bound of a random delay in milliseconds added to every key expiration, spreads mass expirations"
+    )]
+    pub expire_jitter_ms: u64,
+
+    #[arg(
+        long = "requirepass",
+        help = "Require clients to authenticate with AUTH <password> before running other commands"
+    )]
+    pub requirepass: Option<String>,
+
+    #[arg(
+        long = "max-reply-elements",
+        default_value_t = 0,
+        help = "Reply with an error instead of sending replies with more array or map elements than this, 0 disables it"
+    )]
+    pub max_reply_elements: usize,
+
+    #[arg(
+        long = "max-reply-bytes",
+        default_value_t = 0,
+        help = "Reply with an error instead of sending replies larger than this many bytes, 0 disables it"
+    )]
+    pub max_reply_bytes: usize,
+
+    #[arg(
+        long = "no-cpu-affinity",
+        default_value_t = false,
+        help = "Don't pin shard and TCP handler threads to CPU cores"
+    )]
+    pub no_cpu_affinity: bool,
+
+    #[arg(
+        long = "cpu-affinity-offset",
+        default_value_t = 0,
+        help = "First CPU core used for pinning, shards and then TCP handlers take the following cores"
+    )]
+    pub cpu_affinity_offset: usize,
+
+    #[arg(
+        long = "log-format",
+        value_enum,
+        default_value_t = LogFormat::Text,
+        help = "Log output format: text or json"
+    )]
+    pub log_format: LogFormat,
 }
 
 impl StartupArguments {
     /// Parse command line arguments using clap.
     ///
     /// Usage:
-    ///     --mode=dispatcher|reuseport --address=0.0.0.0:8080 --tcp-handlers=4 --shards=4
+    ///     --mode=dispatcher|reuseport --address=0.0.0.0:8080 --tcp-handlers=4 --shards=4 --initial-keyspace-size=1000000 --read-buffer-size=16384 --first-command-timeout-ms=5000 --read-only --expire-jitter-ms=100 --requirepass=secret --max-reply-elements=100000 --max-reply-bytes=67108864 --cpu-affinity-offset=2 --log-format=json
     pub fn parse_args() -> Self {
         let mut args = Self::parse();
 
@@ -45,6 +122,13 @@ impl StartupArguments {
         args.shards = std::cmp::min(args.shards, half);
         args.tcp_handlers = std::cmp::min(args.tcp_handlers, half);
 
+        // On a single core there is nothing to isolate the threads from, and the cores after the first one
+        // don't exist, so leave the shard and the TCP handler to the OS scheduler. Blocking commands still
+        // work there: they wait on a notifier and never hold the thread of the shard or the TCP handler.
+        if available == 1 {
+            args.no_cpu_affinity = true;
+        }
+
         args
     }
 }
@@ -53,8 +137,26 @@ impl Display for StartupArguments {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             formatter,
-            "address={}, tcp_handlers={}, shards={}",
-            self.address, self.tcp_handlers, self.shards
+            "address={}, tcp_handlers={}, shards={}, initial_keyspace_size={}, read_buffer_size={}, first_command_timeout_ms={}, read_only={}, expire_jitter_ms={}, requirepass={}, max_reply_elements={}, max_reply_bytes={}, no_cpu_affinity={}, cpu_affinity_offset={}, log_format={:?}",
+            self.address,
+            self.tcp_handlers,
+            self.shards,
+            self.initial_keyspace_size,
+            self.read_buffer_size,
+            self.first_command_timeout_ms,
+            self.read_only,
+            self.expire_jitter_ms,
+            // Never log the password itself
+            if self.requirepass.is_some() {
+                "<set>"
+            } else {
+                "<none>"
+            },
+            self.max_reply_elements,
+            self.max_reply_bytes,
+            self.no_cpu_affinity,
+            self.cpu_affinity_offset,
+            self.log_format
         )
     }
 }
@@ -66,3 +168,13 @@ pub enum Mode {
     #[value(name = "dispatcher")]
     Dispatcher,
 }
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum LogFormat {
+    /// Human-readable lines
+    #[value(name = "text")]
+    Text,
+    /// One JSON object per event, for log pipelines
+    #[value(name = "json")]
+    Json,
+}
@@ -1,8 +1,11 @@
 use std::{
     cell::RefCell,
-    collections::{HashMap, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     hash::DefaultHasher,
+    pin::pin,
     rc::Rc,
+    sync::OnceLock,
+    sync::atomic::{AtomicU64, Ordering},
     thread::{self},
 };
 
@@ -10,15 +13,53 @@ use std::hash::{Hash, Hasher};
 use std::thread_local;
 
 use async_trait::async_trait;
+use bytes::{Bytes, BytesMut};
+use futures::future::{Either, select};
 use tokio::sync::{Notify, mpsc::UnboundedSender};
 use tokio::task::JoinHandle;
+use tokio::time::{Duration, Instant, sleep_until};
 use tokio::{sync::oneshot, task::LocalSet};
 
+use crate::utils::random::random_below;
 use crate::utils::thread_utils::pin_current_thread_to_cpu;
 pub mod get_storage;
 pub use get_storage::GetStorage;
 pub mod set_storage;
 pub use set_storage::SetStorage;
+pub mod get_ex_storage;
+pub use get_ex_storage::{ExpirationUpdate, GetExStorage};
+pub mod ttl_storage;
+pub use ttl_storage::TtlStorage;
+pub mod expire_storage;
+pub use expire_storage::{ExpireCondition, ExpireStorage};
+pub mod persist_storage;
+pub use persist_storage::PersistStorage;
+pub mod delete_storage;
+pub use delete_storage::DeleteStorage;
+pub mod exists_storage;
+pub use exists_storage::ExistsStorage;
+pub mod rename_storage;
+pub use rename_storage::{RenamePutStorage, RenameTakeStorage};
+pub mod set_nx_storage;
+pub use set_nx_storage::SetNxStorage;
+pub mod multi_get_storage;
+pub use multi_get_storage::MultiGetStorage;
+pub mod multi_set_storage;
+pub use multi_set_storage::MultiSetStorage;
+pub mod get_range_storage;
+pub use get_range_storage::GetRangeStorage;
+pub mod set_range_storage;
+pub use set_range_storage::SetRangeStorage;
+pub mod append_storage;
+pub use append_storage::AppendStorage;
+pub mod incr_by_storage;
+pub use incr_by_storage::IncrByStorage;
+pub mod incr_by_float_storage;
+pub use incr_by_float_storage::IncrByFloatStorage;
+pub mod flush_storage;
+pub use flush_storage::FlushStorage;
+pub mod shard_block_storage;
+pub use shard_block_storage::ShardBlockStorage;
 pub mod list_right_push_storage;
 pub use list_right_push_storage::ListRightPushStorage;
 pub mod list_left_push_storage;
@@ -26,19 +67,141 @@ pub use list_left_push_storage::ListLeftPushStorage;
 
 pub mod list_left_blocking_pop_storage;
 pub use list_left_blocking_pop_storage::ListLeftBlockingPopStorage;
+pub mod list_right_blocking_pop_storage;
+pub use list_right_blocking_pop_storage::ListRightBlockingPopStorage;
 
 pub mod list_left_pop_storage;
 pub use list_left_pop_storage::ListLeftPopStorage;
+pub mod list_right_pop_storage;
+pub use list_right_pop_storage::ListRightPopStorage;
 pub mod list_range_storage;
 pub use list_range_storage::ListRangeStorage;
 pub mod list_length_storage;
 pub use list_length_storage::ListLengthStorage;
+pub mod list_index_storage;
+pub use list_index_storage::ListIndexStorage;
+pub mod list_remove_storage;
+pub use list_remove_storage::ListRemoveStorage;
+pub mod hash_set_storage;
+pub use hash_set_storage::HashSetStorage;
+pub mod hash_get_storage;
+pub use hash_get_storage::HashGetStorage;
+pub mod hash_multi_get_storage;
+pub use hash_multi_get_storage::HashMultiGetStorage;
+pub mod hash_exists_storage;
+pub use hash_exists_storage::HashExistsStorage;
+pub mod hash_delete_storage;
+pub use hash_delete_storage::HashDeleteStorage;
+pub mod hash_incr_by_storage;
+pub use hash_incr_by_storage::HashIncrByStorage;
+pub mod hash_incr_by_float_storage;
+pub use hash_incr_by_float_storage::HashIncrByFloatStorage;
+pub mod hash_view_storage;
+pub use hash_view_storage::{HashView, HashViewStorage};
+pub mod hash_length_storage;
+pub use hash_length_storage::HashLengthStorage;
+pub mod set_add_storage;
+pub use set_add_storage::SetAddStorage;
+pub mod set_remove_storage;
+pub use set_remove_storage::SetRemoveStorage;
+pub mod set_members_storage;
+pub use set_members_storage::SetMembersStorage;
+pub mod set_pop_storage;
+pub use set_pop_storage::SetPopStorage;
+pub mod set_random_members_storage;
+pub use set_random_members_storage::SetRandomMembersStorage;
+pub mod set_is_member_storage;
+pub use set_is_member_storage::SetIsMemberStorage;
+pub mod set_cardinality_storage;
+pub use set_cardinality_storage::SetCardinalityStorage;
+pub mod sorted_set;
+pub use sorted_set::{
+    MIN_MAX_NOT_A_FLOAT_ERROR, ScoreRange, ScoreUpdate, SortedSet, format_score, parse_score,
+};
+pub mod sorted_set_add_storage;
+pub use sorted_set_add_storage::SortedSetAddStorage;
+pub mod sorted_set_score_storage;
+pub use sorted_set_score_storage::SortedSetScoreStorage;
+pub mod sorted_set_range_storage;
+pub use sorted_set_range_storage::SortedSetRangeStorage;
+pub mod zrange_by_score_storage;
+pub use zrange_by_score_storage::ZRangeByScoreStorage;
+pub mod sorted_set_count_storage;
+pub use sorted_set_count_storage::SortedSetCountStorage;
+pub mod sorted_set_pop_storage;
+pub use sorted_set_pop_storage::SortedSetPopStorage;
+pub mod type_storage;
+pub use type_storage::TypeStorage;
+pub mod object_encoding_storage;
+pub use object_encoding_storage::ObjectEncodingStorage;
+pub mod stream_id;
+pub use stream_id::{StreamId, StreamIdSpec, parse_stream_range_bound};
+pub mod stream_add_storage;
+pub use stream_add_storage::StreamAddStorage;
+pub mod stream_length_storage;
+pub use stream_length_storage::StreamLengthStorage;
+pub mod stream_range_storage;
+pub use stream_range_storage::StreamRangeStorage;
 
 thread_local! {
-    pub static LIST_NOTIFIERS: RefCell<HashMap<String, Rc<Notify>>> =
+    pub static LIST_NOTIFIERS: RefCell<HashMap<String, ListNotifier>> =
         RefCell::new(HashMap::new());
 }
 
+/// Per-key notifier used to wake blocked list pops, with the number of waiters registered on it.
+pub struct ListNotifier {
+    pub notify: Rc<Notify>,
+    waiters: usize,
+}
+
+/// Registration of a single blocked waiter on a key's notifier.
+/// The first waiter creates the `LIST_NOTIFIERS` entry and the last one dropped removes it
+/// (value popped, wrong type, or request cancelled on timeout/disconnect), so the map
+/// only holds keys which are currently blocked on.
+pub struct ListNotifierWaiter {
+    key: String,
+    notify: Rc<Notify>,
+}
+
+impl ListNotifierWaiter {
+    pub fn register(key: &str) -> Self {
+        let notify = LIST_NOTIFIERS.with(|cell| {
+            let mut notifiers = cell.borrow_mut();
+            let notifier = notifiers
+                .entry(key.to_string())
+                .or_insert_with(|| ListNotifier {
+                    notify: Rc::new(Notify::new()),
+                    waiters: 0,
+                });
+            notifier.waiters += 1;
+            Rc::clone(&notifier.notify)
+        });
+
+        Self {
+            key: key.to_string(),
+            notify,
+        }
+    }
+
+    pub fn notify(&self) -> &Notify {
+        &self.notify
+    }
+}
+
+impl Drop for ListNotifierWaiter {
+    fn drop(&mut self) {
+        LIST_NOTIFIERS.with(|cell| {
+            let mut notifiers = cell.borrow_mut();
+            if let Some(notifier) = notifiers.get_mut(&self.key) {
+                notifier.waiters -= 1;
+                if notifier.waiters == 0 {
+                    notifiers.remove(&self.key);
+                }
+            }
+        });
+    }
+}
+
 pub struct StorageEngine {
     storage_shards: Vec<StorageShard>,
 }
@@ -66,33 +229,232 @@ pub trait StorageRequest: Send {
     async fn handle(
         &self,
         stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
-        delayed_tasks: &Rc<RefCell<HashMap<String, JoinHandle<()>>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
     ) -> StorageResponse;
 
-    fn commit(&self, _stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>) {}
+    fn commit(
+        &self,
+        _stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) {
+    }
 }
 
 #[derive(Debug)]
 pub enum StorageResponse {
-    KeyValue { value: String },
-    ValueFromList { value: String, list_name: String },
+    KeyValue {
+        value: String,
+    },
+    /// Value of a string key, which may hold arbitrary bytes.
+    /// Shares the stored bytes, so a large value is not copied to be sent back.
+    StringValue {
+        value: Bytes,
+    },
+    ValueFromList {
+        value: String,
+        list_name: String,
+    },
     Null,
     Success,
     ListLength(usize),
-    ListValues { values: Vec<String> },
+    IntegerValue(i64),
+    ListValues {
+        values: Vec<String>,
+    },
+    OptionalValues(Vec<Option<String>>),
+    OptionalStringValues(Vec<Option<Bytes>>),
+    /// Members to pick `count` times at random, repeats allowed (SRANDMEMBER with a large negative count).
+    /// The picks are made while the reply is written, so `count` never sizes an allocation.
+    MembersToSample {
+        members: Vec<String>,
+        count: usize,
+    },
+    StreamLength(usize),
+    StreamEntries {
+        entries: Vec<StreamEntry>,
+    },
+    TakenValue {
+        value: StorageValue,
+        ttl_ms: Option<u64>,
+    },
+    /// Error reply, sent to the client as is. The message starts with a Redis error code that clients
+    /// match on: `WRONGTYPE` for a key holding another type (always `WRONG_TYPE_ERROR`), `ERR` otherwise.
     Failed(String),
 }
 
 #[derive(Debug)]
 pub enum StorageValue {
-    Str(String),
+    /// Binary-safe, e.g. an image or a serialized message, numbers are kept in their decimal form.
+    /// `Bytes` so replies share the value with the shard instead of copying it (see `update_string`).
+    Str(Bytes),
     List(VecDeque<String>),
+    Stream(Vec<StreamEntry>),
+    Hash(HashMap<String, String>),
+    Set(HashSet<String>),
+    ZSet(SortedSet),
+}
+
+impl StorageValue {
+    /// Name of the value type, as reported by TYPE.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            StorageValue::Str(_) => "string",
+            StorageValue::List(_) => "list",
+            StorageValue::Stream(_) => "stream",
+            StorageValue::Hash(_) => "hash",
+            StorageValue::Set(_) => "set",
+            StorageValue::ZSet(_) => "zset",
+        }
+    }
+
+    /// Name of the internal representation, as reported by OBJECT ENCODING.
+    /// Follows the Redis names for the closest equivalent representation.
+    pub fn encoding(&self) -> &'static str {
+        match self {
+            StorageValue::Str(value) if parse_integer(value).is_some() => "int",
+            StorageValue::Str(value) if value.len() <= EMBSTR_MAX_LEN => "embstr",
+            StorageValue::Str(_) => "raw",
+            StorageValue::List(_) => "quicklist",
+            StorageValue::Stream(_) => "stream",
+            StorageValue::Hash(_) | StorageValue::Set(_) => "hashtable",
+            StorageValue::ZSet(_) => "skiplist",
+        }
+    }
+}
+
+/// Longest string Redis stores with the `embstr` encoding.
+const EMBSTR_MAX_LEN: usize = 44;
+
+/// Reply for an operation on a key holding a value of another type, whatever the two types are.
+pub const WRONG_TYPE_ERROR: &str =
+    "WRONGTYPE Operation against a key holding the wrong kind of value";
+
+/// Reply for a stored value or an argument that is not a valid 64-bit integer (or would overflow one).
+pub const NOT_AN_INTEGER_ERROR: &str = "ERR value is not an integer or out of range";
+
+/// Reply for a stored value or an argument that is not a valid finite float.
+pub const NOT_A_FLOAT_ERROR: &str = "ERR value is not a valid float";
+
+/// Single stream entry: its ID and the field-value pairs in insertion order.
+pub type StreamEntry = (StreamId, Vec<(String, String)>);
+
+/// Shared type check for string-only commands.
+/// A missing key is `Ok(None)`, so every command applies its own default (empty string, 0, create),
+/// while a key holding any other type fails without being created or modified.
+pub fn string_or_absent(value: Option<&StorageValue>) -> Result<Option<&Bytes>, StorageResponse> {
+    match value {
+        Some(StorageValue::Str(value)) => Ok(Some(value)),
+        Some(_) => Err(StorageResponse::Failed(WRONG_TYPE_ERROR.to_string())),
+        None => Ok(None),
+    }
+}
+
+/// Updates a string value in place (APPEND, SETRANGE). The bytes are only copied when they are
+/// still shared with a reply being written out, e.g. the one of a GET.
+pub fn update_string(value: &mut Bytes, update: impl FnOnce(&mut BytesMut)) {
+    let mut bytes = std::mem::take(value)
+        .try_into_mut()
+        .unwrap_or_else(|shared| BytesMut::from(&shared[..]));
+    update(&mut bytes);
+    *value = bytes.freeze();
+}
+
+/// Integer held by a string value, None when the bytes are not a decimal 64-bit integer.
+pub fn parse_integer(value: &[u8]) -> Option<i64> {
+    std::str::from_utf8(value).ok()?.parse::<i64>().ok()
+}
+
+/// Pending expiration of a key: the task which deletes it and the moment it fires.
+#[derive(Debug)]
+pub struct ExpirationTask {
+    pub handle: JoinHandle<()>,
+    pub deadline: Instant,
+}
+
+// Set once at startup: told about the keys a shard removed or modified on its own (expiration, flush,
+// blocking pops), which commands sent by the connections don't cover. Client tracking invalidates them.
+static KEYS_CHANGED_HOOK: OnceLock<fn(&[String])> = OnceLock::new();
+
+pub fn set_keys_changed_hook(hook: fn(&[String])) {
+    let _ = KEYS_CHANGED_HOOK.set(hook);
+}
+
+/// Reports keys removed or modified by the shard itself to the hook set with `set_keys_changed_hook`.
+fn keys_changed(keys: &[String]) {
+    if let Some(hook) = KEYS_CHANGED_HOOK.get() {
+        hook(keys);
+    }
+}
+
+// Set once at startup from `--expire-jitter-ms`: upper bound of the random delay added to every expiration.
+static EXPIRE_JITTER_MS: AtomicU64 = AtomicU64::new(0);
+
+pub fn set_expire_jitter_ms(jitter_ms: u64) {
+    EXPIRE_JITTER_MS.store(jitter_ms, Ordering::Relaxed);
+}
+
+/// Replaces any pending expiration of `key` with a new one firing after `delay_ms`.
+/// The expiration task removes the key together with its own `delayed_tasks` entry.
+/// With `--expire-jitter-ms` a random `0..jitter` delay is added, so keys written with the same TTL
+/// don't all expire at the same moment.
+pub fn schedule_expiration(
+    key: &str,
+    delay_ms: u64,
+    stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+    delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+) {
+    cancel_expiration(key, delayed_tasks);
+
+    let key_copy = key.to_string();
+    let local_map_copy = Rc::clone(stored_data);
+    let delayed_tasks_copy = Rc::clone(delayed_tasks);
+
+    let jitter_ms = random_below(EXPIRE_JITTER_MS.load(Ordering::Relaxed));
+    let deadline = Instant::now() + Duration::from_millis(delay_ms.saturating_add(jitter_ms));
+
+    let exp_handler = tokio::task::spawn_local(async move {
+        sleep_until(deadline).await;
+        local_map_copy.borrow_mut().remove(&key_copy);
+        delayed_tasks_copy.borrow_mut().remove(&key_copy);
+        tracing::debug!("Key {key_copy} expired and was deleted.");
+        keys_changed(&[key_copy]);
+    });
+
+    delayed_tasks.borrow_mut().insert(
+        key.to_string(),
+        ExpirationTask {
+            handle: exp_handler,
+            deadline,
+        },
+    );
+}
+
+/// Aborts the pending expiration of `key`, returns whether there was one.
+pub fn cancel_expiration(
+    key: &str,
+    delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+) -> bool {
+    match delayed_tasks.borrow_mut().remove(key) {
+        Some(prev_expiration) => {
+            tracing::debug!("Previous expiration aborted");
+            prev_expiration.handle.abort();
+            true
+        }
+        None => false,
+    }
 }
 
 impl StorageEngine {
-    pub fn new(shards: usize, core_affinity_range: std::ops::Range<usize>) -> Self {
+    /// `initial_keyspace_size` is the expected total number of keys; each shard pre-sizes its map
+    /// for its share of them to avoid rehashing during bulk loads. 0 keeps the maps empty.
+    pub fn new(
+        shards: usize,
+        core_affinity_range: std::ops::Range<usize>,
+        initial_keyspace_size: usize,
+    ) -> Self {
         // shards count should be greater than 0, convert to 1 if 0
         let shards = if shards == 0 { 1 } else { shards };
+        let shard_capacity = Self::shard_capacity(initial_keyspace_size, shards);
 
         let mut storage_threads = Vec::with_capacity(shards);
 
@@ -116,7 +478,7 @@ impl StorageEngine {
                         .expect("Failed to create tokio runtime");
 
                     rt.block_on(local.run_until(async move {
-                        Self::shard_loop(receiver).await;
+                        Self::shard_loop(receiver, shard_capacity).await;
                     }));
                 })
                 .expect("Can't spawn storage-shard thread");
@@ -131,20 +493,28 @@ impl StorageEngine {
         }
     }
 
+    /// Keys are spread evenly across shards by hash, so every shard gets its share of the
+    /// expected keyspace (rounded up, so a small keyspace still pre-sizes every shard).
+    fn shard_capacity(initial_keyspace_size: usize, shards: usize) -> usize {
+        initial_keyspace_size.div_ceil(shards.max(1))
+    }
+
     async fn shard_loop(
         mut queue_receiver: tokio::sync::mpsc::UnboundedReceiver<StorageCommandEnvelope>,
+        shard_capacity: usize,
     ) {
         //
         //TODO: think if it's better to move below values to thread_local!, similar to `LIST_NOTIFIERS`
         //
-        let stored_data = Rc::new(RefCell::new(HashMap::new()));
+        let stored_data: Rc<RefCell<HashMap<String, StorageValue>>> =
+            Rc::new(RefCell::new(HashMap::with_capacity(shard_capacity)));
         let delayed_tasks = Rc::new(RefCell::new(HashMap::new()));
         tracing::debug!("Started");
 
         while let Some(storage_command) = queue_receiver.recv().await {
             if let StorageCommandEnvelope::Request {
                 request,
-                reply_channel,
+                mut reply_channel,
             } = storage_command
             {
                 let stored_data2 = Rc::clone(&stored_data);
@@ -153,11 +523,24 @@ impl StorageEngine {
                 tokio::task::spawn_local(async move {
                     tracing::debug!("Engine handling storage request");
 
-                    let response = request.handle(&stored_data2, &delayed_tasks2).await;
+                    // Stop handling once the caller has gone away (e.g. BLPOP timed out or the client disconnected),
+                    // otherwise a blocked request would wait forever and keep its resources alive.
+                    // `select` polls `handle` first, so requests which complete immediately are never skipped.
+                    let response = {
+                        let handle = pin!(request.handle(&stored_data2, &delayed_tasks2));
+                        let closed = pin!(reply_channel.closed());
+                        match select(handle, closed).await {
+                            Either::Left((response, _)) => response,
+                            Either::Right(_) => {
+                                tracing::debug!("Storage request cancelled by the caller");
+                                return;
+                            }
+                        }
+                    };
 
                     match reply_channel.send(StorageCommandEnvelope::Response { response }) {
                         Ok(_) => {
-                            request.commit(&stored_data2);
+                            request.commit(&stored_data2, &delayed_tasks2);
                         }
                         Err(_) => {
                             tracing::warn!(
@@ -178,15 +561,22 @@ impl StorageEngine {
     where
         R: StorageRequest + 'static,
     {
+        let storage_thread = self.find_shard_for_key(storage_request.key());
+
+        Self::send_to_shard(storage_thread, Box::new(storage_request)).await
+    }
+
+    async fn send_to_shard(
+        storage_thread: &StorageShard,
+        request: Box<dyn StorageRequest + Send>,
+    ) -> anyhow::Result<StorageResponse> {
         // this channel will be used like a future/promise
         let (sender, receiver) = oneshot::channel::<StorageCommandEnvelope>();
 
-        let storage_thread = self.find_shard_for_key(storage_request.key());
-
         storage_thread
             .commands_channel
             .send(StorageCommandEnvelope::Request {
-                request: Box::new(storage_request),
+                request,
                 reply_channel: sender,
             })
             .map_err(|_| anyhow::anyhow!("failed to send to storage shard: channel closed"))?;
@@ -202,14 +592,40 @@ impl StorageEngine {
         }
     }
 
+    pub fn shard_count(&self) -> usize {
+        self.storage_shards.len()
+    }
+
+    /// Same as `execute`, but sends the request to the given shard instead of the key owner.
+    /// Used by keyless requests which must run on every shard (e.g. flushing the keyspace).
+    pub async fn execute_on_shard<R>(
+        &self,
+        shard_idx: usize,
+        storage_request: R,
+    ) -> anyhow::Result<StorageResponse>
+    where
+        R: StorageRequest + 'static,
+    {
+        let storage_thread = self
+            .storage_shards
+            .get(shard_idx)
+            .ok_or_else(|| anyhow::anyhow!("storage shard {shard_idx} does not exist"))?;
+
+        Self::send_to_shard(storage_thread, Box::new(storage_request)).await
+    }
+
     /// Selects the storage shard for a request by hashing the key.
     ///
     /// The shard index is computed as `hash(key) % shard_count`.
     /// All request variants use the request key, ensuring that reads/writes
     /// go to the same shard where the data for that key is stored.
     fn find_shard_for_key(&self, key: &str) -> &StorageShard {
-        let shard_idx = self.hash_string(key) % self.storage_shards.len();
-        &self.storage_shards[shard_idx]
+        &self.storage_shards[self.shard_index_for_key(key)]
+    }
+
+    /// Index of the shard owning `key`, used by multi-key commands to group keys per shard.
+    pub fn shard_index_for_key(&self, key: &str) -> usize {
+        self.hash_string(key) % self.storage_shards.len()
     }
 
     fn hash_string(&self, value: &str) -> usize {
@@ -218,3 +634,108 @@ impl StorageEngine {
         hasher.finish() as usize
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::HashMap, rc::Rc, time::Duration};
+
+    use tokio::task::LocalSet;
+
+    use bytes::Bytes;
+
+    use super::{
+        LIST_NOTIFIERS, ListLeftBlockingPopStorage, ListRightPushStorage, StorageEngine,
+        StorageRequest, StorageResponse, update_string,
+    };
+
+    #[test]
+    fn shard_capacity_splits_keyspace_across_shards() {
+        assert_eq!(StorageEngine::shard_capacity(0, 4), 0);
+        assert_eq!(StorageEngine::shard_capacity(1_000, 4), 250);
+        // rounded up so no shard is under-allocated
+        assert_eq!(StorageEngine::shard_capacity(1_001, 4), 251);
+        assert_eq!(StorageEngine::shard_capacity(3, 4), 1);
+        // defensive: zero shards behaves like a single shard
+        assert_eq!(StorageEngine::shard_capacity(10, 0), 10);
+    }
+
+    fn list_notifiers_len() -> usize {
+        LIST_NOTIFIERS.with(|cell| cell.borrow().len())
+    }
+
+    #[test]
+    fn list_notifiers_return_to_baseline_after_waiters_leave() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .expect("build runtime");
+
+        LocalSet::new().block_on(&rt, async {
+            let stored_data = Rc::new(RefCell::new(HashMap::new()));
+            let delayed_tasks = Rc::new(RefCell::new(HashMap::new()));
+            let baseline = list_notifiers_len();
+
+            // Abandoned waiters: the blocked request is dropped on timeout
+            for i in 0..100 {
+                let request = ListLeftBlockingPopStorage {
+                    key: format!("abandoned:{i}"),
+                };
+                let result = tokio::time::timeout(
+                    Duration::from_millis(1),
+                    request.handle(&stored_data, &delayed_tasks),
+                )
+                .await;
+                assert!(result.is_err());
+            }
+            assert_eq!(list_notifiers_len(), baseline);
+
+            // Unblocked waiters: a push wakes the waiter which then returns
+            for i in 0..100 {
+                let key = format!("unblocked:{i}");
+
+                let waiter = tokio::task::spawn_local({
+                    let stored_data = Rc::clone(&stored_data);
+                    let delayed_tasks = Rc::clone(&delayed_tasks);
+                    let key = key.clone();
+                    async move {
+                        ListLeftBlockingPopStorage { key }
+                            .handle(&stored_data, &delayed_tasks)
+                            .await
+                    }
+                });
+
+                // Let the waiter register on the notifier
+                tokio::task::yield_now().await;
+                assert_eq!(list_notifiers_len(), baseline + 1);
+
+                ListRightPushStorage {
+                    key,
+                    values: vec!["value".to_string()],
+                    only_if_exists: false,
+                }
+                .handle(&stored_data, &delayed_tasks)
+                .await;
+
+                let response = waiter.await.expect("waiter task");
+                assert!(matches!(response, StorageResponse::ValueFromList { .. }));
+            }
+            assert_eq!(list_notifiers_len(), baseline);
+        });
+    }
+
+    #[test]
+    fn update_string_leaves_shared_replies_unchanged() {
+        let mut value = Bytes::from_static(b"hello");
+        let reply = value.clone();
+
+        update_string(&mut value, |bytes| bytes.extend_from_slice(b" world"));
+        assert_eq!(&value[..], b"hello world");
+        assert_eq!(&reply[..], b"hello");
+
+        // Not shared anymore: updated without a copy
+        let ptr = value.as_ptr();
+        update_string(&mut value, |bytes| bytes[0] = b'H');
+        assert_eq!(&value[..], b"Hello world");
+        assert_eq!(value.as_ptr(), ptr);
+    }
+}
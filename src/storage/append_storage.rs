@@ -0,0 +1,45 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use bytes::Bytes;
+
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, string_or_absent, update_string,
+};
+
+#[derive(Debug)]
+pub struct AppendStorage {
+    pub key: String,
+    pub value: Bytes,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for AppendStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        if let Err(failed) = string_or_absent(map_ref.get(&self.key)) {
+            return failed;
+        }
+
+        match map_ref.get_mut(&self.key) {
+            Some(StorageValue::Str(existing)) => {
+                update_string(existing, |bytes| bytes.extend_from_slice(&self.value));
+                StorageResponse::IntegerValue(existing.len() as i64)
+            }
+            _ => {
+                map_ref.insert(self.key.clone(), StorageValue::Str(self.value.clone()));
+                StorageResponse::IntegerValue(self.value.len() as i64)
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, cancel_expiration};
+
+/// Removes a batch of keys owned by a single shard together with their pending expirations.
+/// Returns how many of the keys existed. All keys must belong to the shard the request is sent to.
+#[derive(Debug)]
+pub struct DeleteStorage {
+    pub keys: Vec<String>,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for DeleteStorage {
+    fn key(&self) -> &str {
+        self.keys.first().map(String::as_str).unwrap_or_default()
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        let mut deleted = 0;
+        for key in &self.keys {
+            if map_ref.remove(key).is_some() {
+                cancel_expiration(key, delayed_tasks);
+                deleted += 1;
+            }
+        }
+
+        StorageResponse::IntegerValue(deleted)
+    }
+}
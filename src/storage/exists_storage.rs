@@ -0,0 +1,35 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue};
+
+/// Counts which keys of a batch owned by a single shard exist, answered under one borrow.
+/// Duplicate keys are counted every time. All keys must belong to the shard the request is sent to.
+#[derive(Debug)]
+pub struct ExistsStorage {
+    pub keys: Vec<String>,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for ExistsStorage {
+    fn key(&self) -> &str {
+        self.keys.first().map(String::as_str).unwrap_or_default()
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let map_ref = stored_data.borrow();
+
+        let existing = self
+            .keys
+            .iter()
+            .filter(|key| map_ref.contains_key(key.as_str()))
+            .count();
+
+        StorageResponse::IntegerValue(existing as i64)
+    }
+}
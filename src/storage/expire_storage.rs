@@ -0,0 +1,85 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+use tokio::time::{Duration, Instant};
+
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, cancel_expiration,
+    schedule_expiration,
+};
+
+/// Condition of EXPIRE on the current expiration of the key.
+/// A key without an expiration is treated as having an infinite time to live by GT and LT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpireCondition {
+    /// NX: only when the key has no expiration
+    NoExpiry,
+    /// XX: only when the key already has an expiration
+    HasExpiry,
+    /// GT: only when the new expiration is later than the current one
+    GreaterThan,
+    /// LT: only when the new expiration is earlier than the current one
+    LessThan,
+}
+
+/// Sets the expiration of an existing key to `delay_ms` from now, 0 deletes the key right away.
+/// Responds with 1 when the expiration was set, 0 when the key is missing or the condition doesn't hold.
+#[derive(Debug)]
+pub struct ExpireStorage {
+    pub key: String,
+    pub delay_ms: u64,
+    pub condition: Option<ExpireCondition>,
+}
+
+impl ExpireStorage {
+    fn condition_holds(&self, current_deadline: Option<Instant>) -> bool {
+        let new_deadline = Instant::now() + Duration::from_millis(self.delay_ms);
+
+        match (self.condition, current_deadline) {
+            (None, _) => true,
+            (Some(ExpireCondition::NoExpiry), current) => current.is_none(),
+            (Some(ExpireCondition::HasExpiry), current) => current.is_some(),
+            (Some(ExpireCondition::GreaterThan), current) => {
+                current.is_some_and(|current| new_deadline > current)
+            }
+            (Some(ExpireCondition::LessThan), current) => {
+                current.is_none_or(|current| new_deadline < current)
+            }
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for ExpireStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        if !stored_data.borrow().contains_key(&self.key) {
+            return StorageResponse::IntegerValue(0);
+        }
+
+        let current_deadline = delayed_tasks
+            .borrow()
+            .get(&self.key)
+            .map(|expiration| expiration.deadline);
+
+        if !self.condition_holds(current_deadline) {
+            return StorageResponse::IntegerValue(0);
+        }
+
+        if self.delay_ms == 0 {
+            cancel_expiration(&self.key, delayed_tasks);
+            stored_data.borrow_mut().remove(&self.key);
+        } else {
+            schedule_expiration(&self.key, self.delay_ms, stored_data, delayed_tasks);
+        }
+
+        StorageResponse::IntegerValue(1)
+    }
+}
@@ -0,0 +1,38 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, keys_changed};
+
+/// Removes every key of the shard it runs on, together with pending expirations,
+/// and reports the removed keys with `keys_changed`.
+/// Keyless, so it has to be sent with `StorageEngine::execute_on_shard`.
+#[derive(Debug)]
+pub struct FlushStorage;
+
+#[async_trait(?Send)]
+impl StorageRequest for FlushStorage {
+    fn key(&self) -> &str {
+        ""
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let removed: Vec<String> = stored_data
+            .borrow_mut()
+            .drain()
+            .map(|(key, _)| key)
+            .collect();
+
+        for (_, expiration) in delayed_tasks.borrow_mut().drain() {
+            expiration.handle.abort();
+        }
+
+        keys_changed(&removed);
+
+        StorageResponse::Success
+    }
+}
@@ -0,0 +1,61 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, cancel_expiration,
+    schedule_expiration, string_or_absent,
+};
+
+/// How a read should change the key's time to live.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpirationUpdate {
+    /// Leave the current expiration as is
+    Keep,
+    /// Expire after the given delay, 0 expires the key right away
+    ExpireInMs(u64),
+    /// Remove the current expiration
+    Persist,
+}
+
+#[derive(Debug)]
+pub struct GetExStorage {
+    pub key: String,
+    pub expiration: ExpirationUpdate,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for GetExStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let value = match string_or_absent(stored_data.borrow().get(&self.key)) {
+            Ok(Some(value)) => value.clone(),
+            // A missing key never gets an expiration
+            Ok(None) => return StorageResponse::Null,
+            Err(failed) => return failed,
+        };
+
+        match self.expiration {
+            ExpirationUpdate::Keep => {}
+            ExpirationUpdate::ExpireInMs(0) => {
+                cancel_expiration(&self.key, delayed_tasks);
+                stored_data.borrow_mut().remove(&self.key);
+            }
+            ExpirationUpdate::ExpireInMs(delay_ms) => {
+                schedule_expiration(&self.key, delay_ms, stored_data, delayed_tasks);
+            }
+            ExpirationUpdate::Persist => {
+                cancel_expiration(&self.key, delayed_tasks);
+            }
+        }
+
+        StorageResponse::StringValue { value }
+    }
+}
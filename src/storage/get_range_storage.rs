@@ -0,0 +1,97 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use bytes::Bytes;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, string_or_absent};
+
+#[derive(Debug)]
+pub struct GetRangeStorage {
+    pub key: String,
+    pub start: i64,
+    pub end: i64,
+}
+
+impl GetRangeStorage {
+    /// Converts the inclusive `[start, end]` offsets (negative values count from the end)
+    /// into a byte range, or None when the range is empty. Mirrors Redis `getrangeCommand`.
+    fn normalize_range(start: i64, end: i64, len: usize) -> Option<(usize, usize)> {
+        let len = len as i64;
+
+        if start < 0 && end < 0 && start > end {
+            return None;
+        }
+
+        let start = if start < 0 {
+            (start + len).max(0)
+        } else {
+            start
+        };
+        let end = if end < 0 { (end + len).max(0) } else { end };
+        let end = end.min(len - 1);
+
+        if len == 0 || start > end {
+            return None;
+        }
+
+        Some((start as usize, end as usize))
+    }
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for GetRangeStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let map_ref = stored_data.borrow();
+
+        let value = match string_or_absent(map_ref.get(&self.key)) {
+            Ok(value) => value.cloned().unwrap_or_default(),
+            Err(failed) => return failed,
+        };
+
+        let value = match Self::normalize_range(self.start, self.end, value.len()) {
+            Some((start, end)) => value.slice(start..=end),
+            None => Bytes::new(),
+        };
+
+        StorageResponse::StringValue { value }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetRangeStorage;
+
+    #[test]
+    fn normalize_range_cases() {
+        // "Hello" len = 5
+        assert_eq!(GetRangeStorage::normalize_range(0, -1, 5), Some((0, 4)));
+        assert_eq!(GetRangeStorage::normalize_range(1, 3, 5), Some((1, 3)));
+        assert_eq!(GetRangeStorage::normalize_range(-3, -1, 5), Some((2, 4)));
+        assert_eq!(GetRangeStorage::normalize_range(0, 100, 5), Some((0, 4)));
+
+        // Negative start beyond the string length clamps to 0
+        assert_eq!(GetRangeStorage::normalize_range(-100, -1, 5), Some((0, 4)));
+        assert_eq!(GetRangeStorage::normalize_range(-100, 2, 5), Some((0, 2)));
+    }
+
+    #[test]
+    fn normalize_range_empty_cases() {
+        // start > end
+        assert_eq!(GetRangeStorage::normalize_range(3, 1, 5), None);
+        assert_eq!(GetRangeStorage::normalize_range(-1, -3, 5), None);
+        // start past the end of the string
+        assert_eq!(GetRangeStorage::normalize_range(5, 10, 5), None);
+        // empty string
+        assert_eq!(GetRangeStorage::normalize_range(0, -1, 0), None);
+        assert_eq!(GetRangeStorage::normalize_range(0, 0, 0), None);
+    }
+}
@@ -1,9 +1,8 @@
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use async_trait::async_trait;
-use tokio::task::JoinHandle;
 
-use super::{StorageRequest, StorageResponse, StorageValue};
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR};
 
 #[derive(Debug)]
 pub struct GetStorage {
@@ -19,16 +18,13 @@ impl StorageRequest for GetStorage {
     async fn handle(
         &self,
         stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
-        _delayed_tasks: &Rc<RefCell<HashMap<String, JoinHandle<()>>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
     ) -> StorageResponse {
         match stored_data.borrow().get(&self.key) {
-            Some(StorageValue::Str(value)) => StorageResponse::KeyValue {
+            Some(StorageValue::Str(value)) => StorageResponse::StringValue {
                 value: value.clone(),
             },
-            Some(StorageValue::List(_)) => {
-                // Currently we do not have List support in the public GET API
-                StorageResponse::Null
-            }
+            Some(_) => StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
             None => StorageResponse::Null,
         }
     }
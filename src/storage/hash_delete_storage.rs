@@ -0,0 +1,51 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR,
+    cancel_expiration,
+};
+
+/// Removes the given fields of a hash and responds with how many existed.
+/// A hash left without fields is deleted, like an emptied list.
+#[derive(Debug)]
+pub struct HashDeleteStorage {
+    pub key: String,
+    pub fields: Vec<String>,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for HashDeleteStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        let (removed, remove_empty_hash) = match map_ref.get_mut(&self.key) {
+            None => return StorageResponse::IntegerValue(0),
+            Some(StorageValue::Hash(hash)) => {
+                let removed = self
+                    .fields
+                    .iter()
+                    .filter(|field| hash.remove(*field).is_some())
+                    .count();
+                (removed, hash.is_empty())
+            }
+            Some(_) => return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+        };
+
+        if remove_empty_hash {
+            map_ref.remove(&self.key);
+            cancel_expiration(&self.key, delayed_tasks);
+        }
+
+        StorageResponse::IntegerValue(removed as i64)
+    }
+}
@@ -0,0 +1,36 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR};
+
+/// Reads a single field of a hash, `Null` when either the key or the field is missing.
+#[derive(Debug)]
+pub struct HashGetStorage {
+    pub key: String,
+    pub field: String,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for HashGetStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        match stored_data.borrow().get(&self.key) {
+            Some(StorageValue::Hash(hash)) => match hash.get(&self.field) {
+                Some(value) => StorageResponse::KeyValue {
+                    value: value.clone(),
+                },
+                None => StorageResponse::Null,
+            },
+            Some(_) => StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+            None => StorageResponse::Null,
+        }
+    }
+}
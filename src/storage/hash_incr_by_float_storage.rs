@@ -0,0 +1,66 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{
+    ExpirationTask, IncrByFloatStorage, StorageRequest, StorageResponse, StorageValue,
+    WRONG_TYPE_ERROR,
+};
+
+/// Adds a floating point increment to the number stored in a field of a hash.
+/// A missing key or field is treated as 0 and created. The result is formatted like INCRBYFLOAT.
+#[derive(Debug)]
+pub struct HashIncrByFloatStorage {
+    pub key: String,
+    pub field: String,
+    pub increment: f64,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for HashIncrByFloatStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        let current = match map_ref.get(&self.key) {
+            Some(StorageValue::Hash(hash)) => match hash.get(&self.field) {
+                Some(value) => match IncrByFloatStorage::parse_float(value) {
+                    Some(current) => current,
+                    None => {
+                        return StorageResponse::Failed(
+                            "ERR hash value is not a float".to_string(),
+                        );
+                    }
+                },
+                None => 0.0,
+            },
+            Some(_) => return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+            None => 0.0,
+        };
+
+        let new_value = current + self.increment;
+        if !new_value.is_finite() {
+            return StorageResponse::Failed(
+                "ERR increment would produce NaN or Infinity".to_string(),
+            );
+        }
+
+        let new_value = IncrByFloatStorage::format_float(new_value);
+
+        if let StorageValue::Hash(hash) = map_ref
+            .entry(self.key.clone())
+            .or_insert_with(|| StorageValue::Hash(HashMap::new()))
+        {
+            hash.insert(self.field.clone(), new_value.clone());
+        }
+
+        StorageResponse::KeyValue { value: new_value }
+    }
+}
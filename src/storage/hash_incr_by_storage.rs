@@ -0,0 +1,60 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR};
+
+/// Adds a signed delta to the integer stored in a field of a hash.
+/// A missing key or field is treated as 0 and created.
+#[derive(Debug)]
+pub struct HashIncrByStorage {
+    pub key: String,
+    pub field: String,
+    pub delta: i64,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for HashIncrByStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        let current = match map_ref.get(&self.key) {
+            Some(StorageValue::Hash(hash)) => match hash.get(&self.field) {
+                Some(value) => match value.parse::<i64>() {
+                    Ok(current) => current,
+                    Err(_) => {
+                        return StorageResponse::Failed(
+                            "ERR hash value is not an integer".to_string(),
+                        );
+                    }
+                },
+                None => 0,
+            },
+            Some(_) => return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+            None => 0,
+        };
+
+        let Some(new_value) = current.checked_add(self.delta) else {
+            return StorageResponse::Failed(
+                "ERR increment or decrement would overflow".to_string(),
+            );
+        };
+
+        if let StorageValue::Hash(hash) = map_ref
+            .entry(self.key.clone())
+            .or_insert_with(|| StorageValue::Hash(HashMap::new()))
+        {
+            hash.insert(self.field.clone(), new_value.to_string());
+        }
+
+        StorageResponse::IntegerValue(new_value)
+    }
+}
@@ -0,0 +1,30 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR};
+
+/// Number of fields of a hash, 0 when the key is missing.
+#[derive(Debug)]
+pub struct HashLengthStorage {
+    pub key: String,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for HashLengthStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        match stored_data.borrow().get(&self.key) {
+            Some(StorageValue::Hash(hash)) => StorageResponse::IntegerValue(hash.len() as i64),
+            Some(_) => StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+            None => StorageResponse::IntegerValue(0),
+        }
+    }
+}
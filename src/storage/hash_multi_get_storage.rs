@@ -0,0 +1,38 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR};
+
+/// Reads a batch of fields of a hash under a single borrow, `None` for every missing field.
+/// Values are returned in the order of the requested fields, a missing key reads as an empty hash.
+#[derive(Debug)]
+pub struct HashMultiGetStorage {
+    pub key: String,
+    pub fields: Vec<String>,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for HashMultiGetStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let values = match stored_data.borrow().get(&self.key) {
+            Some(StorageValue::Hash(hash)) => self
+                .fields
+                .iter()
+                .map(|field| hash.get(field).cloned())
+                .collect(),
+            Some(_) => return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+            None => vec![None; self.fields.len()],
+        };
+
+        StorageResponse::OptionalValues(values)
+    }
+}
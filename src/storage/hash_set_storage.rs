@@ -0,0 +1,44 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR};
+
+/// Sets the given fields of a hash, creating the hash when the key is missing.
+/// Responds with the number of fields which were added (overwritten fields are not counted).
+#[derive(Debug)]
+pub struct HashSetStorage {
+    pub key: String,
+    pub fields: Vec<(String, String)>,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for HashSetStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        let hash = match map_ref
+            .entry(self.key.clone())
+            .or_insert_with(|| StorageValue::Hash(HashMap::with_capacity(self.fields.len())))
+        {
+            StorageValue::Hash(hash) => hash,
+            _ => return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+        };
+
+        let added = self
+            .fields
+            .iter()
+            .filter(|(field, value)| hash.insert(field.clone(), value.clone()).is_none())
+            .count();
+
+        StorageResponse::IntegerValue(added as i64)
+    }
+}
@@ -0,0 +1,55 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR};
+
+/// Part of every field of a hash returned by `HashViewStorage`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashView {
+    /// HGETALL: field followed by its value
+    FieldsAndValues,
+    /// HKEYS
+    Fields,
+    /// HVALS
+    Values,
+}
+
+/// Reads every field of a hash under a single borrow, an empty list when the key is missing.
+/// Fields come in the map iteration order, which is the same for every view of an unmodified hash.
+#[derive(Debug)]
+pub struct HashViewStorage {
+    pub key: String,
+    pub view: HashView,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for HashViewStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let map_ref = stored_data.borrow();
+        let hash = match map_ref.get(&self.key) {
+            Some(StorageValue::Hash(hash)) => hash,
+            Some(_) => return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+            None => return StorageResponse::ListValues { values: Vec::new() },
+        };
+
+        let values = match self.view {
+            HashView::FieldsAndValues => hash
+                .iter()
+                .flat_map(|(field, value)| [field.clone(), value.clone()])
+                .collect(),
+            HashView::Fields => hash.keys().cloned().collect(),
+            HashView::Values => hash.values().cloned().collect(),
+        };
+
+        StorageResponse::ListValues { values }
+    }
+}
@@ -0,0 +1,86 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+
+use super::{
+    ExpirationTask, NOT_A_FLOAT_ERROR, StorageRequest, StorageResponse, StorageValue,
+    string_or_absent,
+};
+
+/// Adds a floating point increment to the number stored as a string at key.
+/// The result is stored and returned in decimal form, without exponent and trailing zeros
+/// (`10.5`, `3000`), so a later INCR can still parse integral results.
+#[derive(Debug)]
+pub struct IncrByFloatStorage {
+    pub key: String,
+    pub increment: f64,
+}
+
+impl IncrByFloatStorage {
+    /// Parses a float the way Redis accepts it: any decimal or exponent form, but no NaN or infinity.
+    pub fn parse_float(value: &str) -> Option<f64> {
+        value.parse::<f64>().ok().filter(|value| value.is_finite())
+    }
+
+    /// Formats a result rounded to 15 significant digits, the precision an f64 always keeps through
+    /// decimal conversions, so `0.1 + 0.2` is `0.3` like in Redis rather than `0.30000000000000004`.
+    /// Redis prints 17 digits, but of a long double: with an f64 the last two digits are rounding noise.
+    /// f64 Display never uses an exponent and drops trailing zeros.
+    pub fn format_float(value: f64) -> String {
+        let rounded = format!("{value:.14e}").parse::<f64>().unwrap_or(value);
+        rounded.to_string()
+    }
+
+    /// Same as `parse_float`, for a stored string value.
+    fn parse_stored_float(value: &[u8]) -> Option<f64> {
+        Self::parse_float(std::str::from_utf8(value).ok()?)
+    }
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for IncrByFloatStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        // Missing key is treated as 0
+        let current = match string_or_absent(map_ref.get(&self.key)) {
+            Ok(Some(value)) => match Self::parse_stored_float(value) {
+                Some(current) => current,
+                None => return StorageResponse::Failed(NOT_A_FLOAT_ERROR.to_string()),
+            },
+            Ok(None) => 0.0,
+            Err(failed) => return failed,
+        };
+
+        let new_value = current + self.increment;
+        if !new_value.is_finite() {
+            return StorageResponse::Failed(
+                "ERR increment would produce NaN or Infinity".to_string(),
+            );
+        }
+
+        let new_value = Self::format_float(new_value);
+
+        // Update in place so an existing expiration is kept
+        match map_ref.get_mut(&self.key) {
+            Some(StorageValue::Str(value)) => *value = Bytes::from(new_value.clone()),
+            _ => {
+                map_ref.insert(
+                    self.key.clone(),
+                    StorageValue::Str(Bytes::from(new_value.clone())),
+                );
+            }
+        }
+
+        StorageResponse::KeyValue { value: new_value }
+    }
+}
@@ -0,0 +1,58 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+
+use super::{
+    ExpirationTask, NOT_AN_INTEGER_ERROR, StorageRequest, StorageResponse, StorageValue,
+    parse_integer, string_or_absent,
+};
+
+/// Adds a signed delta to the integer stored as a string at key (shared by INCR/DECR family).
+#[derive(Debug)]
+pub struct IncrByStorage {
+    pub key: String,
+    pub delta: i64,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for IncrByStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        // Missing key is treated as 0
+        let current = match string_or_absent(map_ref.get(&self.key)) {
+            Ok(Some(value)) => match parse_integer(value) {
+                Some(current) => current,
+                None => return StorageResponse::Failed(NOT_AN_INTEGER_ERROR.to_string()),
+            },
+            Ok(None) => 0,
+            Err(failed) => return failed,
+        };
+
+        let Some(new_value) = current.checked_add(self.delta) else {
+            return StorageResponse::Failed(NOT_AN_INTEGER_ERROR.to_string());
+        };
+
+        // Update in place so an existing expiration is kept
+        match map_ref.get_mut(&self.key) {
+            Some(StorageValue::Str(value)) => *value = Bytes::from(new_value.to_string()),
+            _ => {
+                map_ref.insert(
+                    self.key.clone(),
+                    StorageValue::Str(Bytes::from(new_value.to_string())),
+                );
+            }
+        }
+
+        StorageResponse::IntegerValue(new_value)
+    }
+}
@@ -0,0 +1,76 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR};
+
+#[derive(Debug)]
+pub struct ListIndexStorage {
+    pub key: String,
+    pub index: i64,
+}
+
+impl ListIndexStorage {
+    /// Converts a zero-based index, negative ones counting from the tail (-1 is the last element),
+    /// into a position inside a list of `values_len` elements. None when it is out of range.
+    fn normalize_list_index(index: i64, values_len: usize) -> Option<usize> {
+        let values_len = values_len as i64;
+        let index = if index < 0 { index + values_len } else { index };
+
+        if (0..values_len).contains(&index) {
+            Some(index as usize)
+        } else {
+            None
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for ListIndexStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        match stored_data.borrow().get(&self.key) {
+            Some(StorageValue::List(values)) => {
+                match Self::normalize_list_index(self.index, values.len()) {
+                    Some(position) => StorageResponse::KeyValue {
+                        value: values[position].clone(),
+                    },
+                    None => StorageResponse::Null,
+                }
+            }
+            Some(_) => StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+            None => StorageResponse::Null,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ListIndexStorage;
+
+    #[test]
+    fn normalize_index_cases() {
+        // len = 5
+        assert_eq!(ListIndexStorage::normalize_list_index(0, 5), Some(0));
+        assert_eq!(ListIndexStorage::normalize_list_index(4, 5), Some(4));
+        assert_eq!(ListIndexStorage::normalize_list_index(5, 5), None);
+        assert_eq!(ListIndexStorage::normalize_list_index(-1, 5), Some(4));
+        assert_eq!(ListIndexStorage::normalize_list_index(-5, 5), Some(0));
+        assert_eq!(ListIndexStorage::normalize_list_index(-6, 5), None);
+        assert_eq!(ListIndexStorage::normalize_list_index(i64::MIN, 5), None);
+        assert_eq!(ListIndexStorage::normalize_list_index(i64::MAX, 5), None);
+    }
+
+    #[test]
+    fn normalize_index_empty_list() {
+        assert_eq!(ListIndexStorage::normalize_list_index(0, 0), None);
+        assert_eq!(ListIndexStorage::normalize_list_index(-1, 0), None);
+    }
+}
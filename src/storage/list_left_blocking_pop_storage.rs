@@ -1,12 +1,17 @@
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
-use crate::storage::LIST_NOTIFIERS;
+use crate::storage::ListNotifierWaiter;
 use async_trait::async_trait;
-use tokio::sync::Notify;
-use tokio::task::JoinHandle;
 
-use super::{StorageRequest, StorageResponse, StorageValue};
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR,
+    cancel_expiration, keys_changed,
+};
 
+/// Waits until the list at `key` has an element and replies with its head.
+/// The element is only removed by `commit`, once the reply was handed to the caller, so a BLPOP
+/// cancelled before that (timeout, disconnect) does not lose it. An emptied list is removed.
+/// The popped key is reported with `keys_changed` here, as only the shard knows which key it was.
 #[derive(Debug)]
 pub struct ListLeftBlockingPopStorage {
     pub key: String,
@@ -18,11 +23,20 @@ impl StorageRequest for ListLeftBlockingPopStorage {
         &self.key
     }
 
-    fn commit(&self, stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>) {
+    fn commit(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) {
         let mut map_ref = stored_data.borrow_mut();
 
         if let Some(StorageValue::List(values)) = map_ref.get_mut(&self.key) {
             values.pop_front();
+            if values.is_empty() {
+                map_ref.remove(&self.key);
+                cancel_expiration(&self.key, delayed_tasks);
+            }
+            keys_changed(std::slice::from_ref(&self.key));
         } else {
             tracing::warn!("commit stage failed for BLPOP")
         }
@@ -31,19 +45,14 @@ impl StorageRequest for ListLeftBlockingPopStorage {
     async fn handle(
         &self,
         stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
-        _delayed_tasks: &Rc<RefCell<HashMap<String, JoinHandle<()>>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
     ) -> StorageResponse {
-        loop {
-            // Get or create per-key notifier for this shard thread
-            let notifier = LIST_NOTIFIERS.with(|cell| {
-                let mut m = cell.borrow_mut();
-                m.entry(self.key.clone())
-                    .or_insert_with(|| Rc::new(Notify::new()))
-                    .clone()
-            });
+        // Registered for the whole wait, the per-key notifier is removed once the last waiter is dropped
+        let waiter = ListNotifierWaiter::register(&self.key);
 
+        loop {
             // Acquire awaitable BEFORE checking state to avoid missed wakeups
-            let notified = notifier.notified();
+            let notified = waiter.notify().notified();
 
             {
                 let mut map_ref = stored_data.borrow_mut();
@@ -58,13 +67,10 @@ impl StorageRequest for ListLeftBlockingPopStorage {
                                 None
                             }
                         }
-                        Some(StorageValue::Str(_)) => {
-                            return StorageResponse::Failed(format!(
-                                "'{}' is not a list.",
-                                self.key
-                            ));
+                        Some(_) => {
+                            return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string());
                         }
-                        _ => None,
+                        None => None,
                     }
                 } {
                     return StorageResponse::ValueFromList {
@@ -1,9 +1,11 @@
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use async_trait::async_trait;
-use tokio::task::JoinHandle;
 
-use super::{StorageRequest, StorageResponse, StorageValue};
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR,
+    cancel_expiration,
+};
 
 #[derive(Debug)]
 pub struct ListLeftPopStorage {
@@ -22,7 +24,7 @@ impl StorageRequest for ListLeftPopStorage {
     async fn handle(
         &self,
         stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
-        _delayed_tasks: &Rc<RefCell<HashMap<String, JoinHandle<()>>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
     ) -> StorageResponse {
         let mut map_ref = stored_data.borrow_mut();
 
@@ -31,9 +33,6 @@ impl StorageRequest for ListLeftPopStorage {
 
         let response = match map_ref.get_mut(&self.key) {
             None => StorageResponse::Null,
-            Some(StorageValue::Str(_)) => {
-                StorageResponse::Failed(format!("'{}' is not a list.", self.key))
-            }
             Some(StorageValue::List(values)) => {
                 match self.count {
                     // Single element pop
@@ -81,10 +80,12 @@ impl StorageRequest for ListLeftPopStorage {
                     }
                 }
             }
+            Some(_) => StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
         };
 
         if remove_empty_list {
             map_ref.remove(&self.key);
+            cancel_expiration(&self.key, delayed_tasks);
         }
 
         response
@@ -1,9 +1,8 @@
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use async_trait::async_trait;
-use tokio::task::JoinHandle;
 
-use super::{StorageRequest, StorageResponse, StorageValue};
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR};
 
 #[derive(Debug)]
 pub struct ListLengthStorage {
@@ -19,11 +18,11 @@ impl StorageRequest for ListLengthStorage {
     async fn handle(
         &self,
         stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
-        _delayed_tasks: &Rc<RefCell<HashMap<String, JoinHandle<()>>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
     ) -> StorageResponse {
         match stored_data.borrow().get(&self.key) {
             Some(StorageValue::List(values)) => StorageResponse::ListLength(values.len()),
-            Some(_) => StorageResponse::Failed(format!("'{}' is not a list.", self.key)),
+            Some(_) => StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
             None => StorageResponse::ListLength(0),
         }
     }
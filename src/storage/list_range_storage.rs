@@ -2,20 +2,25 @@ use std::cmp::min;
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use async_trait::async_trait;
-use tokio::task::JoinHandle;
 
-use super::{StorageRequest, StorageResponse, StorageValue};
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR};
 
 #[derive(Debug)]
 pub struct ListRangeStorage {
     pub key: String,
-    pub start: i32,
-    pub end: i32,
+    pub start: i64,
+    pub end: i64,
 }
 
 impl ListRangeStorage {
-    fn normalize_list_range_index(index: i32, values_len_usize: usize, start_index: bool) -> usize {
-        let values_len = values_len_usize as i32;
+    /// Clamps a possibly negative LRANGE-style index into `0..=len` (start) or `0..len` (end).
+    /// Shared with other index ranges over ordered values, like ZRANGE.
+    pub(super) fn normalize_list_range_index(
+        index: i64,
+        values_len_usize: usize,
+        start_index: bool,
+    ) -> usize {
+        let values_len = values_len_usize as i64;
         let mut index = index;
 
         if index < 0 {
@@ -49,7 +54,7 @@ impl StorageRequest for ListRangeStorage {
     async fn handle(
         &self,
         stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
-        _delayed_tasks: &Rc<RefCell<HashMap<String, JoinHandle<()>>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
     ) -> StorageResponse {
         match stored_data.borrow().get(&self.key) {
             Some(StorageValue::List(values)) => {
@@ -76,14 +81,14 @@ impl StorageRequest for ListRangeStorage {
                         // Collect a contiguous slice from VecDeque by indexing
                         // Note: VecDeque supports indexing by logical index
                         let mut out = Vec::with_capacity(end - start + 1);
-                        for single_value in values.iter().skip(start).take(end + 1) {
+                        for single_value in values.iter().skip(start).take(end - start + 1) {
                             out.push(single_value.clone());
                         }
                         StorageResponse::ListValues { values: out }
                     }
                 }
             }
-            Some(_) => StorageResponse::Failed(format!("'{}' is not a list.", self.key)),
+            Some(_) => StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
             None => StorageResponse::Failed(format!("No list found with name '{}'", self.key)),
         }
     }
@@ -174,4 +179,30 @@ mod tests {
             0
         );
     }
+
+    #[test]
+    fn normalize_indexes_beyond_i32() {
+        // Lengths and indexes above i32::MAX must not wrap around
+        let huge_len = 3_000_000_000usize;
+        assert_eq!(
+            ListRangeStorage::normalize_list_range_index(-1, huge_len, true),
+            huge_len - 1
+        );
+        assert_eq!(
+            ListRangeStorage::normalize_list_range_index(5_000_000_000, huge_len, false),
+            huge_len - 1
+        );
+        assert_eq!(
+            ListRangeStorage::normalize_list_range_index(-5_000_000_000, 5, true),
+            0
+        );
+        assert_eq!(
+            ListRangeStorage::normalize_list_range_index(i64::MAX, 5, true),
+            5
+        );
+        assert_eq!(
+            ListRangeStorage::normalize_list_range_index(i64::MIN, 5, false),
+            0
+        );
+    }
 }
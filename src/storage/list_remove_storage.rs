@@ -0,0 +1,72 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR,
+    cancel_expiration,
+};
+
+#[derive(Debug)]
+pub struct ListRemoveStorage {
+    pub key: String,
+    /// Positive = remove up to `count` matches from head to tail
+    /// Negative = remove up to `-count` matches from tail to head
+    /// 0 = remove every match
+    pub count: i64,
+    pub value: String,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for ListRemoveStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        let (removed, remove_empty_list) = match map_ref.get_mut(&self.key) {
+            None => return StorageResponse::IntegerValue(0),
+            Some(StorageValue::List(values)) => {
+                let limit = match self.count.unsigned_abs() {
+                    0 => usize::MAX,
+                    count => usize::try_from(count).unwrap_or(usize::MAX),
+                };
+
+                let mut removed = 0;
+                if self.count >= 0 {
+                    values.retain(|element| {
+                        let remove = removed < limit && *element == self.value;
+                        removed += usize::from(remove);
+                        !remove
+                    });
+                } else {
+                    // Walk from the tail, `retain` only goes front to back
+                    let mut index = values.len();
+                    while index > 0 && removed < limit {
+                        index -= 1;
+                        if values[index] == self.value {
+                            values.remove(index);
+                            removed += 1;
+                        }
+                    }
+                }
+
+                (removed, values.is_empty())
+            }
+            Some(_) => return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+        };
+
+        if remove_empty_list {
+            map_ref.remove(&self.key);
+            cancel_expiration(&self.key, delayed_tasks);
+        }
+
+        StorageResponse::IntegerValue(removed as i64)
+    }
+}
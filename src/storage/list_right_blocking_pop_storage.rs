@@ -0,0 +1,122 @@
+use std::cell::Cell;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use crate::storage::ListNotifierWaiter;
+use async_trait::async_trait;
+
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR,
+    cancel_expiration, keys_changed,
+};
+
+/// Replies with the tail of the list at `key`, with `wait` once an element was pushed to an empty one.
+/// The element is only removed by `commit`, once the reply was handed to the caller, so a BRPOP
+/// cancelled before that (timeout, disconnect) does not lose it. An emptied list is removed.
+/// The popped key is reported with `keys_changed` here, as only the shard knows which key it was.
+///
+/// BRPOP on several keys waits with one request per key, possibly on different shards, all sharing
+/// `claim`: only the first one finding an element takes it, the others reply Null and pop nothing.
+#[derive(Debug)]
+pub struct ListRightBlockingPopStorage {
+    pub key: String,
+    /// Without it, an empty or missing list replies Null right away
+    pub wait: bool,
+    pub claim: Arc<AtomicBool>,
+    // Set by `handle` once it took the claim, `commit` pops only then
+    claimed: Cell<bool>,
+}
+
+impl ListRightBlockingPopStorage {
+    pub fn new(key: String, wait: bool, claim: Arc<AtomicBool>) -> Self {
+        Self {
+            key,
+            wait,
+            claim,
+            claimed: Cell::new(false),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for ListRightBlockingPopStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    fn commit(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) {
+        if !self.claimed.get() {
+            return;
+        }
+        let mut map_ref = stored_data.borrow_mut();
+
+        if let Some(StorageValue::List(values)) = map_ref.get_mut(&self.key) {
+            values.pop_back();
+            if values.is_empty() {
+                map_ref.remove(&self.key);
+                cancel_expiration(&self.key, delayed_tasks);
+            }
+            keys_changed(std::slice::from_ref(&self.key));
+        } else {
+            tracing::warn!("commit stage failed for BRPOP")
+        }
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        // Registered for the whole wait, the per-key notifier is removed once the last waiter is dropped
+        let waiter = ListNotifierWaiter::register(&self.key);
+
+        loop {
+            // Acquire awaitable BEFORE checking state to avoid missed wakeups
+            let notified = waiter.notify().notified();
+
+            {
+                let mut map_ref = stored_data.borrow_mut();
+
+                // Try to pop without holding a borrow across .await
+                if let Some(popped_value) = {
+                    match map_ref.get_mut(&self.key) {
+                        Some(StorageValue::List(values)) => {
+                            if !values.is_empty() {
+                                values.back()
+                            } else {
+                                None
+                            }
+                        }
+                        Some(_) => {
+                            return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string());
+                        }
+                        None => None,
+                    }
+                } {
+                    // Another key of the same BRPOP got an element first
+                    if self.claim.swap(true, Ordering::AcqRel) {
+                        return StorageResponse::Null;
+                    }
+                    self.claimed.set(true);
+
+                    return StorageResponse::ValueFromList {
+                        value: popped_value.to_string(),
+                        list_name: self.key.clone(),
+                    };
+                }
+            }
+
+            if !self.wait {
+                return StorageResponse::Null;
+            }
+
+            // Wait until someone pushes into the list
+            notified.await;
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR,
+    cancel_expiration,
+};
+
+#[derive(Debug)]
+pub struct ListRightPopStorage {
+    pub key: String,
+    /// None = pop a single element
+    /// Some(count) = pop up to `count` elements
+    pub count: Option<usize>,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for ListRightPopStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        // Use a flag to remove the key after we finish mutably borrowing its value.
+        let mut remove_empty_list = false;
+
+        let response = match map_ref.get_mut(&self.key) {
+            None => StorageResponse::Null,
+            Some(StorageValue::List(values)) => {
+                match self.count {
+                    // Single element pop
+                    None => {
+                        match values.pop_back() {
+                            Some(v) => {
+                                if values.is_empty() {
+                                    remove_empty_list = true;
+                                }
+                                StorageResponse::KeyValue { value: v }
+                            }
+                            None => {
+                                // List exists but is empty; treat as nil and remove the key
+                                remove_empty_list = true;
+                                StorageResponse::Null
+                            }
+                        }
+                    }
+                    // Multi pop (up to count)
+                    Some(count) => {
+                        if count == 0 {
+                            // Return empty array for zero count (no elements popped)
+                            StorageResponse::ListValues {
+                                values: Vec::with_capacity(0),
+                            }
+                        } else {
+                            let elems_to_removed_cnt = count.min(values.len());
+
+                            let mut out = Vec::with_capacity(elems_to_removed_cnt);
+                            for _ in 0..elems_to_removed_cnt {
+                                if let Some(popped_value) = values.pop_back() {
+                                    out.push(popped_value);
+                                } else {
+                                    tracing::warn!(
+                                        "None value popped from list using RPOP, but should not"
+                                    );
+                                    break;
+                                }
+                            }
+                            if values.is_empty() {
+                                remove_empty_list = true;
+                            }
+                            StorageResponse::ListValues { values: out }
+                        }
+                    }
+                }
+            }
+            Some(_) => StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+        };
+
+        if remove_empty_list {
+            map_ref.remove(&self.key);
+            cancel_expiration(&self.key, delayed_tasks);
+        }
+
+        response
+    }
+}
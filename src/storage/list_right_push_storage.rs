@@ -6,14 +6,15 @@ use std::{
 
 use crate::storage::LIST_NOTIFIERS;
 use async_trait::async_trait;
-use tokio::task::JoinHandle;
 
-use super::{StorageRequest, StorageResponse, StorageValue};
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR};
 
 #[derive(Debug)]
 pub struct ListRightPushStorage {
     pub key: String,
     pub values: Vec<String>,
+    /// LPUSHX/RPUSHX: push only into an existing list, a missing key is left absent (length 0)
+    pub only_if_exists: bool,
 }
 
 #[async_trait(?Send)]
@@ -25,7 +26,7 @@ impl StorageRequest for ListRightPushStorage {
     async fn handle(
         &self,
         stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
-        _delayed_tasks: &Rc<RefCell<HashMap<String, JoinHandle<()>>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
     ) -> StorageResponse {
         let key_clone = self.key.clone();
 
@@ -40,12 +41,8 @@ impl StorageRequest for ListRightPushStorage {
                     }
                     (StorageResponse::ListLength(original_values.len()), true)
                 }
-                Some(StorageValue::Str(_)) => (
-                    StorageResponse::Failed(
-                        "Can't execute Right Push for a String value, should be List".to_string(),
-                    ),
-                    false,
-                ),
+                Some(_) => (StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()), false),
+                None if self.only_if_exists => (StorageResponse::ListLength(0), false),
                 None => {
                     let length = self.values.len();
                     let mut deque = VecDeque::with_capacity(length);
@@ -60,8 +57,11 @@ impl StorageRequest for ListRightPushStorage {
 
         // Notify ALL waiters if we actually added items to a list
         if should_notify
-            && let Some(notifier) =
-                LIST_NOTIFIERS.with(|cell| cell.borrow().get(&key_clone).cloned())
+            && let Some(notifier) = LIST_NOTIFIERS.with(|cell| {
+                cell.borrow()
+                    .get(&key_clone)
+                    .map(|notifier| Rc::clone(&notifier.notify))
+            })
         {
             notifier.notify_waiters();
         }
@@ -0,0 +1,39 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue};
+
+/// Batch of GETs for keys owned by a single shard, answered under one borrow.
+/// All keys must belong to the shard the request is sent to (see `StorageEngine::shard_index_for_key`).
+#[derive(Debug)]
+pub struct MultiGetStorage {
+    pub keys: Vec<String>,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for MultiGetStorage {
+    fn key(&self) -> &str {
+        self.keys.first().map(String::as_str).unwrap_or_default()
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let map_ref = stored_data.borrow();
+
+        // Missing keys and non-string values are both reported as None
+        let values = self
+            .keys
+            .iter()
+            .map(|key| match map_ref.get(key) {
+                Some(StorageValue::Str(value)) => Some(value.clone()),
+                _ => None,
+            })
+            .collect();
+
+        StorageResponse::OptionalStringValues(values)
+    }
+}
@@ -0,0 +1,40 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, cancel_expiration};
+
+/// Batch of SETs (without expiration) for keys owned by a single shard, applied under one borrow.
+/// All keys must belong to the shard the request is sent to (see `StorageEngine::shard_index_for_key`).
+#[derive(Debug)]
+pub struct MultiSetStorage {
+    pub pairs: Vec<(String, Bytes)>,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for MultiSetStorage {
+    fn key(&self) -> &str {
+        self.pairs
+            .first()
+            .map(|(key, _)| key.as_str())
+            .unwrap_or_default()
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        for (key, value) in &self.pairs {
+            map_ref.insert(key.clone(), StorageValue::Str(value.clone()));
+
+            // Same as SET: a new value drops any previous expiration
+            cancel_expiration(key, delayed_tasks);
+        }
+
+        StorageResponse::Success
+    }
+}
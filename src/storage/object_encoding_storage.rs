@@ -0,0 +1,31 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue};
+
+/// Responds with the encoding name of the value stored at key, `Null` when the key is missing.
+#[derive(Debug)]
+pub struct ObjectEncodingStorage {
+    pub key: String,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for ObjectEncodingStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        match stored_data.borrow().get(&self.key) {
+            Some(value) => StorageResponse::KeyValue {
+                value: value.encoding().to_string(),
+            },
+            None => StorageResponse::Null,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, cancel_expiration};
+
+/// Removes the expiration of a key, returns 1 if a timeout was removed and 0 otherwise.
+#[derive(Debug)]
+pub struct PersistStorage {
+    pub key: String,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for PersistStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        _stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let removed = cancel_expiration(&self.key, delayed_tasks);
+        StorageResponse::IntegerValue(i64::from(removed))
+    }
+}
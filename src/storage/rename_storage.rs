@@ -0,0 +1,105 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+use tokio::time::Instant;
+
+use crate::storage::LIST_NOTIFIERS;
+
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, cancel_expiration,
+    schedule_expiration,
+};
+
+/// First half of RENAME: removes the value of `key` together with its pending expiration.
+/// Replies with the value and its remaining time to live, or `Null` when the key does not exist.
+#[derive(Debug)]
+pub struct RenameTakeStorage {
+    pub key: String,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for RenameTakeStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let Some(value) = stored_data.borrow_mut().remove(&self.key) else {
+            return StorageResponse::Null;
+        };
+
+        let ttl_ms = delayed_tasks.borrow().get(&self.key).map(|expiration| {
+            expiration
+                .deadline
+                .saturating_duration_since(Instant::now())
+                .as_millis() as u64
+        });
+        cancel_expiration(&self.key, delayed_tasks);
+
+        StorageResponse::TakenValue { value, ttl_ms }
+    }
+}
+
+/// Second half of RENAME: stores a value taken by `RenameTakeStorage` under `key`, keeping its remaining TTL.
+/// With `only_if_absent` (RENAMENX) an existing key is left untouched and the value is handed back
+/// as `TakenValue`, so the caller can restore it; otherwise replies with `IntegerValue(1)`.
+#[derive(Debug)]
+pub struct RenamePutStorage {
+    pub key: String,
+    // Moved into the map by `handle`, which only gets `&self`
+    pub value: RefCell<Option<StorageValue>>,
+    pub ttl_ms: Option<u64>,
+    pub only_if_absent: bool,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for RenamePutStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let Some(value) = self.value.borrow_mut().take() else {
+            return StorageResponse::Failed("RENAME value was already stored".to_string());
+        };
+
+        if self.only_if_absent && stored_data.borrow().contains_key(&self.key) {
+            return StorageResponse::TakenValue {
+                value,
+                ttl_ms: self.ttl_ms,
+            };
+        }
+
+        let is_list = matches!(value, StorageValue::List(_));
+        stored_data.borrow_mut().insert(self.key.clone(), value);
+
+        // The destination keeps the TTL of the source, any TTL of an overwritten value is dropped
+        match self.ttl_ms {
+            Some(ttl_ms) => schedule_expiration(&self.key, ttl_ms, stored_data, delayed_tasks),
+            None => {
+                cancel_expiration(&self.key, delayed_tasks);
+            }
+        }
+
+        // Clients blocked on the destination list can pop from the renamed one
+        if is_list
+            && let Some(notifier) = LIST_NOTIFIERS.with(|cell| {
+                cell.borrow()
+                    .get(&self.key)
+                    .map(|notifier| Rc::clone(&notifier.notify))
+            })
+        {
+            notifier.notify_waiters();
+        }
+
+        StorageResponse::IntegerValue(1)
+    }
+}
@@ -0,0 +1,48 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR};
+
+/// Adds members to a set, creating the set when the key is missing.
+/// Responds with the number of members which were not in the set yet.
+#[derive(Debug)]
+pub struct SetAddStorage {
+    pub key: String,
+    pub members: Vec<String>,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for SetAddStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        let set = match map_ref
+            .entry(self.key.clone())
+            .or_insert_with(|| StorageValue::Set(HashSet::with_capacity(self.members.len())))
+        {
+            StorageValue::Set(set) => set,
+            _ => return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+        };
+
+        let added = self
+            .members
+            .iter()
+            .filter(|member| set.insert((*member).clone()))
+            .count();
+
+        StorageResponse::IntegerValue(added as i64)
+    }
+}
@@ -0,0 +1,33 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR};
+
+/// Checks whether a member is in a set, responds with 1 or 0 (also for a missing key).
+#[derive(Debug)]
+pub struct SetIsMemberStorage {
+    pub key: String,
+    pub member: String,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for SetIsMemberStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        match stored_data.borrow().get(&self.key) {
+            Some(StorageValue::Set(set)) => {
+                StorageResponse::IntegerValue(i64::from(set.contains(&self.member)))
+            }
+            Some(_) => StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+            None => StorageResponse::IntegerValue(0),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR};
+
+/// Every member of a set in unspecified order, an empty list when the key is missing.
+#[derive(Debug)]
+pub struct SetMembersStorage {
+    pub key: String,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for SetMembersStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        match stored_data.borrow().get(&self.key) {
+            Some(StorageValue::Set(set)) => StorageResponse::ListValues {
+                values: set.iter().cloned().collect(),
+            },
+            Some(_) => StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+            None => StorageResponse::ListValues { values: Vec::new() },
+        }
+    }
+}
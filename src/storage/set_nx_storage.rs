@@ -0,0 +1,34 @@
+use std::{cell::RefCell, collections::HashMap, collections::hash_map::Entry, rc::Rc};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue};
+
+#[derive(Debug)]
+pub struct SetNxStorage {
+    pub key: String,
+    pub value: Bytes,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for SetNxStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        // Check and insert under a single mutable borrow, any existing value (of any type) counts as present
+        match stored_data.borrow_mut().entry(self.key.clone()) {
+            Entry::Occupied(_) => StorageResponse::IntegerValue(0),
+            Entry::Vacant(entry) => {
+                entry.insert(StorageValue::Str(self.value.clone()));
+                StorageResponse::IntegerValue(1)
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::set_random_members_storage::sample_distinct;
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR,
+    cancel_expiration,
+};
+use crate::utils::random::with_thread_prng;
+
+/// Removes random members from a set and responds with them. A set left without members is deleted.
+/// - Without count: one member as `KeyValue`, `Null` when the key is missing.
+/// - With count: up to `count` members as `ListValues`, empty when the key is missing.
+#[derive(Debug)]
+pub struct SetPopStorage {
+    pub key: String,
+    pub count: Option<usize>,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for SetPopStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        let (popped, remove_empty_set) = match map_ref.get_mut(&self.key) {
+            None if self.count.is_some() => {
+                return StorageResponse::ListValues { values: Vec::new() };
+            }
+            None => return StorageResponse::Null,
+            Some(StorageValue::Set(set)) => {
+                let popped =
+                    with_thread_prng(|prng| sample_distinct(set, self.count.unwrap_or(1), prng));
+                for member in &popped {
+                    set.remove(member);
+                }
+                (popped, set.is_empty())
+            }
+            Some(_) => return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+        };
+
+        if remove_empty_set {
+            map_ref.remove(&self.key);
+            cancel_expiration(&self.key, delayed_tasks);
+        }
+
+        match self.count {
+            Some(_) => StorageResponse::ListValues { values: popped },
+            None => match popped.into_iter().next() {
+                Some(value) => StorageResponse::KeyValue { value },
+                None => StorageResponse::Null,
+            },
+        }
+    }
+}
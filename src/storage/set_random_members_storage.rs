@@ -0,0 +1,156 @@
+use std::{cell::RefCell, collections::HashMap, collections::HashSet, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR};
+use crate::utils::random::{Prng, with_thread_prng};
+
+/// Random members of a set, left in place.
+/// - Without count: one member as `KeyValue`, `Null` when the key is missing.
+/// - Positive count: up to `count` distinct members.
+/// - Negative count: exactly `-count` members, the same member may be returned several times.
+///   When that is more than the set holds, the members are returned as `MembersToSample` instead,
+///   and picked by the connection while writing the reply (see `repeated_picks`).
+#[derive(Debug)]
+pub struct SetRandomMembersStorage {
+    pub key: String,
+    pub count: Option<i64>,
+}
+
+/// Up to `count` distinct members picked uniformly (partial Fisher-Yates shuffle).
+pub(super) fn sample_distinct(set: &HashSet<String>, count: usize, prng: &mut Prng) -> Vec<String> {
+    let mut members: Vec<&String> = set.iter().collect();
+    let count = count.min(members.len());
+
+    for i in 0..count {
+        let j = i + prng.next_below((members.len() - i) as u64) as usize;
+        members.swap(i, j);
+    }
+
+    members.into_iter().take(count).cloned().collect()
+}
+
+/// Endless picks from `members`, made independently so repeats are possible. `members` must not be empty.
+/// Cloning the iterator replays the same picks, so a reply can be measured before it is written.
+pub fn repeated_picks(members: &[String], mut prng: Prng) -> impl Iterator<Item = &[u8]> + Clone {
+    std::iter::repeat_with(move || {
+        members[prng.next_below(members.len() as u64) as usize].as_bytes()
+    })
+}
+
+/// `count` members picked independently, so repeats are possible. Empty for an empty set.
+fn sample_with_repeats(set: &HashSet<String>, count: usize, prng: &mut Prng) -> Vec<String> {
+    let members: Vec<&String> = set.iter().collect();
+    if members.is_empty() {
+        return Vec::new();
+    }
+
+    (0..count)
+        .map(|_| members[prng.next_below(members.len() as u64) as usize].clone())
+        .collect()
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for SetRandomMembersStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let map_ref = stored_data.borrow();
+        let set = match map_ref.get(&self.key) {
+            Some(StorageValue::Set(set)) => set,
+            Some(_) => return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+            None if self.count.is_some() => {
+                return StorageResponse::ListValues { values: Vec::new() };
+            }
+            None => return StorageResponse::Null,
+        };
+
+        with_thread_prng(|prng| match self.count {
+            None => match sample_distinct(set, 1, prng).pop() {
+                Some(value) => StorageResponse::KeyValue { value },
+                None => StorageResponse::Null,
+            },
+            Some(count) if count >= 0 => StorageResponse::ListValues {
+                values: sample_distinct(set, count as usize, prng),
+            },
+            // Picked here only while the reply is no larger than the set, a count parsed from the
+            // request must not size an allocation
+            Some(count) if count.unsigned_abs() <= set.len() as u64 => {
+                StorageResponse::ListValues {
+                    values: sample_with_repeats(set, count.unsigned_abs() as usize, prng),
+                }
+            }
+            Some(count) => StorageResponse::MembersToSample {
+                members: set.iter().cloned().collect(),
+                count: count.unsigned_abs() as usize,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{repeated_picks, sample_distinct, sample_with_repeats};
+    use crate::utils::random::Prng;
+
+    fn set_of(members: &[&str]) -> HashSet<String> {
+        members.iter().map(|member| member.to_string()).collect()
+    }
+
+    #[test]
+    fn sample_distinct_is_reproducible_with_a_seed() {
+        let set = set_of(&["a", "b", "c", "d", "e", "f"]);
+
+        let first = sample_distinct(&set, 3, &mut Prng::new(7));
+        let second = sample_distinct(&set, 3, &mut Prng::new(7));
+
+        assert_eq!(first, second);
+        assert_eq!(first.iter().collect::<HashSet<_>>().len(), 3);
+        assert!(first.iter().all(|member| set.contains(member)));
+    }
+
+    #[test]
+    fn sample_distinct_is_capped_by_the_set_size() {
+        let set = set_of(&["a", "b", "c"]);
+
+        let mut sample = sample_distinct(&set, 10, &mut Prng::new(1));
+        sample.sort();
+
+        assert_eq!(sample, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn sample_with_repeats_returns_exactly_count() {
+        let set = set_of(&["a", "b"]);
+
+        let sample = sample_with_repeats(&set, 10, &mut Prng::new(3));
+
+        assert_eq!(sample.len(), 10);
+        assert!(sample.iter().all(|member| set.contains(member)));
+        assert!(sample_with_repeats(&HashSet::new(), 5, &mut Prng::new(3)).is_empty());
+    }
+
+    #[test]
+    fn repeated_picks_replay_when_cloned() {
+        let members = ["a".to_string(), "b".to_string(), "c".to_string()];
+
+        let picks = repeated_picks(&members, Prng::new(5));
+        let measured: Vec<&[u8]> = picks.clone().take(20).collect();
+        let written: Vec<&[u8]> = picks.take(20).collect();
+
+        assert_eq!(measured, written);
+        assert!(
+            written
+                .iter()
+                .all(|pick| members.iter().any(|m| m.as_bytes() == *pick))
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use bytes::Bytes;
+
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, string_or_absent, update_string,
+};
+
+#[derive(Debug)]
+pub struct SetRangeStorage {
+    pub key: String,
+    pub offset: usize,
+    pub value: Bytes,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for SetRangeStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        let current_len = match string_or_absent(map_ref.get(&self.key)) {
+            Ok(value) => value.map(Bytes::len).unwrap_or(0),
+            Err(failed) => return failed,
+        };
+
+        // Empty value is a no-op: nothing is created, the current length is returned
+        if self.value.is_empty() {
+            return StorageResponse::IntegerValue(current_len as i64);
+        }
+
+        let value = match map_ref
+            .entry(self.key.clone())
+            .or_insert_with(|| StorageValue::Str(Bytes::new()))
+        {
+            StorageValue::Str(value) => value,
+            _ => unreachable!("checked by string_or_absent"),
+        };
+
+        update_string(value, |bytes| {
+            // Pad with zero bytes when the offset is past the current end
+            let new_end = self.offset + self.value.len();
+            if bytes.len() < new_end {
+                bytes.resize(new_end, 0);
+            }
+            bytes[self.offset..new_end].copy_from_slice(&self.value);
+        });
+
+        StorageResponse::IntegerValue(value.len() as i64)
+    }
+}
@@ -0,0 +1,51 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR,
+    cancel_expiration,
+};
+
+/// Removes members from a set and responds with how many were in it.
+/// A set left without members is deleted.
+#[derive(Debug)]
+pub struct SetRemoveStorage {
+    pub key: String,
+    pub members: Vec<String>,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for SetRemoveStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        let (removed, remove_empty_set) = match map_ref.get_mut(&self.key) {
+            None => return StorageResponse::IntegerValue(0),
+            Some(StorageValue::Set(set)) => {
+                let removed = self
+                    .members
+                    .iter()
+                    .filter(|member| set.remove(*member))
+                    .count();
+                (removed, set.is_empty())
+            }
+            Some(_) => return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+        };
+
+        if remove_empty_set {
+            map_ref.remove(&self.key);
+            cancel_expiration(&self.key, delayed_tasks);
+        }
+
+        StorageResponse::IntegerValue(removed as i64)
+    }
+}
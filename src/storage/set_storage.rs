@@ -1,15 +1,20 @@
-use std::{cell::RefCell, collections::HashMap, rc::Rc, time::Duration};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use async_trait::async_trait;
-use tokio::{task::JoinHandle, time::sleep};
+use bytes::Bytes;
 
-use super::{StorageRequest, StorageResponse, StorageValue};
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, cancel_expiration,
+    schedule_expiration,
+};
 
 #[derive(Debug)]
 pub struct SetStorage {
     pub key: String,
-    pub value: String,
-    pub expiration_in_ms: u64,
+    /// The request argument, stored as is: the map shares its bytes instead of copying them
+    pub value: Bytes,
+    /// None keeps the key without expiration, Some(0) expires it right away (e.g. `PXAT` in the past)
+    pub expiration_in_ms: Option<u64>,
 }
 
 #[async_trait(?Send)]
@@ -21,37 +26,24 @@ impl StorageRequest for SetStorage {
     async fn handle(
         &self,
         stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
-        delayed_tasks: &Rc<RefCell<HashMap<String, JoinHandle<()>>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
     ) -> StorageResponse {
-        {
-            // short-lived mutable borrow; do not await while borrowed
-            stored_data
-                .borrow_mut()
-                .insert(self.key.clone(), StorageValue::Str(self.value.clone()));
-            if let Some(prev_exp_handle) = delayed_tasks.borrow_mut().remove(&self.key) {
-                // abort any previously created expiration tasks if any
-                tracing::debug!("Previous expiration aborted");
-                prev_exp_handle.abort();
-            }
-        }
-
-        if self.expiration_in_ms > 0 {
-            // Delete expired key after 'expiration_in_ms' milliseconds delay
-            let key_copy = self.key.clone();
+        // abort any previously created expiration tasks if any
+        cancel_expiration(&self.key, delayed_tasks);
 
-            let local_map_copy = Rc::clone(stored_data);
-            let delayed_tasks_copy = Rc::clone(delayed_tasks);
-            let exp_ms = self.expiration_in_ms;
+        if self.expiration_in_ms == Some(0) {
+            // Set and expired at once: the previous value is gone, nothing is stored
+            stored_data.borrow_mut().remove(&self.key);
+            return StorageResponse::Success;
+        }
 
-            let exp_handler = tokio::task::spawn_local(async move {
-                sleep(Duration::from_millis(exp_ms)).await;
-                local_map_copy.borrow_mut().remove(&key_copy);
-                tracing::debug!("Key {key_copy} expired and was deleted.");
-            });
+        stored_data
+            .borrow_mut()
+            .insert(self.key.clone(), StorageValue::Str(self.value.clone()));
 
-            delayed_tasks_copy
-                .borrow_mut()
-                .insert(self.key.clone(), exp_handler);
+        if let Some(exp_ms) = self.expiration_in_ms {
+            // Delete expired key after 'expiration_in_ms' milliseconds delay
+            schedule_expiration(&self.key, exp_ms, stored_data, delayed_tasks);
         }
 
         StorageResponse::Success
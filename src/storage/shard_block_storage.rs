@@ -0,0 +1,30 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc, time::Duration};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue};
+
+/// Test helper: sleeps synchronously on the shard owning `key`, so the whole shard loop stalls
+/// and every other request for it queues up until the sleep ends. Other shards are not affected.
+#[derive(Debug)]
+pub struct ShardBlockStorage {
+    pub key: String,
+    pub duration_ms: u64,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for ShardBlockStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        _stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        // Deliberately not `tokio::time::sleep`: the point is to block the shard thread
+        std::thread::sleep(Duration::from_millis(self.duration_ms));
+        StorageResponse::Success
+    }
+}
@@ -0,0 +1,295 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Bound;
+
+pub const MIN_MAX_NOT_A_FLOAT_ERROR: &str = "ERR min or max is not a float";
+
+///
+/// Scores are IEEE 754 doubles, like in Redis: integers are exact up to 2^53, other values are
+/// the nearest double (`0.1` is stored as 0.1000000000000000055...). Replies show the shortest
+/// decimal that reads back as the same double, see `format_score`.
+///
+/// Every comparison of scores (the ordered index, ZRANGEBYSCORE/ZCOUNT bounds, ZADD GT/LT) goes
+/// through this function: `-0.0` and `0.0` are equal, `-inf` and `inf` are the lowest and highest scores.
+/// Scores are never NaN, it would only be ordered after `inf` to keep the order total.
+///
+pub fn compare_scores(left: f64, right: f64) -> Ordering {
+    left.partial_cmp(&right)
+        .unwrap_or_else(|| left.total_cmp(&right))
+}
+
+/// Parses a ZADD score: any float including `inf`, `+inf` and `-inf`, but not NaN.
+pub fn parse_score(value: &str) -> Option<f64> {
+    value.parse::<f64>().ok().filter(|value| !value.is_nan())
+}
+
+/// Score of a sorted set member, totally ordered by `compare_scores` so it can be part of a `BTreeSet` key.
+/// `-0.0` is stored as `0.0`, so replies never show a negative zero.
+#[derive(Debug, Clone, Copy)]
+struct Score(f64);
+
+impl PartialEq for Score {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Score {}
+
+impl PartialOrd for Score {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Score {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_scores(self.0, other.0)
+    }
+}
+
+///
+/// Sorted set value: unique members, each with a score.
+/// https://redis.io/docs/latest/develop/data-types/sorted-sets/
+///
+/// Members are kept in a map for score lookups and in an ordered set for iteration
+/// by score, members with the same score ordered lexicographically.
+///
+#[derive(Debug, Default)]
+pub struct SortedSet {
+    scores: HashMap<String, f64>,
+    ordered: BTreeSet<(Score, String)>,
+}
+
+impl SortedSet {
+    /// Adds a member or updates its score. Returns true when the member was not in the set yet.
+    pub fn insert(&mut self, member: &str, score: f64) -> bool {
+        let score = if score == 0.0 { 0.0 } else { score };
+
+        match self.scores.get_mut(member) {
+            Some(current) => {
+                if compare_scores(*current, score) != Ordering::Equal {
+                    self.ordered.remove(&(Score(*current), member.to_string()));
+                    self.ordered.insert((Score(score), member.to_string()));
+                    *current = score;
+                }
+                false
+            }
+            None => {
+                self.scores.insert(member.to_string(), score);
+                self.ordered.insert((Score(score), member.to_string()));
+                true
+            }
+        }
+    }
+
+    pub fn score(&self, member: &str) -> Option<f64> {
+        self.scores.get(member).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.scores.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.scores.is_empty()
+    }
+
+    /// Members with their scores, from the lowest score to the highest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&str, f64)> {
+        self.ordered
+            .iter()
+            .map(|(score, member)| (member.as_str(), score.0))
+    }
+
+    /// Removes and returns the member with the lowest score, ties broken by the smallest member.
+    pub fn pop_min(&mut self) -> Option<(String, f64)> {
+        let (score, member) = self.ordered.pop_first()?;
+        self.scores.remove(&member);
+        Some((member, score.0))
+    }
+
+    /// Removes and returns the member with the highest score, ties broken by the greatest member.
+    pub fn pop_max(&mut self) -> Option<(String, f64)> {
+        let (score, member) = self.ordered.pop_last()?;
+        self.scores.remove(&member);
+        Some((member, score.0))
+    }
+
+    /// Members with their scores inside `range`, from the lowest score to the highest.
+    /// Only the members in the range are visited.
+    pub fn range_by_score(&self, range: ScoreRange) -> impl Iterator<Item = (&str, f64)> {
+        let start = (Score(range.min.value), String::new());
+        self.ordered
+            .range((Bound::Included(start), Bound::Unbounded))
+            .map(|(score, member)| (member.as_str(), score.0))
+            .skip_while(move |(_, score)| {
+                range.min.exclusive && compare_scores(*score, range.min.value) == Ordering::Equal
+            })
+            .take_while(move |(_, score)| range.max.admits_below(*score))
+    }
+}
+
+/// One end of a score range: `1.5` is inclusive, `(1.5` exclusive, `-inf` and `+inf` are unbounded.
+#[derive(Debug, Clone, Copy)]
+pub struct ScoreBound {
+    pub value: f64,
+    pub exclusive: bool,
+}
+
+impl ScoreBound {
+    pub fn parse(value: &str) -> Option<Self> {
+        let (value, exclusive) = match value.strip_prefix('(') {
+            Some(value) => (value, true),
+            None => (value, false),
+        };
+
+        let value = parse_score(value)?;
+        Some(Self { value, exclusive })
+    }
+
+    /// Whether `score` is on the lower side of this bound when it is used as a maximum.
+    fn admits_below(&self, score: f64) -> bool {
+        match compare_scores(score, self.value) {
+            Ordering::Less => true,
+            Ordering::Equal => !self.exclusive,
+            Ordering::Greater => false,
+        }
+    }
+}
+
+/// Score interval of ZRANGEBYSCORE and ZCOUNT, given as `min max` arguments.
+/// https://redis.io/docs/latest/commands/zrangebyscore/#exclusive-intervals-and-infinity
+#[derive(Debug, Clone, Copy)]
+pub struct ScoreRange {
+    pub min: ScoreBound,
+    pub max: ScoreBound,
+}
+
+impl ScoreRange {
+    pub fn parse(min: &str, max: &str) -> Option<Self> {
+        Some(Self {
+            min: ScoreBound::parse(min)?,
+            max: ScoreBound::parse(max)?,
+        })
+    }
+}
+
+/// ZADD GT/LT: when the score of a member already in the set may be replaced.
+/// New members are added whatever the condition.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScoreUpdate {
+    #[default]
+    Always,
+    GreaterThan,
+    LessThan,
+}
+
+impl ScoreUpdate {
+    pub fn admits(&self, current: f64, new: f64) -> bool {
+        match self {
+            Self::Always => true,
+            Self::GreaterThan => compare_scores(new, current) == Ordering::Greater,
+            Self::LessThan => compare_scores(new, current) == Ordering::Less,
+        }
+    }
+}
+
+/// Formats a score the way replies carry it: shortest decimal form, without exponent (`1.5`, `10`).
+pub fn format_score(score: f64) -> String {
+    score.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn members(sorted_set: &SortedSet) -> Vec<(&str, f64)> {
+        sorted_set.iter().collect()
+    }
+
+    #[test]
+    fn infinities_are_the_lowest_and_highest_scores() {
+        let mut sorted_set = SortedSet::default();
+        sorted_set.insert("max", f64::MAX);
+        sorted_set.insert("inf", f64::INFINITY);
+        sorted_set.insert("zero", 0.0);
+        sorted_set.insert("-inf", f64::NEG_INFINITY);
+        sorted_set.insert("min", f64::MIN);
+
+        assert_eq!(
+            members(&sorted_set),
+            vec![
+                ("-inf", f64::NEG_INFINITY),
+                ("min", f64::MIN),
+                ("zero", 0.0),
+                ("max", f64::MAX),
+                ("inf", f64::INFINITY),
+            ]
+        );
+
+        let range = ScoreRange::parse("(-inf", "+inf").expect("valid range");
+        assert_eq!(sorted_set.range_by_score(range).count(), 4);
+        let range = ScoreRange::parse("inf", "inf").expect("valid range");
+        assert_eq!(
+            sorted_set.range_by_score(range).collect::<Vec<_>>(),
+            vec![("inf", f64::INFINITY)]
+        );
+    }
+
+    #[test]
+    fn negative_zero_equals_zero() {
+        assert_eq!(compare_scores(-0.0, 0.0), Ordering::Equal);
+
+        let mut sorted_set = SortedSet::default();
+        assert!(sorted_set.insert("a", -0.0));
+        assert!(!sorted_set.insert("a", 0.0));
+        assert!(sorted_set.insert("b", 0.0));
+        assert_eq!(format_score(sorted_set.score("a").unwrap()), "0");
+
+        // Both bounds match 0 whatever the sign
+        let range = ScoreRange::parse("-0", "0").expect("valid range");
+        assert_eq!(sorted_set.range_by_score(range).count(), 2);
+        let range = ScoreRange::parse("(-0", "+inf").expect("valid range");
+        assert_eq!(sorted_set.range_by_score(range).count(), 0);
+        let range = ScoreRange::parse("-inf", "(-0").expect("valid range");
+        assert_eq!(sorted_set.range_by_score(range).count(), 0);
+
+        assert!(!ScoreUpdate::GreaterThan.admits(-0.0, 0.0));
+        assert!(!ScoreUpdate::LessThan.admits(0.0, -0.0));
+    }
+
+    #[test]
+    fn equal_scores_are_ordered_by_member() {
+        let mut sorted_set = SortedSet::default();
+        for member in ["c", "a", "b", "B"] {
+            sorted_set.insert(member, 1.0);
+        }
+        sorted_set.insert("z", 0.5);
+
+        assert_eq!(
+            members(&sorted_set),
+            vec![("z", 0.5), ("B", 1.0), ("a", 1.0), ("b", 1.0), ("c", 1.0)]
+        );
+        assert_eq!(sorted_set.pop_max(), Some(("c".to_string(), 1.0)));
+        assert_eq!(sorted_set.pop_min(), Some(("z".to_string(), 0.5)));
+    }
+
+    #[test]
+    fn score_update_conditions() {
+        assert!(ScoreUpdate::Always.admits(2.0, 1.0));
+        assert!(ScoreUpdate::GreaterThan.admits(1.0, 2.0));
+        assert!(!ScoreUpdate::GreaterThan.admits(2.0, 2.0));
+        assert!(ScoreUpdate::GreaterThan.admits(f64::MAX, f64::INFINITY));
+        assert!(ScoreUpdate::LessThan.admits(f64::MIN, f64::NEG_INFINITY));
+        assert!(!ScoreUpdate::LessThan.admits(f64::NEG_INFINITY, f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn nan_is_not_a_score() {
+        assert_eq!(parse_score("nan"), None);
+        assert_eq!(parse_score("+inf"), Some(f64::INFINITY));
+        assert_eq!(parse_score("-inf"), Some(f64::NEG_INFINITY));
+        assert!(ScoreBound::parse("(nan").is_none());
+    }
+}
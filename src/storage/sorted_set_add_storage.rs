@@ -0,0 +1,52 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{
+    ExpirationTask, ScoreUpdate, SortedSet, StorageRequest, StorageResponse, StorageValue,
+    WRONG_TYPE_ERROR,
+};
+
+/// Adds members with their scores to a sorted set, creating it when the key is missing.
+/// Members already in the set get their score updated when `update` admits the new score.
+/// Responds with the number of members which were not in the set yet.
+#[derive(Debug)]
+pub struct SortedSetAddStorage {
+    pub key: String,
+    pub entries: Vec<(f64, String)>,
+    pub update: ScoreUpdate,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for SortedSetAddStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        let sorted_set = match map_ref
+            .entry(self.key.clone())
+            .or_insert_with(|| StorageValue::ZSet(SortedSet::default()))
+        {
+            StorageValue::ZSet(sorted_set) => sorted_set,
+            _ => return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+        };
+
+        let added = self
+            .entries
+            .iter()
+            .filter(|(score, member)| match sorted_set.score(member) {
+                Some(current) if !self.update.admits(current, *score) => false,
+                _ => sorted_set.insert(member, *score),
+            })
+            .count();
+
+        StorageResponse::IntegerValue(added as i64)
+    }
+}
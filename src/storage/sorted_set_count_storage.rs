@@ -0,0 +1,35 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{
+    ExpirationTask, ScoreRange, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR,
+};
+
+/// Number of members of a sorted set whose score is inside `range`, 0 when key does not exist.
+#[derive(Debug)]
+pub struct SortedSetCountStorage {
+    pub key: String,
+    pub range: ScoreRange,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for SortedSetCountStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        match stored_data.borrow().get(&self.key) {
+            Some(StorageValue::ZSet(sorted_set)) => {
+                StorageResponse::IntegerValue(sorted_set.range_by_score(self.range).count() as i64)
+            }
+            None => StorageResponse::IntegerValue(0),
+            Some(_) => StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+        }
+    }
+}
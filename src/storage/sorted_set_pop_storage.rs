@@ -0,0 +1,61 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR,
+    cancel_expiration, format_score,
+};
+
+/// Removes up to `count` members from the lowest scores end of a sorted set, or from the highest
+/// scores end when `max` is set, and responds with them as `ListValues` of member, score pairs.
+/// Empty when the key is missing. A sorted set left without members is deleted.
+#[derive(Debug)]
+pub struct SortedSetPopStorage {
+    pub key: String,
+    pub count: usize,
+    pub max: bool,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for SortedSetPopStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        let (values, remove_empty_set) = match map_ref.get_mut(&self.key) {
+            None => return StorageResponse::ListValues { values: Vec::new() },
+            Some(StorageValue::ZSet(sorted_set)) => {
+                let mut values = Vec::with_capacity(self.count.min(sorted_set.len()) * 2);
+                for _ in 0..self.count {
+                    let popped = if self.max {
+                        sorted_set.pop_max()
+                    } else {
+                        sorted_set.pop_min()
+                    };
+                    let Some((member, score)) = popped else {
+                        break;
+                    };
+                    values.push(member);
+                    values.push(format_score(score));
+                }
+                (values, sorted_set.is_empty())
+            }
+            Some(_) => return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+        };
+
+        if remove_empty_set {
+            map_ref.remove(&self.key);
+            cancel_expiration(&self.key, delayed_tasks);
+        }
+
+        StorageResponse::ListValues { values }
+    }
+}
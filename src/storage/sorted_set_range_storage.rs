@@ -0,0 +1,67 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{
+    ExpirationTask, ListRangeStorage, StorageRequest, StorageResponse, StorageValue,
+    WRONG_TYPE_ERROR, format_score,
+};
+
+/// Members of a sorted set between two inclusive rank indexes, normalized like LRANGE indexes.
+/// Ranks follow the score order (ties by member), or the opposite order when `reverse` is set.
+/// With `with_scores`, every member is followed by its score in the values.
+#[derive(Debug)]
+pub struct SortedSetRangeStorage {
+    pub key: String,
+    pub start: i64,
+    pub end: i64,
+    pub reverse: bool,
+    pub with_scores: bool,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for SortedSetRangeStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let map_ref = stored_data.borrow();
+        let sorted_set = match map_ref.get(&self.key) {
+            Some(StorageValue::ZSet(sorted_set)) if !sorted_set.is_empty() => sorted_set,
+            Some(StorageValue::ZSet(_)) | None => {
+                return StorageResponse::ListValues { values: Vec::new() };
+            }
+            Some(_) => return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+        };
+
+        let len = sorted_set.len();
+        let start = ListRangeStorage::normalize_list_range_index(self.start, len, true);
+        let end = ListRangeStorage::normalize_list_range_index(self.end, len, false);
+
+        if start == len || start > end {
+            return StorageResponse::ListValues { values: Vec::new() };
+        }
+
+        let ranked: Box<dyn Iterator<Item = (&str, f64)>> = if self.reverse {
+            Box::new(sorted_set.iter().rev())
+        } else {
+            Box::new(sorted_set.iter())
+        };
+
+        let count = end - start + 1;
+        let mut values = Vec::with_capacity(if self.with_scores { count * 2 } else { count });
+        for (member, score) in ranked.skip(start).take(count) {
+            values.push(member.to_string());
+            if self.with_scores {
+                values.push(format_score(score));
+            }
+        }
+
+        StorageResponse::ListValues { values }
+    }
+}
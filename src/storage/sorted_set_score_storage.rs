@@ -0,0 +1,38 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR, format_score,
+};
+
+/// Score of a sorted set member as `KeyValue`, `Null` when the key or the member is missing.
+#[derive(Debug)]
+pub struct SortedSetScoreStorage {
+    pub key: String,
+    pub member: String,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for SortedSetScoreStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        match stored_data.borrow().get(&self.key) {
+            Some(StorageValue::ZSet(sorted_set)) => match sorted_set.score(&self.member) {
+                Some(score) => StorageResponse::KeyValue {
+                    value: format_score(score),
+                },
+                None => StorageResponse::Null,
+            },
+            Some(_) => StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+            None => StorageResponse::Null,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, StreamIdSpec, WRONG_TYPE_ERROR,
+};
+
+#[derive(Debug)]
+pub struct StreamAddStorage {
+    pub key: String,
+    pub id: StreamIdSpec,
+    pub fields: Vec<(String, String)>,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for StreamAddStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let mut map_ref = stored_data.borrow_mut();
+
+        let last_id = match map_ref.get(&self.key) {
+            Some(StorageValue::Stream(entries)) => entries.last().map(|(id, _)| *id),
+            Some(_) => return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+            None => None,
+        };
+
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .unwrap_or(0);
+
+        // Validate the ID before touching the map, so a rejected XADD never creates the key
+        let new_id = match self.id.resolve(last_id, now_ms) {
+            Ok(id) => id,
+            Err(msg) => return StorageResponse::Failed(msg),
+        };
+
+        let entry = (new_id, self.fields.clone());
+
+        match map_ref.get_mut(&self.key) {
+            Some(StorageValue::Stream(entries)) => entries.push(entry),
+            _ => {
+                map_ref.insert(self.key.clone(), StorageValue::Stream(vec![entry]));
+            }
+        }
+
+        StorageResponse::KeyValue {
+            value: new_id.to_string(),
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::fmt::{Display, Formatter};
+
+use anyhow::{Result, anyhow};
+
+pub const INVALID_STREAM_ID_ERROR: &str =
+    "ERR Invalid stream ID specified as stream command argument";
+
+///
+/// Stream entry ID in the `<milliseconds>-<sequence>` form.
+/// https://redis.io/docs/latest/develop/data-types/streams/#entry-ids
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct StreamId {
+    pub ms: u64,
+    pub seq: u64,
+}
+
+impl StreamId {
+    pub const MIN: StreamId = StreamId { ms: 0, seq: 0 };
+    pub const MAX: StreamId = StreamId {
+        ms: u64::MAX,
+        seq: u64::MAX,
+    };
+
+    /// Parses an explicit `ms-seq` or `ms` ID. A missing sequence part is interpreted as `default_seq`.
+    pub fn parse(value: &str, default_seq: u64) -> Result<Self> {
+        let (ms_str, seq_str) = match value.split_once('-') {
+            Some((ms_str, seq_str)) => (ms_str, Some(seq_str)),
+            None => (value, None),
+        };
+
+        let ms = ms_str
+            .parse::<u64>()
+            .map_err(|_| anyhow!(INVALID_STREAM_ID_ERROR))?;
+
+        let seq = match seq_str {
+            Some(seq_str) => seq_str
+                .parse::<u64>()
+                .map_err(|_| anyhow!(INVALID_STREAM_ID_ERROR))?,
+            None => default_seq,
+        };
+
+        Ok(Self { ms, seq })
+    }
+}
+
+/// Parses an XRANGE bound: `-` and `+` are the smallest and greatest possible IDs,
+/// an incomplete `<ms>` ID covers the whole millisecond (sequence 0 for start, max for end).
+pub fn parse_stream_range_bound(value: &str, is_start: bool) -> Result<StreamId> {
+    match value {
+        "-" => Ok(StreamId::MIN),
+        "+" => Ok(StreamId::MAX),
+        _ => StreamId::parse(value, if is_start { 0 } else { u64::MAX }),
+    }
+}
+
+impl Display for StreamId {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "{}-{}", self.ms, self.seq)
+    }
+}
+
+/// ID argument of XADD, which may ask the server to generate the whole ID or only the sequence part.
+#[derive(Debug, Clone, Copy)]
+pub enum StreamIdSpec {
+    /// `*`
+    Auto,
+    /// `<ms>-*`
+    AutoSequence(u64),
+    /// `<ms>-<seq>` or `<ms>`
+    Explicit(StreamId),
+}
+
+impl StreamIdSpec {
+    pub fn parse(value: &str) -> Result<Self> {
+        if value == "*" {
+            return Ok(Self::Auto);
+        }
+
+        if let Some(ms_str) = value.strip_suffix("-*") {
+            let ms = ms_str
+                .parse::<u64>()
+                .map_err(|_| anyhow!(INVALID_STREAM_ID_ERROR))?;
+            return Ok(Self::AutoSequence(ms));
+        }
+
+        Ok(Self::Explicit(StreamId::parse(value, 0)?))
+    }
+
+    /// Computes the ID for a new entry given the current top item of the stream.
+    /// New IDs must be strictly greater than the last one to keep the stream monotonic.
+    pub fn resolve(&self, last_id: Option<StreamId>, now_ms: u64) -> Result<StreamId, String> {
+        let last_id = last_id.unwrap_or(StreamId::MIN);
+
+        let new_id = match *self {
+            Self::Auto => {
+                if now_ms > last_id.ms {
+                    StreamId { ms: now_ms, seq: 0 }
+                } else {
+                    Self::next_sequence(last_id)?
+                }
+            }
+            Self::AutoSequence(ms) => {
+                if ms == last_id.ms {
+                    Self::next_sequence(last_id)?
+                } else {
+                    StreamId { ms, seq: 0 }
+                }
+            }
+            Self::Explicit(id) => {
+                if id == StreamId::MIN {
+                    return Err("ERR The ID specified in XADD must be greater than 0-0".to_string());
+                }
+                id
+            }
+        };
+
+        if new_id <= last_id {
+            return Err(
+                "ERR The ID specified in XADD is equal or smaller than the target stream top item"
+                    .to_string(),
+            );
+        }
+
+        Ok(new_id)
+    }
+
+    fn next_sequence(last_id: StreamId) -> Result<StreamId, String> {
+        match last_id.seq.checked_add(1) {
+            Some(seq) => Ok(StreamId {
+                ms: last_id.ms,
+                seq,
+            }),
+            None => Err(
+                "ERR The stream has exhausted the last possible ID, unable to add more items"
+                    .to_string(),
+            ),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{
+    ExpirationTask, StorageRequest, StorageResponse, StorageValue, StreamId, WRONG_TYPE_ERROR,
+};
+
+#[derive(Debug)]
+pub struct StreamRangeStorage {
+    pub key: String,
+    /// Inclusive lower bound
+    pub start: StreamId,
+    /// Inclusive upper bound
+    pub end: StreamId,
+    /// None = return all entries in range
+    pub count: Option<usize>,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for StreamRangeStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        match stored_data.borrow().get(&self.key) {
+            Some(StorageValue::Stream(entries)) => {
+                // Entries are kept sorted by ID (XADD enforces monotonic IDs), so binary search the start
+                let first_idx = entries.partition_point(|(id, _)| *id < self.start);
+
+                let entries = entries[first_idx..]
+                    .iter()
+                    .take_while(|(id, _)| *id <= self.end)
+                    .take(self.count.unwrap_or(usize::MAX))
+                    .cloned()
+                    .collect();
+
+                StorageResponse::StreamEntries { entries }
+            }
+            Some(_) => StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+            None => StorageResponse::StreamEntries {
+                entries: Vec::with_capacity(0),
+            },
+        }
+    }
+}
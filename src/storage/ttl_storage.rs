@@ -0,0 +1,39 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+use tokio::time::Instant;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue};
+
+/// Remaining time to live of a key in milliseconds, -2 when the key does not exist and -1 when it has no expiration.
+#[derive(Debug)]
+pub struct TtlStorage {
+    pub key: String,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for TtlStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        if !stored_data.borrow().contains_key(&self.key) {
+            return StorageResponse::IntegerValue(-2);
+        }
+
+        match delayed_tasks.borrow().get(&self.key) {
+            Some(expiration) => {
+                let remaining = expiration
+                    .deadline
+                    .saturating_duration_since(Instant::now());
+                StorageResponse::IntegerValue(remaining.as_millis() as i64)
+            }
+            None => StorageResponse::IntegerValue(-1),
+        }
+    }
+}
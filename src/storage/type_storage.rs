@@ -0,0 +1,33 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{ExpirationTask, StorageRequest, StorageResponse, StorageValue};
+
+/// Responds with the type name of the value stored at key (`none` when missing), as reported by TYPE.
+#[derive(Debug)]
+pub struct TypeStorage {
+    pub key: String,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for TypeStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let type_name = stored_data
+            .borrow()
+            .get(&self.key)
+            .map_or("none", StorageValue::type_name);
+
+        StorageResponse::KeyValue {
+            value: type_name.to_string(),
+        }
+    }
+}
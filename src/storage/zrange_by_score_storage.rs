@@ -0,0 +1,62 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use async_trait::async_trait;
+
+use super::{
+    ExpirationTask, ScoreRange, StorageRequest, StorageResponse, StorageValue, WRONG_TYPE_ERROR,
+    format_score,
+};
+
+/// Members of a sorted set whose score is inside `range`, ordered by score then member.
+/// `limit` is the `(offset, count)` of the LIMIT option: a negative offset selects nothing,
+/// a negative count everything after the offset.
+/// With `with_scores`, every member is followed by its score in the values.
+#[derive(Debug)]
+pub struct ZRangeByScoreStorage {
+    pub key: String,
+    pub range: ScoreRange,
+    pub limit: Option<(i64, i64)>,
+    pub with_scores: bool,
+}
+
+#[async_trait(?Send)]
+impl StorageRequest for ZRangeByScoreStorage {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn handle(
+        &self,
+        stored_data: &Rc<RefCell<HashMap<String, StorageValue>>>,
+        _delayed_tasks: &Rc<RefCell<HashMap<String, ExpirationTask>>>,
+    ) -> StorageResponse {
+        let map_ref = stored_data.borrow();
+        let sorted_set = match map_ref.get(&self.key) {
+            Some(StorageValue::ZSet(sorted_set)) => sorted_set,
+            None => return StorageResponse::ListValues { values: Vec::new() },
+            Some(_) => return StorageResponse::Failed(WRONG_TYPE_ERROR.to_string()),
+        };
+
+        let (offset, count) = match self.limit {
+            Some((offset, _)) if offset < 0 => {
+                return StorageResponse::ListValues { values: Vec::new() };
+            }
+            Some((offset, count)) => (offset as usize, usize::try_from(count).ok()),
+            None => (0, None),
+        };
+
+        let mut values = Vec::new();
+        for (member, score) in sorted_set
+            .range_by_score(self.range)
+            .skip(offset)
+            .take(count.unwrap_or(usize::MAX))
+        {
+            values.push(member.to_string());
+            if self.with_scores {
+                values.push(format_score(score));
+            }
+        }
+
+        StorageResponse::ListValues { values }
+    }
+}
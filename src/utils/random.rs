@@ -0,0 +1,90 @@
+use std::cell::RefCell;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Small, fast, non-cryptographic PRNG (xorshift64*).
+/// A fixed seed always gives the same sequence, which keeps randomized behaviour reproducible in tests.
+#[derive(Debug, Clone)]
+pub struct Prng {
+    state: u64,
+}
+
+impl Prng {
+    pub fn new(seed: u64) -> Self {
+        // xorshift never leaves the all-zero state, so replace it with an arbitrary odd constant
+        let state = if seed == 0 {
+            0x9E37_79B9_7F4A_7C15
+        } else {
+            seed
+        };
+        Self { state }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Random value in `0..bound`, 0 when `bound` is 0.
+    pub fn next_below(&mut self, bound: u64) -> u64 {
+        if bound == 0 {
+            0
+        } else {
+            self.next_u64() % bound
+        }
+    }
+}
+
+thread_local! {
+    // Seeded per thread from the clock and the thread id, so shards don't share a sequence.
+    static THREAD_PRNG: RefCell<Prng> = RefCell::new(Prng::new(thread_seed()));
+}
+
+fn thread_seed() -> u64 {
+    let mut hasher = DefaultHasher::new();
+    std::thread::current().id().hash(&mut hasher);
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos())
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Random value in `0..bound` from the current thread's PRNG, 0 when `bound` is 0.
+pub fn random_below(bound: u64) -> u64 {
+    with_thread_prng(|prng| prng.next_below(bound))
+}
+
+/// Runs `f` with the current thread's PRNG. Code taking the generator as `&mut Prng`
+/// can be driven by a seeded one in tests and by the thread's one at runtime.
+pub fn with_thread_prng<T>(f: impl FnOnce(&mut Prng) -> T) -> T {
+    THREAD_PRNG.with(|prng| f(&mut prng.borrow_mut()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Prng;
+
+    #[test]
+    fn same_seed_gives_same_sequence() {
+        let mut first = Prng::new(42);
+        let mut second = Prng::new(42);
+
+        for _ in 0..100 {
+            assert_eq!(first.next_u64(), second.next_u64());
+        }
+    }
+
+    #[test]
+    fn next_below_stays_in_range() {
+        let mut prng = Prng::new(0);
+
+        assert_eq!(prng.next_below(0), 0);
+        for _ in 0..1_000 {
+            assert!(prng.next_below(10) < 10);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::thread::JoinHandle;
 
 pub fn current_thread_name_or_default(default_name: &str) -> String {
@@ -15,21 +16,33 @@ pub fn wait_for_all(handlers: Vec<JoinHandle<()>>) {
     }
 }
 
+// Set once at startup from `--no-cpu-affinity` and `--cpu-affinity-offset`, before any thread is pinned.
+static CPU_AFFINITY_ENABLED: AtomicBool = AtomicBool::new(true);
+static CPU_AFFINITY_OFFSET: AtomicUsize = AtomicUsize::new(0);
+
+pub fn configure_cpu_affinity(enabled: bool, offset: usize) {
+    CPU_AFFINITY_ENABLED.store(enabled, Ordering::Relaxed);
+    CPU_AFFINITY_OFFSET.store(offset, Ordering::Relaxed);
+}
+
 /// Pin current thread to a CPU core for stronger isolation and better performance (Linux only).
+/// Cores are numbered from `--cpu-affinity-offset`; with `--no-cpu-affinity` threads are left to the OS scheduler.
 #[cfg(target_os = "linux")]
 pub fn pin_current_thread_to_cpu(id: usize, core_affinity_range: std::ops::Range<usize>) {
-    let core = core_affinity_range.start + (id % core_affinity_range.len());
+    if !CPU_AFFINITY_ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
 
-    let _ = affinity::set_thread_affinity([core]);
-    tracing::info!("Pinned to CPU {core}");
-}
+    let core = CPU_AFFINITY_OFFSET.load(Ordering::Relaxed)
+        + core_affinity_range.start
+        + (id % core_affinity_range.len());
 
-#[cfg(target_os = "windows")]
-pub fn pin_current_thread_to_cpu(_id: usize, _core_affinity_range: std::ops::Range<usize>) {
-    // No-op for Windows platforms
+    match affinity::set_thread_affinity([core]) {
+        Ok(()) => tracing::info!("Pinned to CPU {core}"),
+        Err(error) => tracing::warn!("Can't pin to CPU {core}: {error}"),
+    }
 }
 
-#[cfg(target_os = "macos")]
-pub fn pin_current_thread_to_cpu(_id: usize, _core_affinity_range: std::ops::Range<usize>) {
-    // No-op for Macos platforms
-}
+/// Thread affinity is only implemented for Linux, other platforms (Windows, macOS) never pin threads.
+#[cfg(not(target_os = "linux"))]
+pub fn pin_current_thread_to_cpu(_id: usize, _core_affinity_range: std::ops::Range<usize>) {}
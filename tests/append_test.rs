@@ -0,0 +1,57 @@
+mod common;
+
+use crate::common::ValkyrieClientTest;
+
+// https://redis.io/docs/latest/commands/append/
+// APPEND key value
+
+fn append_request(key: &str, value: &str) -> String {
+    format!(
+        "*3\r\n$6\r\nAPPEND\r\n${}\r\n{}\r\n${}\r\n{}\r\n",
+        key.len(),
+        key,
+        value.len(),
+        value
+    )
+}
+
+#[test]
+fn append_creates_missing_key() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(&append_request("greeting", "Hello"), ":5\r\n");
+    client.assert_command_response("*2\r\n$3\r\nGET\r\n$8\r\ngreeting\r\n", "$5\r\nHello\r\n");
+}
+
+#[test]
+fn append_to_existing_string() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(
+        "*3\r\n$3\r\nSET\r\n$8\r\ngreeting\r\n$5\r\nHello\r\n",
+        "+OK\r\n",
+    );
+    client.assert_command_response(&append_request("greeting", " World"), ":11\r\n");
+    client.assert_command_response(&append_request("greeting", ""), ":11\r\n");
+    client.assert_command_response(
+        "*2\r\n$3\r\nGET\r\n$8\r\ngreeting\r\n",
+        "$11\r\nHello World\r\n",
+    );
+}
+
+#[test]
+fn append_wrong_type_returns_error() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response("*3\r\n$5\r\nRPUSH\r\n$4\r\nlist\r\n$1\r\na\r\n", ":1\r\n");
+    client.assert_command_response(
+        &append_request("list", "x"),
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+
+    // The list is left untouched
+    client.assert_command_response("*2\r\n$4\r\nLLEN\r\n$4\r\nlist\r\n", ":1\r\n");
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use crate::common::{request, start_client};
+
+// Commands with an argument spec (GET, SET, RPUSH, LRANGE) are validated during dispatch,
+// so malformed requests get the same error replies whatever the command.
+
+fn wrong_arity(command: &str) -> String {
+    format!("-ERR wrong number of arguments for '{command}' command\r\n")
+}
+
+#[test]
+fn wrong_arity_is_reported_the_same_way_for_every_command() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["GET"]), &wrong_arity("get"));
+    client.assert_command_response(&request(&["GET", "a", "b"]), &wrong_arity("get"));
+    client.assert_command_response(&request(&["SET", "key"]), &wrong_arity("set"));
+    client.assert_command_response(&request(&["rpush", "list"]), &wrong_arity("rpush"));
+    client.assert_command_response(&request(&["LRANGE", "list", "0"]), &wrong_arity("lrange"));
+    client.assert_command_response(
+        &request(&["LRANGE", "list", "0", "1", "2"]),
+        &wrong_arity("lrange"),
+    );
+
+    // Nothing was written by the rejected requests
+    client.assert_command_response(&request(&["EXISTS", "key", "list"]), ":0\r\n");
+}
+
+#[test]
+fn wrong_argument_types_are_reported_with_the_argument_name() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        "*2\r\n$3\r\nGET\r\n:1\r\n",
+        "-ERR invalid argument 'key' for 'get' command, expected a bulk string\r\n",
+    );
+    client.assert_command_response(
+        "*3\r\n$3\r\nSET\r\n$3\r\nkey\r\n+value\r\n",
+        "-ERR invalid argument 'value' for 'set' command, expected a bulk string or an integer\r\n",
+    );
+    client.assert_command_response(
+        "*4\r\n$5\r\nRPUSH\r\n$4\r\nlist\r\n$1\r\na\r\n$-1\r\n",
+        "-ERR invalid argument 'element' for 'rpush' command, expected a bulk string or an integer\r\n",
+    );
+    client.assert_command_response(
+        &request(&["LRANGE", "list", "0", "last"]),
+        "-ERR value is not an integer or out of range\r\n",
+    );
+}
+
+#[test]
+fn well_formed_requests_still_reach_the_command() {
+    let mut client = start_client();
+
+    // Integers are accepted where the spec allows them
+    client.assert_command_response(
+        "*4\r\n$5\r\nRPUSH\r\n$4\r\nlist\r\n$1\r\na\r\n:7\r\n",
+        ":2\r\n",
+    );
+    client.assert_command_response(
+        "*4\r\n$6\r\nLRANGE\r\n$4\r\nlist\r\n:0\r\n:-1\r\n",
+        "*2\r\n$1\r\na\r\n$1\r\n7\r\n",
+    );
+
+    // SET options are left to SET itself
+    client.assert_command_response(
+        &request(&["SET", "key", "value", "EX"]),
+        "-ERR syntax error\r\n",
+    );
+    client.assert_command_response(&request(&["SET", "key", "value", "EX", "10"]), "+OK\r\n");
+    client.assert_command_response(&request(&["GET", "key"]), "$5\r\nvalue\r\n");
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use std::io::{Read, Write};
+
+use crate::common::{ValkyrieClientTest, request};
+
+// https://redis.io/docs/latest/commands/auth/
+// --requirepass gates every command except AUTH, HELLO and QUIT until AUTH succeeds
+
+const NOAUTH_ERROR: &str = "-NOAUTH Authentication required.\r\n";
+const WRONGPASS_ERROR: &str = "-WRONGPASS invalid username-password pair or user is disabled.\r\n";
+
+fn start_client_with_password() -> ValkyrieClientTest {
+    let server = common::ValkyrieServerTest::start_with_args(2, 3, &["--requirepass", "s3cret"])
+        .expect("start server");
+    ValkyrieClientTest::new(server)
+}
+
+#[test]
+fn commands_are_rejected_before_auth() {
+    let mut client = start_client_with_password();
+
+    client.assert_command_response(&request(&["GET", "key"]), NOAUTH_ERROR);
+    client.assert_command_response(&request(&["SET", "key", "value"]), NOAUTH_ERROR);
+    client.assert_command_response(&request(&["PING"]), NOAUTH_ERROR);
+    client.assert_command_response(&request(&["COMMAND", "COUNT"]), NOAUTH_ERROR);
+}
+
+#[test]
+fn wrong_password_keeps_connection_unauthenticated() {
+    let mut client = start_client_with_password();
+
+    client.assert_command_response(&request(&["AUTH", "wrong"]), WRONGPASS_ERROR);
+    client.assert_command_response(&request(&["GET", "key"]), NOAUTH_ERROR);
+
+    // Right password for an unknown user
+    client.assert_command_response(&request(&["AUTH", "admin", "s3cret"]), WRONGPASS_ERROR);
+    client.assert_command_response(&request(&["GET", "key"]), NOAUTH_ERROR);
+}
+
+#[test]
+fn auth_unlocks_the_connection() {
+    let mut client = start_client_with_password();
+
+    client.assert_command_response(&request(&["auth", "s3cret"]), "+OK\r\n");
+    client.assert_command_response(&request(&["SET", "key", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["GET", "key"]), "$5\r\nvalue\r\n");
+    client.assert_command_response(&request(&["PING"]), "+PONG\r\n");
+
+    // A later failed AUTH doesn't log the connection out
+    client.assert_command_response(&request(&["AUTH", "wrong"]), WRONGPASS_ERROR);
+    client.assert_command_response(&request(&["GET", "key"]), "$5\r\nvalue\r\n");
+}
+
+#[test]
+fn auth_with_default_user() {
+    let mut client = start_client_with_password();
+
+    client.assert_command_response(&request(&["AUTH", "default", "s3cret"]), "+OK\r\n");
+    client.assert_command_response(&request(&["GET", "key"]), "$-1\r\n");
+}
+
+#[test]
+fn authentication_is_per_connection() {
+    let server = common::ValkyrieServerTest::start_with_args(2, 3, &["--requirepass", "s3cret"])
+        .expect("start server");
+    let mut other = server.connect().expect("connect");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(&request(&["AUTH", "s3cret"]), "+OK\r\n");
+
+    other
+        .write_all(request(&["GET", "key"]).as_bytes())
+        .expect("send GET");
+    let mut reply = vec![0u8; NOAUTH_ERROR.len()];
+    other.read_exact(&mut reply).expect("read reply");
+    assert_eq!(reply, NOAUTH_ERROR.as_bytes());
+}
+
+#[test]
+fn auth_errors() {
+    let mut client = start_client_with_password();
+
+    client.assert_command_response(
+        &request(&["AUTH"]),
+        "-ERR wrong number of arguments for 'auth' command\r\n",
+    );
+    client.assert_command_response(
+        &request(&["AUTH", "a", "b", "c"]),
+        "-ERR wrong number of arguments for 'auth' command\r\n",
+    );
+
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    // Without --requirepass commands run unauthenticated and AUTH is an error
+    client.assert_command_response(&request(&["GET", "key"]), "$-1\r\n");
+    client.assert_command_response(
+        &request(&["AUTH", "s3cret"]),
+        "-ERR AUTH <password> called without any password configured for the default user. Are you sure your configuration is correct?\r\n",
+    );
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use std::io::{Read, Write};
+
+use crate::common::ValkyrieServerTest;
+
+/// Request with raw byte arguments, which can't go through the `&str` helpers.
+fn request(args: &[&[u8]]) -> Vec<u8> {
+    let mut request = format!("*{}\r\n", args.len()).into_bytes();
+    for arg in args {
+        request.extend_from_slice(format!("${}\r\n", arg.len()).as_bytes());
+        request.extend_from_slice(arg);
+        request.extend_from_slice(b"\r\n");
+    }
+    request
+}
+
+fn read_exact_reply(stream: &mut std::net::TcpStream, expected: &[u8]) {
+    let mut reply = vec![0u8; expected.len()];
+    stream.read_exact(&mut reply).expect("read reply");
+    assert_eq!(expected, &reply[..]);
+}
+
+#[test]
+fn set_then_get_returns_exact_bytes() {
+    let server = ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut stream = server.connect().expect("connect to server");
+
+    let value: &[u8] = &[0x00, 0xFF, 0x0D, 0x0A];
+
+    stream
+        .write_all(&request(&[b"SET", b"binary", value]))
+        .expect("send SET");
+    read_exact_reply(&mut stream, b"+OK\r\n");
+
+    stream
+        .write_all(&request(&[b"GET", b"binary"]))
+        .expect("send GET");
+    read_exact_reply(&mut stream, b"$4\r\n\x00\xff\r\n\r\n");
+}
+
+#[test]
+fn append_and_getrange_keep_exact_bytes() {
+    let server = ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut stream = server.connect().expect("connect to server");
+
+    stream
+        .write_all(&request(&[b"APPEND", b"binary", b"\xff\r\n"]))
+        .expect("send APPEND");
+    read_exact_reply(&mut stream, b":3\r\n");
+
+    stream
+        .write_all(&request(&[b"APPEND", b"binary", b"\x00\xfe"]))
+        .expect("send APPEND");
+    read_exact_reply(&mut stream, b":5\r\n");
+
+    stream
+        .write_all(&request(&[b"GETRANGE", b"binary", b"1", b"3"]))
+        .expect("send GETRANGE");
+    read_exact_reply(&mut stream, b"$3\r\n\r\n\x00\r\n");
+
+    // Same bytes back from MGET as well
+    stream
+        .write_all(&request(&[b"MGET", b"binary"]))
+        .expect("send MGET");
+    read_exact_reply(&mut stream, b"*1\r\n$5\r\n\xff\r\n\x00\xfe\r\n");
+}
@@ -9,7 +9,7 @@ use crate::common::ValkyrieClientTest;
 // - Returns Array [key, value] when an element is popped
 // - Returns Null Array (*-1) on timeout/no keys ready
 // - Error messages follow parser messages in blpop.rs
-// - Operating on a string key yields error: "'<key>' is not a list."
+// - Operating on a string key yields a WRONGTYPE error
 
 // Non-existent key with positive timeout returns Null Array
 #[test]
@@ -38,6 +38,30 @@ fn blpop_single_key_pops_head_returns_key_and_value() {
     client.assert_command_response(blpop_req, blpop_resp);
 }
 
+// Each BLPOP removes the element it returns, the emptied list is deleted
+#[test]
+fn blpop_twice_pops_different_elements() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    // RPUSH mylist a b
+    let rpush_req = "*4\r\n$5\r\nRPUSH\r\n$6\r\nmylist\r\n$1\r\na\r\n$1\r\nb\r\n";
+    client.assert_command_response(rpush_req, ":2\r\n");
+
+    // BLPOP mylist 1, twice
+    let blpop_req = "*3\r\n$5\r\nBLPOP\r\n$6\r\nmylist\r\n$1\r\n1\r\n";
+    client.assert_command_response(blpop_req, "*2\r\n$6\r\nmylist\r\n$1\r\na\r\n");
+    client.assert_command_response(blpop_req, "*2\r\n$6\r\nmylist\r\n$1\r\nb\r\n");
+
+    // EXISTS mylist -> 0
+    let exists_req = "*2\r\n$6\r\nEXISTS\r\n$6\r\nmylist\r\n";
+    client.assert_command_response(exists_req, ":0\r\n");
+
+    // Nothing left: BLPOP mylist 0.05 times out
+    let blpop_timeout_req = "*3\r\n$5\r\nBLPOP\r\n$6\r\nmylist\r\n$4\r\n0.05\r\n";
+    client.assert_command_response(blpop_timeout_req, "*-1\r\n");
+}
+
 // Multi-key: returns first non-empty list name and value
 #[test]
 fn blpop_multiple_keys_returns_first_non_empty() {
@@ -163,8 +187,7 @@ fn blpop_timeout_invalid_nonnumeric() {
 
     // BLPOP mykey abc
     let req = "*3\r\n$5\r\nBLPOP\r\n$5\r\nmykey\r\n$3\r\nabc\r\n";
-    // Note: message comes from convert_float_str_seconds_to_ms() with a minor typo ('numbe')
-    let expected = "-BLPOP 'timeout' must be a finite, non-negative numbe\r\n";
+    let expected = "-BLPOP 'timeout' must be a finite, non-negative number\r\n";
     client.assert_command_response(req, expected);
 }
 
@@ -200,5 +223,40 @@ fn blpop_on_string_key_fails() {
 
     // BLPOP skey 1 -> error
     let blpop_req = "*3\r\n$5\r\nBLPOP\r\n$4\r\nskey\r\n$1\r\n1\r\n";
-    client.assert_command_response(blpop_req, "-'skey' is not a list.\r\n");
+    client.assert_command_response(
+        blpop_req,
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+}
+
+// A single shard behind a single TCP handler, as on a one-core machine: the blocked BLPOP must
+// neither hold the handler thread nor the shard, so other connections are still served
+#[test]
+fn blpop_single_shard_single_handler_unblocked_by_other_connection() {
+    use std::io::{BufRead, BufReader, Write};
+    use std::time::Duration;
+
+    let server = common::ValkyrieServerTest::start(1, 1).expect("start server");
+
+    // BLPOP mylist 0 (block indefinitely)
+    let mut c1 = server.connect().expect("c1 connect");
+    c1.set_read_timeout(Some(Duration::from_secs(2))).unwrap();
+    let mut c1_reader = BufReader::new(c1.try_clone().expect("clone c1 for reader"));
+    c1.write_all(b"*3\r\n$5\r\nBLPOP\r\n$6\r\nmylist\r\n$1\r\n0\r\n")
+        .expect("write blpop");
+
+    let mut c2 = ValkyrieClientTest::new(server);
+
+    // The same handler and shard keep serving other commands while BLPOP waits
+    c2.assert_command_response("*1\r\n$4\r\nPING\r\n", "+PONG\r\n");
+    c2.assert_command_response("*3\r\n$3\r\nSET\r\n$1\r\nk\r\n$1\r\nv\r\n", "+OK\r\n");
+
+    // RPUSH mylist v
+    c2.assert_command_response("*3\r\n$5\r\nRPUSH\r\n$6\r\nmylist\r\n$1\r\nv\r\n", ":1\r\n");
+
+    let mut reply = String::new();
+    for _ in 0..5 {
+        c1_reader.read_line(&mut reply).expect("read blpop reply");
+    }
+    assert_eq!(reply, "*2\r\n$6\r\nmylist\r\n$1\r\nv\r\n");
 }
@@ -0,0 +1,186 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request, start_client};
+use std::io::{BufRead, BufReader, Write};
+use std::thread;
+use std::time::Duration;
+
+// https://redis.io/docs/latest/commands/brpop/
+// BRPOP key [key ...] timeout
+
+#[test]
+fn brpop_nonexistent_key_times_out_returns_null_array() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["BRPOP", "mylist", "0.05"]), "*-1\r\n");
+}
+
+#[test]
+fn brpop_pops_tail_and_removes_emptied_list() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["RPUSH", "mylist", "a", "b"]), ":2\r\n");
+    client.assert_command_response(
+        &request(&["BRPOP", "mylist", "1"]),
+        "*2\r\n$6\r\nmylist\r\n$1\r\nb\r\n",
+    );
+    client.assert_command_response(
+        &request(&["BRPOP", "mylist", "1"]),
+        "*2\r\n$6\r\nmylist\r\n$1\r\na\r\n",
+    );
+    client.assert_command_response(&request(&["EXISTS", "mylist"]), ":0\r\n");
+}
+
+#[test]
+fn brpop_multiple_keys_returns_first_non_empty() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["RPUSH", "list2", "x", "y"]), ":2\r\n");
+    client.assert_command_response(
+        &request(&["BRPOP", "list1", "list2", "1"]),
+        "*2\r\n$5\r\nlist2\r\n$1\r\ny\r\n",
+    );
+}
+
+#[test]
+fn brpop_block_then_unblock_with_push_from_other_client() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+
+    // First connection blocks indefinitely
+    let mut blocked = server.connect().expect("connect blocked client");
+    let mut blocked_reader = BufReader::new(blocked.try_clone().expect("clone blocked client"));
+    blocked
+        .write_all(request(&["BRPOP", "mylist", "0"]).as_bytes())
+        .expect("send BRPOP");
+
+    // Give BRPOP time to register as a waiter
+    thread::sleep(Duration::from_millis(100));
+
+    let mut pusher = ValkyrieClientTest::new(server);
+    pusher.assert_command_response(&request(&["LPUSH", "mylist", "v1", "v2"]), ":2\r\n");
+
+    let mut reply = String::new();
+    for _ in 0..5 {
+        blocked_reader
+            .read_line(&mut reply)
+            .expect("read BRPOP reply");
+    }
+    assert_eq!(reply, "*2\r\n$6\r\nmylist\r\n$2\r\nv1\r\n");
+
+    // Only the tail was popped
+    pusher.assert_command_response(
+        &request(&["LRANGE", "mylist", "0", "-1"]),
+        "*1\r\n$2\r\nv2\r\n",
+    );
+}
+
+#[test]
+fn brpop_invalid_arguments() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["BRPOP", "mylist"]),
+        "-Incomplete BRPOP command, expected at least 3 values: 'BRPOP key timeout'\r\n",
+    );
+    client.assert_command_response(
+        &request(&["BRPOP", "mylist", "-1"]),
+        "-BRPOP 'timeout' must be a finite, non-negative number\r\n",
+    );
+}
+
+#[test]
+fn brpop_on_string_key_fails() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "skey", "sval"]), "+OK\r\n");
+    client.assert_command_response(
+        &request(&["BRPOP", "skey", "1"]),
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+}
+
+/// Two keys owned by different shards when the server runs more than one.
+fn keys_on_two_shards() -> (String, String) {
+    let shards = common::effective_shards(3);
+    let first = "first".to_string();
+    let second = (0..)
+        .map(|i| format!("second:{i}"))
+        .find(|key| {
+            shards == 1
+                || common::shard_for_key(key, shards) != common::shard_for_key(&first, shards)
+        })
+        .expect("key on another shard");
+    (first, second)
+}
+
+fn llen(client: &mut ValkyrieClientTest, key: &str) -> usize {
+    client
+        .send(request(&["LLEN", key]).as_bytes())
+        .expect("send LLEN");
+    let line = client.read_line().expect("read LLEN reply");
+    line.trim_start_matches(':')
+        .trim()
+        .parse()
+        .expect("parse LLEN reply")
+}
+
+// Lists with elements are served in argument order, the other ones keep their elements
+#[test]
+fn brpop_serves_keys_in_argument_order() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+    let (first, second) = keys_on_two_shards();
+
+    client.assert_command_response(&request(&["RPUSH", &first, "a1", "a2"]), ":2\r\n");
+    client.assert_command_response(&request(&["RPUSH", &second, "b1", "b2"]), ":2\r\n");
+
+    for expected in ["a2", "a1"] {
+        client.assert_command_response(
+            &request(&["BRPOP", &first, &second, "1"]),
+            &format!("*2\r\n${}\r\n{first}\r\n$2\r\n{expected}\r\n", first.len()),
+        );
+        assert_eq!(llen(&mut client, &second), 2);
+    }
+
+    client.assert_command_response(
+        &request(&["BRPOP", &first, &second, "1"]),
+        &format!("*2\r\n${}\r\n{second}\r\n$2\r\nb2\r\n", second.len()),
+    );
+    assert_eq!(llen(&mut client, &second), 1);
+}
+
+// Elements pushed to several of the waited keys at once: only the one replied is popped
+#[test]
+fn brpop_waiting_on_several_keys_pops_a_single_element() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let (first, second) = keys_on_two_shards();
+
+    let mut blocked = server.connect().expect("connect blocked client");
+    let mut blocked_reader = BufReader::new(blocked.try_clone().expect("clone blocked client"));
+    blocked
+        .write_all(request(&["BRPOP", &first, &second, "0"]).as_bytes())
+        .expect("send BRPOP");
+    thread::sleep(Duration::from_millis(100));
+
+    let mut pusher = ValkyrieClientTest::new(server);
+    let pushes = request(&["RPUSH", &first, "a"]) + &request(&["RPUSH", &second, "b"]);
+    pusher.send(pushes.as_bytes()).expect("send pushes");
+    assert_eq!(pusher.read_line().expect("first push reply"), ":1\r\n");
+    assert_eq!(pusher.read_line().expect("second push reply"), ":1\r\n");
+
+    let mut reply = String::new();
+    for _ in 0..5 {
+        blocked_reader
+            .read_line(&mut reply)
+            .expect("read BRPOP reply");
+    }
+    let popped_first = reply.contains(&format!("\r\n{first}\r\n"));
+    assert!(
+        popped_first || reply.contains(&format!("\r\n{second}\r\n")),
+        "{reply:?}"
+    );
+
+    // The other list still holds its element
+    assert_eq!(llen(&mut pusher, &first), usize::from(!popped_first));
+    assert_eq!(llen(&mut pusher, &second), usize::from(popped_first));
+}
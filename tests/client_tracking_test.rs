@@ -0,0 +1,285 @@
+mod common;
+
+use crate::common::request;
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+
+// https://redis.io/docs/latest/commands/client-tracking/
+// https://redis.io/docs/latest/develop/reference/client-side-caching/
+
+struct Connection {
+    stream: TcpStream,
+    reader: BufReader<TcpStream>,
+}
+
+impl Connection {
+    fn open(server: &common::ValkyrieServerTest) -> Self {
+        let stream = server.connect().expect("connect");
+        let reader = BufReader::new(stream.try_clone().expect("clone stream"));
+        Self { stream, reader }
+    }
+
+    /// Sends a command and returns its whole reply, as sent on the wire.
+    fn command(&mut self, args: &[&str]) -> String {
+        self.stream
+            .write_all(request(args).as_bytes())
+            .expect("send command");
+        self.read_reply()
+    }
+
+    /// Reads one complete reply (or push message), nested values included.
+    fn read_reply(&mut self) -> String {
+        let mut line = String::new();
+        self.reader.read_line(&mut line).expect("read reply line");
+
+        let len = line[1..].trim_end().parse::<i64>().unwrap_or(-1);
+        match line.as_bytes()[0] {
+            b'$' if len >= 0 => {
+                let mut payload = vec![0u8; len as usize + 2];
+                self.reader.read_exact(&mut payload).expect("read payload");
+                line.push_str(&String::from_utf8(payload).expect("utf8 payload"));
+            }
+            b'*' | b'>' => {
+                for _ in 0..len.max(0) {
+                    line.push_str(&self.read_reply());
+                }
+            }
+            b'%' => {
+                for _ in 0..len * 2 {
+                    line.push_str(&self.read_reply());
+                }
+            }
+            _ => {}
+        }
+        line
+    }
+
+    fn hello3(&mut self) {
+        assert!(self.command(&["HELLO", "3"]).starts_with("%7\r\n"));
+    }
+
+    fn client_id(&mut self) -> String {
+        let reply = self.command(&["CLIENT", "ID"]);
+        reply
+            .strip_prefix(':')
+            .expect("integer reply")
+            .trim_end()
+            .to_string()
+    }
+
+    /// Nothing was pushed before the reply to this PING.
+    fn assert_no_pending_message(&mut self) {
+        assert_eq!(self.command(&["PING"]), "+PONG\r\n");
+    }
+}
+
+fn invalidate_push(keys: &[&str]) -> String {
+    let mut push = format!(">2\r\n$10\r\ninvalidate\r\n*{}\r\n", keys.len());
+    for key in keys {
+        push.push_str(&format!("${}\r\n{}\r\n", key.len(), key));
+    }
+    push
+}
+
+// Enable tracking, GET a key, modify it from another connection: the reader gets an invalidation push
+#[test]
+fn modified_key_is_invalidated() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut tracking = Connection::open(&server);
+    let mut writer = Connection::open(&server);
+
+    tracking.hello3();
+    assert_eq!(tracking.command(&["CLIENT", "TRACKING", "ON"]), "+OK\r\n");
+    assert_eq!(writer.command(&["SET", "key", "v1"]), "+OK\r\n");
+    assert_eq!(tracking.command(&["GET", "key"]), "$2\r\nv1\r\n");
+
+    assert_eq!(writer.command(&["SET", "key", "v2"]), "+OK\r\n");
+    assert_eq!(tracking.read_reply(), invalidate_push(&["key"]));
+
+    // Invalidations are sent once, until the key is read again
+    assert_eq!(writer.command(&["SET", "key", "v3"]), "+OK\r\n");
+    tracking.assert_no_pending_message();
+
+    assert_eq!(tracking.command(&["GET", "key"]), "$2\r\nv3\r\n");
+    assert_eq!(writer.command(&["DEL", "key"]), ":1\r\n");
+    assert_eq!(tracking.read_reply(), invalidate_push(&["key"]));
+}
+
+#[test]
+fn keys_not_read_are_not_invalidated() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut tracking = Connection::open(&server);
+    let mut writer = Connection::open(&server);
+
+    tracking.hello3();
+    assert_eq!(tracking.command(&["CLIENT", "TRACKING", "ON"]), "+OK\r\n");
+    assert_eq!(tracking.command(&["GET", "read"]), "_\r\n");
+
+    assert_eq!(writer.command(&["SET", "unread", "v"]), "+OK\r\n");
+    tracking.assert_no_pending_message();
+
+    // Multi-key writes invalidate each of their keys
+    assert_eq!(
+        writer.command(&["MSET", "unread", "v", "read", "v"]),
+        "+OK\r\n"
+    );
+    assert_eq!(tracking.read_reply(), invalidate_push(&["read"]));
+}
+
+#[test]
+fn tracking_off_stops_invalidations() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut tracking = Connection::open(&server);
+    let mut writer = Connection::open(&server);
+
+    tracking.hello3();
+    assert_eq!(tracking.command(&["CLIENT", "TRACKING", "ON"]), "+OK\r\n");
+    assert_eq!(tracking.command(&["GET", "key"]), "_\r\n");
+    assert_eq!(tracking.command(&["CLIENT", "TRACKING", "OFF"]), "+OK\r\n");
+
+    assert_eq!(writer.command(&["SET", "key", "v"]), "+OK\r\n");
+    tracking.assert_no_pending_message();
+}
+
+#[test]
+fn bcast_invalidates_keys_matching_prefixes() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut tracking = Connection::open(&server);
+    let mut writer = Connection::open(&server);
+
+    tracking.hello3();
+    assert_eq!(
+        tracking.command(&["CLIENT", "TRACKING", "ON", "BCAST", "PREFIX", "user:"]),
+        "+OK\r\n"
+    );
+
+    // Keys don't need to be read first in BCAST mode
+    assert_eq!(writer.command(&["SET", "user:1", "v"]), "+OK\r\n");
+    assert_eq!(tracking.read_reply(), invalidate_push(&["user:1"]));
+
+    assert_eq!(writer.command(&["SET", "session:1", "v"]), "+OK\r\n");
+    tracking.assert_no_pending_message();
+
+    // And they keep being invalidated
+    assert_eq!(writer.command(&["RPUSH", "user:1:list", "v"]), ":1\r\n");
+    assert_eq!(tracking.read_reply(), invalidate_push(&["user:1:list"]));
+}
+
+// Keys removed by the shards on their own are invalidated as well
+#[test]
+fn expired_key_is_invalidated() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut tracking = Connection::open(&server);
+    let mut writer = Connection::open(&server);
+
+    tracking.hello3();
+    assert_eq!(tracking.command(&["CLIENT", "TRACKING", "ON"]), "+OK\r\n");
+    assert_eq!(writer.command(&["SET", "key", "v", "PX", "200"]), "+OK\r\n");
+    assert_eq!(tracking.command(&["GET", "key"]), "$1\r\nv\r\n");
+
+    // Pushed by the expiration, without any other command
+    assert_eq!(tracking.read_reply(), invalidate_push(&["key"]));
+    assert_eq!(tracking.command(&["GET", "key"]), "_\r\n");
+}
+
+#[test]
+fn flushed_key_is_invalidated() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut tracking = Connection::open(&server);
+    let mut writer = Connection::open(&server);
+
+    tracking.hello3();
+    assert_eq!(tracking.command(&["CLIENT", "TRACKING", "ON"]), "+OK\r\n");
+    assert_eq!(writer.command(&["SET", "key", "v"]), "+OK\r\n");
+    assert_eq!(tracking.command(&["GET", "key"]), "$1\r\nv\r\n");
+
+    assert_eq!(writer.command(&["DEBUG", "FLUSHALL"]), "+OK\r\n");
+    assert_eq!(tracking.read_reply(), invalidate_push(&["key"]));
+}
+
+// Only the key BLPOP popped from is invalidated, not every key it waited on
+#[test]
+fn blocking_pop_invalidates_popped_key() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut tracking = Connection::open(&server);
+    let mut writer = Connection::open(&server);
+
+    assert_eq!(writer.command(&["RPUSH", "full", "a", "b"]), ":2\r\n");
+    tracking.hello3();
+    assert_eq!(tracking.command(&["CLIENT", "TRACKING", "ON"]), "+OK\r\n");
+    assert_eq!(tracking.command(&["LLEN", "empty"]), ":0\r\n");
+    assert_eq!(tracking.command(&["LLEN", "full"]), ":2\r\n");
+
+    assert_eq!(
+        writer.command(&["BLPOP", "empty", "full", "1"]),
+        "*2\r\n$4\r\nfull\r\n$1\r\na\r\n"
+    );
+    assert_eq!(tracking.read_reply(), invalidate_push(&["full"]));
+    tracking.assert_no_pending_message();
+}
+
+// With REDIRECT, invalidations are sent to another connection as Pub/Sub messages, RESP2 is fine then
+#[test]
+fn redirect_sends_invalidations_to_other_connection() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut target = Connection::open(&server);
+    let mut tracking = Connection::open(&server);
+    let mut writer = Connection::open(&server);
+
+    let target_id = target.client_id();
+    assert_eq!(
+        tracking.command(&["CLIENT", "TRACKING", "ON", "REDIRECT", &target_id]),
+        "+OK\r\n"
+    );
+    assert_eq!(tracking.command(&["GET", "key"]), "$-1\r\n");
+
+    assert_eq!(writer.command(&["SET", "key", "v"]), "+OK\r\n");
+    assert_eq!(
+        target.read_reply(),
+        "*3\r\n$7\r\nmessage\r\n$20\r\n__redis__:invalidate\r\n*1\r\n$3\r\nkey\r\n"
+    );
+    tracking.assert_no_pending_message();
+}
+
+#[test]
+fn client_tracking_errors() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = Connection::open(&server);
+
+    // RESP2 connections can't receive pushes
+    assert_eq!(
+        client.command(&["CLIENT", "TRACKING", "ON"]),
+        "-ERR Client tracking without REDIRECT requires RESP3, switch the protocol with HELLO 3\r\n"
+    );
+
+    client.hello3();
+    assert_eq!(
+        client.command(&["CLIENT", "TRACKING", "ON", "REDIRECT", "999999"]),
+        "-ERR The client ID you want redirect to does not exist\r\n"
+    );
+    assert_eq!(
+        client.command(&["CLIENT", "TRACKING", "ON", "REDIRECT", "abc"]),
+        "-ERR Invalid client ID\r\n"
+    );
+    assert_eq!(
+        client.command(&["CLIENT", "TRACKING", "ON", "PREFIX", "user:"]),
+        "-ERR PREFIX option requires BCAST mode to be enabled\r\n"
+    );
+    assert_eq!(
+        client.command(&["CLIENT", "TRACKING", "ON", "OPTIN"]),
+        "-ERR syntax error\r\n"
+    );
+    assert_eq!(
+        client.command(&["CLIENT", "TRACKING", "MAYBE"]),
+        "-ERR syntax error\r\n"
+    );
+    assert_eq!(
+        client.command(&["CLIENT", "NOSUCH"]),
+        "-ERR unknown subcommand or wrong number of arguments for 'NOSUCH'\r\n"
+    );
+    assert_eq!(
+        client.command(&["CLIENT"]),
+        "-ERR wrong number of arguments for 'client' command\r\n"
+    );
+}
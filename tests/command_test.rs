@@ -0,0 +1,156 @@
+mod common;
+
+use std::io::{BufRead, BufReader, Write};
+
+use crate::common::{ValkyrieClientTest, request, start_client};
+
+// https://redis.io/docs/latest/commands/command-info/
+// https://redis.io/docs/latest/commands/command-docs/
+// https://redis.io/docs/latest/commands/command-list/
+// https://redis.io/docs/latest/commands/command-count/
+
+const GET_INFO: &str = "*10\r\n$3\r\nget\r\n:2\r\n*2\r\n+readonly\r\n+fast\r\n:1\r\n:1\r\n:1\r\n*0\r\n*0\r\n*0\r\n*0\r\n";
+const SET_INFO: &str = "*10\r\n$3\r\nset\r\n:-3\r\n*2\r\n+write\r\n+denyoom\r\n:1\r\n:1\r\n:1\r\n*0\r\n*0\r\n*0\r\n*0\r\n";
+
+#[test]
+fn command_info_for_specific_commands() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["COMMAND", "INFO", "GET", "SET"]),
+        &format!("*2\r\n{GET_INFO}{SET_INFO}"),
+    );
+}
+
+#[test]
+fn command_info_unknown_command_is_null() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["COMMAND", "INFO", "get", "nosuchcommand"]),
+        &format!("*2\r\n{GET_INFO}*-1\r\n"),
+    );
+}
+
+#[test]
+fn command_docs_for_specific_commands() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["COMMAND", "DOCS", "get", "nosuchcommand"]),
+        concat!(
+            "*2\r\n$3\r\nget\r\n*6\r\n",
+            "$7\r\nsummary\r\n$34\r\nReturns the string value of a key.\r\n$5\r\ngroup\r\n$6\r\nstring\r\n",
+            // From the argument spec
+            "$9\r\narguments\r\n*1\r\n*6\r\n$4\r\nname\r\n$3\r\nkey\r\n$4\r\ntype\r\n$3\r\nkey\r\n$5\r\nflags\r\n*0\r\n",
+        ),
+    );
+
+    client.assert_command_response(&request(&["COMMAND", "DOCS", "nosuchcommand"]), "*0\r\n");
+}
+
+#[test]
+fn command_unknown_subcommand_is_rejected() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["COMMAND", "NOPE"]),
+        "-ERR unknown subcommand 'NOPE'\r\n",
+    );
+}
+
+/// Reads an array of bulk strings, only the header is expected as a line
+fn read_bulk_string_array(client: &mut ValkyrieClientTest) -> Vec<String> {
+    let header = client.read_line().expect("read array header");
+    assert!(header.starts_with('*'), "Expected array, got: {header:?}");
+    let len: usize = header[1..].trim().parse().expect("parse array length");
+
+    (0..len)
+        .map(|_| client.read_bulk_or_null().expect("command name"))
+        .collect()
+}
+
+fn read_integer(client: &mut ValkyrieClientTest) -> usize {
+    let line = client.read_line().expect("read integer");
+    assert!(line.starts_with(':'), "Expected integer, got: {line:?}");
+    line[1..].trim().parse().expect("parse integer")
+}
+
+#[test]
+fn command_list_and_count() {
+    let mut client = start_client();
+
+    client
+        .send(request(&["COMMAND", "LIST"]).as_bytes())
+        .expect("send COMMAND LIST");
+    let names = read_bulk_string_array(&mut client);
+    for name in ["ping", "set", "get", "rpush"] {
+        assert!(
+            names.iter().any(|n| n == name),
+            "{name} missing from {names:?}"
+        );
+    }
+
+    client
+        .send(request(&["COMMAND", "COUNT"]).as_bytes())
+        .expect("send COMMAND COUNT");
+    assert_eq!(read_integer(&mut client), names.len());
+
+    // Same registry as COMMAND INFO
+    client
+        .send(request(&["COMMAND", "INFO"]).as_bytes())
+        .expect("send COMMAND INFO");
+    let header = client.read_line().expect("read COMMAND INFO header");
+    assert_eq!(header, format!("*{}\r\n", names.len()));
+}
+
+#[test]
+fn command_list_rejects_arguments() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["COMMAND", "COUNT", "extra"]),
+        "-ERR unknown subcommand or wrong number of arguments for 'COUNT'\r\n",
+    );
+}
+
+// COMMAND LIST/COUNT come from the command table: each listed command must reach its implementation.
+// The reverse (every dispatched command is listed) is checked by the unit tests in src/command.rs.
+#[test]
+fn every_listed_command_is_dispatched() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+
+    let mut stream = server.connect().expect("connect");
+    let mut reader = BufReader::new(stream.try_clone().expect("clone stream"));
+    stream
+        .write_all(request(&["COMMAND", "LIST"]).as_bytes())
+        .expect("send COMMAND LIST");
+    let mut names = Vec::new();
+    let mut header = String::new();
+    reader.read_line(&mut header).expect("read array header");
+    let len: usize = header[1..].trim().parse().expect("parse array length");
+    for _ in 0..len {
+        let mut line = String::new();
+        reader.read_line(&mut line).expect("read bulk header");
+        line.clear();
+        reader.read_line(&mut line).expect("read command name");
+        names.push(line.trim_end().to_string());
+    }
+
+    // Without arguments every command replies right away (most with an arity error), never as unknown.
+    // A connection per command, as only the first line of each reply is read.
+    for name in names {
+        let mut stream = server.connect().expect("connect");
+        let mut reader = BufReader::new(stream.try_clone().expect("clone stream"));
+        stream
+            .write_all(request(&[&name]).as_bytes())
+            .expect("send command");
+
+        let mut reply = String::new();
+        reader.read_line(&mut reply).expect("read reply");
+        assert!(
+            !reply.is_empty() && !reply.starts_with("-Command type is not defined or unknown"),
+            "{name} is listed by COMMAND LIST but not dispatched: {reply:?}"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 #![allow(dead_code)]
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::io::{BufRead, BufReader, Read, Write};
 use std::net::{TcpListener, TcpStream};
 use std::process::{Child, Command as StdCommand, Stdio};
@@ -16,6 +17,15 @@ pub struct ValkyrieServerTest {
 impl ValkyrieServerTest {
     /// Start the server on an ephemeral localhost port with given handler/shard counts.
     pub fn start(tcp_handlers: usize, shards: usize) -> anyhow::Result<Self> {
+        Self::start_with_args(tcp_handlers, shards, &[])
+    }
+
+    /// Same as `start`, passing additional CLI flags to the server binary.
+    pub fn start_with_args(
+        tcp_handlers: usize,
+        shards: usize,
+        extra_args: &[&str],
+    ) -> anyhow::Result<Self> {
         // Choose a free local port to avoid conflicts across tests/machines.
         let port = {
             let l = TcpListener::bind("127.0.0.1:0")?;
@@ -34,6 +44,7 @@ impl ValkyrieServerTest {
             .arg(tcp_handlers.to_string())
             .arg("--shards")
             .arg(shards.to_string())
+            .args(extra_args)
             .stdout(Stdio::null())
             .stderr(Stdio::null())
             .spawn()?;
@@ -74,6 +85,21 @@ impl Drop for ValkyrieServerTest {
     }
 }
 
+/// Starts a server with 2 TCP handlers and 3 shards, and connects a client to it.
+pub fn start_client() -> ValkyrieClientTest {
+    let server = ValkyrieServerTest::start(2, 3).expect("start server");
+    ValkyrieClientTest::new(server)
+}
+
+/// Encodes a command as a RESP array of bulk strings, as clients send it.
+pub fn request(args: &[&str]) -> String {
+    let mut req = format!("*{}\r\n", args.len());
+    for arg in args {
+        req.push_str(&format!("${}\r\n{}\r\n", arg.len(), arg));
+    }
+    req
+}
+
 /// Test client helper that keeps the server process alive and provides simple RESP helpers.
 pub struct ValkyrieClientTest {
     // Keep the server alive for the lifetime of the client to avoid dropping the child process.
@@ -122,7 +148,7 @@ impl ValkyrieClientTest {
     }
 
     /// Read a single line (terminated by CRLF) and return it.
-    fn read_line(&mut self) -> std::io::Result<String> {
+    pub fn read_line(&mut self) -> std::io::Result<String> {
         let mut line = String::new();
         self.reader.read_line(&mut line)?;
         Ok(line)
@@ -172,3 +198,18 @@ impl ValkyrieClientTest {
         Some(String::from_utf8(payload).expect("payload utf8"))
     }
 }
+
+/// Mirrors `StorageEngine::shard_index_for_key`, so tests can pick keys landing on specific shards.
+pub fn shard_for_key(key: &str, shards: usize) -> usize {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish() as usize % shards
+}
+
+/// Shard count the server actually runs with: the requested value clamped to half of the CPUs (at least 1).
+pub fn effective_shards(requested: usize) -> usize {
+    let available = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+    requested.min(std::cmp::max(1, available / 2))
+}
@@ -0,0 +1,27 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request};
+
+fn assert_serves_commands(server: common::ValkyrieServerTest) {
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(&request(&["PING"]), "+PONG\r\n");
+    client.assert_command_response(&request(&["SET", "key", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["GET", "key"]), "$5\r\nvalue\r\n");
+}
+
+#[test]
+fn no_cpu_affinity_serves_commands() {
+    assert_serves_commands(
+        common::ValkyrieServerTest::start_with_args(2, 3, &["--no-cpu-affinity"])
+            .expect("start server"),
+    );
+}
+
+#[test]
+fn cpu_affinity_offset_serves_commands() {
+    assert_serves_commands(
+        common::ValkyrieServerTest::start_with_args(2, 3, &["--cpu-affinity-offset", "1"])
+            .expect("start server"),
+    );
+}
@@ -0,0 +1,181 @@
+mod common;
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::thread;
+use std::time::Duration;
+
+use crate::common::{ValkyrieClientTest, request};
+
+// Multi-key commands spanning several shards are not atomic (see `group_keys_by_shard` in src/command.rs).
+// With more than one shard, the shard of one key is stalled with DEBUG SHARD-BLOCK so the command can be
+// caught half applied; with a single shard only the final state is checked.
+
+/// Picks a key starting with `prefix` owned by `shard`.
+fn key_on_shard(prefix: &str, shard: usize, shards: usize) -> String {
+    (0..)
+        .map(|i| format!("{prefix}:{i}"))
+        .find(|key| common::shard_for_key(key, shards) == shard)
+        .expect("key on shard")
+}
+
+/// Raw connection whose replies are read separately from sending, to leave commands in flight.
+struct RawConnection {
+    stream: TcpStream,
+    reader: BufReader<TcpStream>,
+}
+
+impl RawConnection {
+    fn open(server: &common::ValkyrieServerTest) -> Self {
+        let stream = server.connect().expect("connect");
+        let reader = BufReader::new(stream.try_clone().expect("clone stream"));
+        Self { stream, reader }
+    }
+
+    fn send(&mut self, args: &[&str]) {
+        self.stream
+            .write_all(request(args).as_bytes())
+            .expect("send request");
+    }
+
+    fn read_line(&mut self) -> String {
+        let mut line = String::new();
+        self.reader.read_line(&mut line).expect("read reply");
+        line
+    }
+}
+
+struct CrossShardSetup {
+    shards: usize,
+    /// Key on the first shard, never blocked
+    free_key: String,
+    /// Key on the last shard, blocked while the multi-key command runs
+    blocked_key: String,
+    blocker: RawConnection,
+    writer: RawConnection,
+    client: ValkyrieClientTest,
+}
+
+impl CrossShardSetup {
+    fn start() -> Self {
+        let shards = common::effective_shards(3);
+        let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+        let blocker = RawConnection::open(&server);
+        let writer = RawConnection::open(&server);
+
+        Self {
+            shards,
+            free_key: key_on_shard("free", 0, shards),
+            blocked_key: key_on_shard("blocked", shards - 1, shards),
+            blocker,
+            writer,
+            client: ValkyrieClientTest::new(server),
+        }
+    }
+
+    fn is_cross_shard(&self) -> bool {
+        self.shards > 1
+    }
+
+    /// Sends `args` on the writer connection while the shard of `blocked_key` is stalled,
+    /// then runs `observe` before that shard is released (only when keys span several shards).
+    fn run_while_blocked(&mut self, args: &[&str], observe: impl FnOnce(&mut ValkyrieClientTest)) {
+        if self.is_cross_shard() {
+            self.blocker
+                .send(&["DEBUG", "SHARD-BLOCK", &self.blocked_key, "500"]);
+            thread::sleep(Duration::from_millis(50));
+            self.writer.send(args);
+            thread::sleep(Duration::from_millis(50));
+            observe(&mut self.client);
+            assert_eq!(self.blocker.read_line(), "+OK\r\n");
+        } else {
+            self.writer.send(args);
+        }
+    }
+}
+
+#[test]
+fn mset_across_shards_is_visible_half_applied() {
+    let mut setup = CrossShardSetup::start();
+    let (free_key, blocked_key) = (setup.free_key.clone(), setup.blocked_key.clone());
+
+    setup.run_while_blocked(&["MSET", &free_key, "new", &blocked_key, "new"], |client| {
+        // The free shard already applied its part of the MSET
+        client.assert_command_response(&request(&["GET", &free_key]), "$3\r\nnew\r\n");
+    });
+
+    assert_eq!(setup.writer.read_line(), "+OK\r\n");
+    setup
+        .client
+        .assert_command_response(&request(&["GET", &free_key]), "$3\r\nnew\r\n");
+    setup
+        .client
+        .assert_command_response(&request(&["GET", &blocked_key]), "$3\r\nnew\r\n");
+}
+
+#[test]
+fn del_across_shards_removes_keys_shard_by_shard() {
+    let mut setup = CrossShardSetup::start();
+    let (free_key, blocked_key) = (setup.free_key.clone(), setup.blocked_key.clone());
+    setup.client.assert_command_response(
+        &request(&["MSET", &free_key, "v", &blocked_key, "v"]),
+        "+OK\r\n",
+    );
+
+    setup.run_while_blocked(&["DEL", &free_key, &blocked_key], |client| {
+        // Removed from the free shard while the blocked one still holds its key
+        client.assert_command_response(&request(&["EXISTS", &free_key]), ":0\r\n");
+    });
+
+    assert_eq!(setup.writer.read_line(), ":2\r\n");
+    setup
+        .client
+        .assert_command_response(&request(&["EXISTS", &free_key, &blocked_key]), ":0\r\n");
+}
+
+#[test]
+fn lmove_across_shards_leaves_element_in_neither_list_in_between() {
+    let mut setup = CrossShardSetup::start();
+    let (source, destination) = (setup.free_key.clone(), setup.blocked_key.clone());
+    setup
+        .client
+        .assert_command_response(&request(&["RPUSH", &source, "a"]), ":1\r\n");
+
+    setup.run_while_blocked(
+        &["LMOVE", &source, &destination, "LEFT", "RIGHT"],
+        |client| {
+            // Popped from source, not yet pushed to destination
+            client.assert_command_response(&request(&["LLEN", &source]), ":0\r\n");
+        },
+    );
+
+    assert_eq!(setup.writer.read_line(), "$1\r\n");
+    assert_eq!(setup.writer.read_line(), "a\r\n");
+    setup
+        .client
+        .assert_command_response(&request(&["LLEN", &source]), ":0\r\n");
+    setup.client.assert_command_response(
+        &request(&["LRANGE", &destination, "0", "-1"]),
+        "*1\r\n$1\r\na\r\n",
+    );
+}
+
+#[test]
+fn multi_key_commands_document_cross_shard_behavior() {
+    // Enforces the convention from src/command.rs: every command spreading its keys over shards
+    // tells in its doc comment that it is not atomic across them.
+    let command_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/src/command");
+    for file in ["mget", "mset", "del", "exists", "lmove", "rename", "sinter"] {
+        let source = std::fs::read_to_string(format!("{command_dir}/{file}.rs"))
+            .unwrap_or_else(|e| panic!("read {file}.rs: {e}"));
+        let docs = source
+            .lines()
+            .filter(|line| line.trim_start().starts_with("///"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        assert!(
+            docs.contains("atomic"),
+            "{file}.rs does not document its cross-shard atomicity"
+        );
+    }
+}
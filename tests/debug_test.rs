@@ -0,0 +1,108 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request};
+
+// DEBUG FLUSHALL
+
+#[test]
+fn debug_flushall_empties_every_shard() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    // Enough keys to land on every shard
+    for i in 0..32 {
+        let key = format!("key:{i}");
+        client.assert_command_response(&request(&["SET", &key, "value"]), "+OK\r\n");
+    }
+    client.assert_command_response(&request(&["RPUSH", "list", "a", "b"]), ":2\r\n");
+    client.assert_command_response(
+        &request(&["SET", "expiring", "value", "PX", "200"]),
+        "+OK\r\n",
+    );
+
+    client.assert_command_response(&request(&["DEBUG", "FLUSHALL"]), "+OK\r\n");
+
+    for i in 0..32 {
+        let key = format!("key:{i}");
+        client.assert_command_response(&request(&["GET", &key]), "$-1\r\n");
+    }
+    client.assert_command_response(&request(&["LLEN", "list"]), ":0\r\n");
+    client.assert_command_response(&request(&["GET", "expiring"]), "$-1\r\n");
+
+    // Keyspace is usable again after the flush
+    client.assert_command_response(&request(&["SET", "key:0", "new"]), "+OK\r\n");
+    client.assert_command_response(&request(&["GET", "key:0"]), "$3\r\nnew\r\n");
+}
+
+#[test]
+fn debug_unknown_subcommand_is_rejected() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(
+        &request(&["DEBUG", "NOPE"]),
+        "-ERR unknown subcommand 'NOPE'\r\n",
+    );
+}
+
+#[test]
+fn debug_shard_block_only_stalls_the_owning_shard() {
+    use std::io::{BufRead, BufReader, Write};
+    use std::time::{Duration, Instant};
+
+    let shards = common::effective_shards(3);
+    let blocked_key = "blocked";
+    let blocked_shard = common::shard_for_key(blocked_key, shards);
+
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut blocker = server.connect().expect("blocker connect");
+    let mut blocker_reader = BufReader::new(blocker.try_clone().expect("clone blocker"));
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(&request(&["SET", blocked_key, "v"]), "+OK\r\n");
+
+    blocker
+        .write_all(request(&["DEBUG", "SHARD-BLOCK", blocked_key, "600"]).as_bytes())
+        .expect("send DEBUG SHARD-BLOCK");
+    // Let the block reach its shard before probing
+    std::thread::sleep(Duration::from_millis(50));
+
+    if shards > 1 {
+        let free_key = (0..)
+            .map(|i| format!("free:{i}"))
+            .find(|key| common::shard_for_key(key, shards) != blocked_shard)
+            .expect("key on another shard");
+        let start = Instant::now();
+        client.assert_command_response(&request(&["GET", &free_key]), "$-1\r\n");
+        assert!(
+            start.elapsed() < Duration::from_millis(300),
+            "other shard was stalled for {:?}",
+            start.elapsed()
+        );
+    }
+
+    let start = Instant::now();
+    client.assert_command_response(&request(&["GET", blocked_key]), "$1\r\nv\r\n");
+    assert!(
+        start.elapsed() >= Duration::from_millis(300),
+        "blocked shard answered after {:?}",
+        start.elapsed()
+    );
+
+    let mut reply = String::new();
+    blocker_reader
+        .read_line(&mut reply)
+        .expect("read DEBUG SHARD-BLOCK reply");
+    assert_eq!(reply, "+OK\r\n");
+}
+
+#[test]
+fn debug_shard_block_rejects_invalid_duration() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(
+        &request(&["DEBUG", "SHARD-BLOCK", "key", "soon"]),
+        "-ERR value is not an integer or out of range\r\n",
+    );
+}
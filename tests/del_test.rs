@@ -0,0 +1,89 @@
+mod common;
+
+use crate::common::{request, start_client};
+use std::thread;
+use std::time::Duration;
+
+// https://redis.io/docs/latest/commands/del/
+// https://redis.io/docs/latest/commands/unlink/
+
+/// One key owned by every shard
+fn key_per_shard(shards: usize) -> Vec<String> {
+    let mut keys: Vec<Option<String>> = vec![None; shards];
+    let mut i = 0;
+    while keys.iter().any(Option::is_none) {
+        let key = format!("key:{i}");
+        let slot = &mut keys[common::shard_for_key(&key, shards)];
+        if slot.is_none() {
+            *slot = Some(key);
+        }
+        i += 1;
+    }
+    keys.into_iter().flatten().collect()
+}
+
+#[test]
+fn del_counts_existing_keys_across_shards() {
+    let mut client = start_client();
+    let keys = key_per_shard(common::effective_shards(3));
+
+    for key in &keys {
+        client.assert_command_response(&request(&["SET", key, "value"]), "+OK\r\n");
+    }
+    client.assert_command_response(&request(&["RPUSH", "list", "a", "b"]), ":2\r\n");
+
+    let mut del = vec!["DEL", "missing:1", "list"];
+    del.extend(keys.iter().map(String::as_str));
+    del.push("missing:2");
+    client.assert_command_response(&request(&del), &format!(":{}\r\n", keys.len() + 1));
+
+    for key in &keys {
+        client.assert_command_response(&request(&["GET", key]), "$-1\r\n");
+    }
+    client.assert_command_response(&request(&["LLEN", "list"]), ":0\r\n");
+
+    // Nothing left to remove
+    client.assert_command_response(&request(&del), ":0\r\n");
+}
+
+#[test]
+fn del_duplicate_keys_are_removed_once() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "key", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["DEL", "key", "key"]), ":1\r\n");
+}
+
+#[test]
+fn unlink_is_an_alias_of_del() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "a", "1"]), "+OK\r\n");
+    client.assert_command_response(&request(&["SET", "b", "2"]), "+OK\r\n");
+    client.assert_command_response(&request(&["UNLINK", "a", "b", "c"]), ":2\r\n");
+    client.assert_command_response(&request(&["GET", "a"]), "$-1\r\n");
+}
+
+#[test]
+fn del_cancels_pending_expiration() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "key", "old", "PX", "200"]), "+OK\r\n");
+    client.assert_command_response(&request(&["DEL", "key"]), ":1\r\n");
+    client.assert_command_response(&request(&["SET", "key", "new"]), "+OK\r\n");
+
+    thread::sleep(Duration::from_millis(400));
+
+    // The old timer must not remove the new value
+    client.assert_command_response(&request(&["GET", "key"]), "$3\r\nnew\r\n");
+}
+
+#[test]
+fn del_wrong_number_of_arguments() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["DEL"]),
+        "-Wrong number of arguments for DEL command\r\n",
+    );
+}
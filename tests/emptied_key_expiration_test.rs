@@ -0,0 +1,75 @@
+mod common;
+
+use crate::common::{request, start_client};
+use std::thread;
+use std::time::Duration;
+
+// A key deleted because its last element was removed takes its expiration with it:
+// the key created again under the same name has no TTL and outlives the old deadline.
+
+/// Creates `key` with `create`, sets a 1 second expiration, empties it with `empty`
+/// and creates it again: the new key must not expire.
+fn assert_emptied_key_forgets_expiration(
+    create: (&[&str], &str),
+    empty: (&[&str], &str),
+    key: &str,
+) {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(create.0), create.1);
+    client.assert_command_response(&request(&["EXPIRE", key, "1"]), ":1\r\n");
+    client.assert_command_response(&request(empty.0), empty.1);
+    client.assert_command_response(&request(&["EXISTS", key]), ":0\r\n");
+
+    client.assert_command_response(&request(create.0), create.1);
+    client.assert_command_response(&request(&["TTL", key]), ":-1\r\n");
+
+    thread::sleep(Duration::from_millis(1200));
+    client.assert_command_response(&request(&["EXISTS", key]), ":1\r\n");
+    client.assert_command_response(&request(&["TTL", key]), ":-1\r\n");
+}
+
+#[test]
+fn lpop_emptied_list_forgets_expiration() {
+    assert_emptied_key_forgets_expiration(
+        (&["RPUSH", "list", "a"], ":1\r\n"),
+        (&["LPOP", "list"], "$1\r\na\r\n"),
+        "list",
+    );
+}
+
+#[test]
+fn hdel_emptied_hash_forgets_expiration() {
+    assert_emptied_key_forgets_expiration(
+        (&["HSET", "hash", "field", "value"], ":1\r\n"),
+        (&["HDEL", "hash", "field"], ":1\r\n"),
+        "hash",
+    );
+}
+
+#[test]
+fn srem_emptied_set_forgets_expiration() {
+    assert_emptied_key_forgets_expiration(
+        (&["SADD", "set", "member"], ":1\r\n"),
+        (&["SREM", "set", "member"], ":1\r\n"),
+        "set",
+    );
+}
+
+#[test]
+fn spop_emptied_set_forgets_expiration() {
+    assert_emptied_key_forgets_expiration(
+        (&["SADD", "set", "member"], ":1\r\n"),
+        (&["SPOP", "set"], "$6\r\nmember\r\n"),
+        "set",
+    );
+}
+
+#[test]
+fn zpopmin_emptied_sorted_set_forgets_expiration() {
+    assert_emptied_key_forgets_expiration(
+        (&["ZADD", "zset", "1", "member"], ":1\r\n"),
+        (&["ZPOPMIN", "zset"], "*2\r\n$6\r\nmember\r\n$1\r\n1\r\n"),
+        "zset",
+    );
+}
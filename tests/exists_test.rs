@@ -0,0 +1,68 @@
+mod common;
+
+use crate::common::{request, start_client};
+
+// https://redis.io/docs/latest/commands/exists/
+
+/// One key owned by every shard
+fn key_per_shard(shards: usize) -> Vec<String> {
+    let mut keys: Vec<Option<String>> = vec![None; shards];
+    let mut i = 0;
+    while keys.iter().any(Option::is_none) {
+        let key = format!("key:{i}");
+        let slot = &mut keys[common::shard_for_key(&key, shards)];
+        if slot.is_none() {
+            *slot = Some(key);
+        }
+        i += 1;
+    }
+    keys.into_iter().flatten().collect()
+}
+
+#[test]
+fn exists_counts_keys_across_shards() {
+    let mut client = start_client();
+    let keys = key_per_shard(common::effective_shards(3));
+
+    for key in &keys {
+        client.assert_command_response(&request(&["SET", key, "value"]), "+OK\r\n");
+    }
+
+    let mut exists = vec!["EXISTS", "missing:1"];
+    exists.extend(keys.iter().map(String::as_str));
+    exists.push("missing:2");
+    client.assert_command_response(&request(&exists), &format!(":{}\r\n", keys.len()));
+
+    client.assert_command_response(&request(&["EXISTS", "missing:1"]), ":0\r\n");
+}
+
+#[test]
+fn exists_counts_duplicate_keys() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "key", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["EXISTS", "key", "key"]), ":2\r\n");
+    client.assert_command_response(&request(&["EXISTS", "key", "missing", "key"]), ":2\r\n");
+}
+
+#[test]
+fn exists_sees_every_value_type() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["RPUSH", "list", "a"]), ":1\r\n");
+    client.assert_command_response(
+        &request(&["XADD", "stream", "1-1", "f", "v"]),
+        "$3\r\n1-1\r\n",
+    );
+    client.assert_command_response(&request(&["EXISTS", "list", "stream"]), ":2\r\n");
+}
+
+#[test]
+fn exists_wrong_number_of_arguments() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["EXISTS"]),
+        "-Wrong number of arguments for EXISTS command\r\n",
+    );
+}
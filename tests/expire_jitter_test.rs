@@ -0,0 +1,46 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request};
+use std::thread;
+use std::time::Duration;
+
+// --expire-jitter-ms adds a random delay to every expiration
+
+const KEYS: usize = 50;
+const TTL_MS: u64 = 200;
+const JITTER_MS: u64 = 300;
+
+#[test]
+fn keys_with_jitter_expire_within_ttl_and_jitter_window() {
+    let server = common::ValkyrieServerTest::start_with_args(
+        2,
+        3,
+        &["--expire-jitter-ms", &JITTER_MS.to_string()],
+    )
+    .expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    for i in 0..KEYS {
+        client.assert_command_response(
+            &request(&[
+                "SET",
+                &format!("key:{i}"),
+                "value",
+                "PX",
+                &TTL_MS.to_string(),
+            ]),
+            "+OK\r\n",
+        );
+    }
+
+    // Jitter only delays expirations, nothing is gone before the TTL
+    for i in 0..KEYS {
+        client.assert_command_response(&request(&["EXISTS", &format!("key:{i}")]), ":1\r\n");
+    }
+
+    thread::sleep(Duration::from_millis(TTL_MS + JITTER_MS + 200));
+
+    for i in 0..KEYS {
+        client.assert_command_response(&request(&["EXISTS", &format!("key:{i}")]), ":0\r\n");
+    }
+}
@@ -0,0 +1,115 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request, start_client};
+use std::thread;
+use std::time::Duration;
+
+// https://redis.io/docs/latest/commands/expire/
+
+/// Fresh client with `persistent` (no TTL) and `volatile` (100 seconds TTL) keys.
+fn start_client_with_keys() -> ValkyrieClientTest {
+    let mut client = start_client();
+    client.assert_command_response(&request(&["SET", "persistent", "value"]), "+OK\r\n");
+    client.assert_command_response(
+        &request(&["SET", "volatile", "value", "EX", "100"]),
+        "+OK\r\n",
+    );
+    client
+}
+
+#[test]
+fn expire_sets_timeout() {
+    let mut client = start_client_with_keys();
+
+    client.assert_command_response(&request(&["EXPIRE", "persistent", "50"]), ":1\r\n");
+    client.assert_command_response(&request(&["TTL", "persistent"]), ":50\r\n");
+
+    // Replaces the previous timeout
+    client.assert_command_response(&request(&["EXPIRE", "volatile", "20"]), ":1\r\n");
+    client.assert_command_response(&request(&["TTL", "volatile"]), ":20\r\n");
+
+    client.assert_command_response(&request(&["EXPIRE", "missing", "50"]), ":0\r\n");
+}
+
+#[test]
+fn expire_deletes_key_when_timeout_passes() {
+    let mut client = start_client_with_keys();
+
+    client.assert_command_response(&request(&["EXPIRE", "persistent", "1"]), ":1\r\n");
+    thread::sleep(Duration::from_millis(1200));
+    client.assert_command_response(&request(&["GET", "persistent"]), "$-1\r\n");
+}
+
+#[test]
+fn expire_non_positive_timeout_deletes_key() {
+    let mut client = start_client_with_keys();
+
+    client.assert_command_response(&request(&["EXPIRE", "persistent", "0"]), ":1\r\n");
+    client.assert_command_response(&request(&["EXPIRE", "volatile", "-5"]), ":1\r\n");
+    client.assert_command_response(&request(&["EXISTS", "persistent", "volatile"]), ":0\r\n");
+}
+
+#[test]
+fn expire_nx() {
+    let mut client = start_client_with_keys();
+
+    client.assert_command_response(&request(&["EXPIRE", "persistent", "50", "NX"]), ":1\r\n");
+    client.assert_command_response(&request(&["TTL", "persistent"]), ":50\r\n");
+
+    client.assert_command_response(&request(&["EXPIRE", "volatile", "50", "nx"]), ":0\r\n");
+    client.assert_command_response(&request(&["TTL", "volatile"]), ":100\r\n");
+}
+
+#[test]
+fn expire_xx() {
+    let mut client = start_client_with_keys();
+
+    client.assert_command_response(&request(&["EXPIRE", "persistent", "50", "XX"]), ":0\r\n");
+    client.assert_command_response(&request(&["TTL", "persistent"]), ":-1\r\n");
+
+    client.assert_command_response(&request(&["EXPIRE", "volatile", "50", "XX"]), ":1\r\n");
+    client.assert_command_response(&request(&["TTL", "volatile"]), ":50\r\n");
+}
+
+#[test]
+fn expire_gt() {
+    let mut client = start_client_with_keys();
+
+    // No timeout counts as infinite, nothing is greater
+    client.assert_command_response(&request(&["EXPIRE", "persistent", "50", "GT"]), ":0\r\n");
+    client.assert_command_response(&request(&["TTL", "persistent"]), ":-1\r\n");
+
+    client.assert_command_response(&request(&["EXPIRE", "volatile", "50", "GT"]), ":0\r\n");
+    client.assert_command_response(&request(&["TTL", "volatile"]), ":100\r\n");
+    client.assert_command_response(&request(&["EXPIRE", "volatile", "200", "GT"]), ":1\r\n");
+    client.assert_command_response(&request(&["TTL", "volatile"]), ":200\r\n");
+}
+
+#[test]
+fn expire_lt() {
+    let mut client = start_client_with_keys();
+
+    // No timeout counts as infinite, any timeout is less
+    client.assert_command_response(&request(&["EXPIRE", "persistent", "50", "LT"]), ":1\r\n");
+    client.assert_command_response(&request(&["TTL", "persistent"]), ":50\r\n");
+
+    client.assert_command_response(&request(&["EXPIRE", "volatile", "200", "LT"]), ":0\r\n");
+    client.assert_command_response(&request(&["TTL", "volatile"]), ":100\r\n");
+    client.assert_command_response(&request(&["EXPIRE", "volatile", "50", "LT"]), ":1\r\n");
+    client.assert_command_response(&request(&["TTL", "volatile"]), ":50\r\n");
+}
+
+#[test]
+fn expire_invalid_arguments() {
+    let mut client = start_client_with_keys();
+
+    client.assert_command_response(
+        &request(&["EXPIRE", "persistent", "soon"]),
+        "-ERR value is not an integer or out of range\r\n",
+    );
+    client.assert_command_response(
+        &request(&["EXPIRE", "persistent", "50", "YY"]),
+        "-ERR Unsupported option YY\r\n",
+    );
+    client.assert_command_response(&request(&["TTL", "persistent"]), ":-1\r\n");
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::common::ValkyrieClientTest;
+
+// --first-command-timeout-ms <ms> closes connections that don't send a complete first command in time
+
+const TIMEOUT_MS: u64 = 300;
+
+fn start_server() -> common::ValkyrieServerTest {
+    common::ValkyrieServerTest::start_with_args(
+        2,
+        3,
+        &["--first-command-timeout-ms", &TIMEOUT_MS.to_string()],
+    )
+    .expect("start server")
+}
+
+/// Blocks until the server closes the connection, returns how long it took.
+fn wait_for_close(stream: &mut TcpStream, since: Instant) -> Duration {
+    let mut buf = [0u8; 64];
+    let n = stream.read(&mut buf).expect("read until close");
+    assert_eq!(n, 0, "Expected the connection to be closed");
+    since.elapsed()
+}
+
+#[test]
+fn silent_connection_is_closed_after_first_command_timeout() {
+    let server = start_server();
+    let started = Instant::now();
+    let mut stream = server.connect().expect("connect");
+
+    let elapsed = wait_for_close(&mut stream, started);
+
+    assert!(
+        elapsed >= Duration::from_millis(TIMEOUT_MS - 50),
+        "closed too early: {elapsed:?}"
+    );
+    assert!(
+        elapsed < Duration::from_millis(TIMEOUT_MS + 1_500),
+        "closed too late: {elapsed:?}"
+    );
+}
+
+#[test]
+fn trickled_incomplete_frame_does_not_extend_the_deadline() {
+    let server = start_server();
+    let started = Instant::now();
+    let mut stream = server.connect().expect("connect");
+
+    // A byte at a time, never completing the frame, for most of the window
+    for byte in b"*1\r\n$" {
+        stream.write_all(&[*byte]).expect("send byte");
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    // A deadline restarted by every read would only expire 300ms after the last byte
+    let elapsed = wait_for_close(&mut stream, started);
+    assert!(
+        elapsed < Duration::from_millis(TIMEOUT_MS + 200),
+        "closed too late: {elapsed:?}"
+    );
+}
+
+#[test]
+fn connection_stays_open_after_the_first_command() {
+    let server = start_server();
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response("*1\r\n$4\r\nPING\r\n", "+PONG\r\n");
+    thread::sleep(Duration::from_millis(TIMEOUT_MS * 2));
+    client.assert_command_response("*1\r\n$4\r\nPING\r\n", "+PONG\r\n");
+}
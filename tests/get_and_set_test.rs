@@ -2,7 +2,7 @@ mod common;
 
 use crate::common::ValkyrieClientTest;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 #[test]
 fn set_and_get_roundtrip_no_expiration() {
@@ -93,3 +93,186 @@ fn set_with_px_expiration_expires() {
     let get_req2 = format!("*2\r\n$3\r\nGET\r\n${}\r\n{}\r\n", key.len(), key);
     client_test.assert_command_response(&get_req2, "$-1\r\n");
 }
+
+#[test]
+fn set_with_ex_and_px_is_syntax_error() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client_test = ValkyrieClientTest::new(server);
+
+    // SET key value EX 10 PX 10000
+    let set_req = "*7\r\n$3\r\nSET\r\n$3\r\nkey\r\n$5\r\nvalue\r\n$2\r\nEX\r\n$2\r\n10\r\n$2\r\nPX\r\n$5\r\n10000\r\n";
+    client_test.assert_command_response(set_req, "-ERR syntax error\r\n");
+
+    // Key must not be created by the rejected SET
+    client_test.assert_command_response("*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n", "$-1\r\n");
+}
+
+#[test]
+fn set_with_duplicated_ex_is_syntax_error() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client_test = ValkyrieClientTest::new(server);
+
+    // SET key value EX 10 EX 20
+    let set_req = "*7\r\n$3\r\nSET\r\n$3\r\nkey\r\n$5\r\nvalue\r\n$2\r\nEX\r\n$2\r\n10\r\n$2\r\nEX\r\n$2\r\n20\r\n";
+    client_test.assert_command_response(set_req, "-ERR syntax error\r\n");
+}
+
+#[test]
+fn set_with_expiration_option_without_value_is_syntax_error() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client_test = ValkyrieClientTest::new(server);
+
+    // SET key value EX
+    let set_req = "*4\r\n$3\r\nSET\r\n$3\r\nkey\r\n$5\r\nvalue\r\n$2\r\nEX\r\n";
+    client_test.assert_command_response(set_req, "-ERR syntax error\r\n");
+}
+
+fn unix_time_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time after epoch")
+        .as_millis() as u64
+}
+
+fn set_with_option_request(key: &str, value: &str, option: &str, option_value: &str) -> String {
+    format!(
+        "*5\r\n$3\r\nSET\r\n${}\r\n{}\r\n${}\r\n{}\r\n${}\r\n{}\r\n${}\r\n{}\r\n",
+        key.len(),
+        key,
+        value.len(),
+        value,
+        option.len(),
+        option,
+        option_value.len(),
+        option_value
+    )
+}
+
+#[test]
+fn set_with_future_exat_keeps_key_until_deadline() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client_test = ValkyrieClientTest::new(server);
+
+    // SET key value EXAT <now + 100s>
+    let deadline = (unix_time_ms() / 1000 + 100).to_string();
+    let set_req = set_with_option_request("exat_key", "value", "EXAT", &deadline);
+    client_test.assert_command_response(&set_req, "+OK\r\n");
+    client_test.assert_command_response("*2\r\n$3\r\nGET\r\n$8\r\nexat_key\r\n", "$5\r\nvalue\r\n");
+
+    // SET key value PXAT <now + 300ms> expires like PX
+    let deadline = (unix_time_ms() + 300).to_string();
+    let set_req = set_with_option_request("pxat_key", "value", "PXAT", &deadline);
+    client_test.assert_command_response(&set_req, "+OK\r\n");
+    thread::sleep(Duration::from_millis(600));
+    client_test.assert_command_response("*2\r\n$3\r\nGET\r\n$8\r\npxat_key\r\n", "$-1\r\n");
+}
+
+#[test]
+fn set_with_past_pxat_expires_immediately() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client_test = ValkyrieClientTest::new(server);
+
+    client_test.assert_command_response("*3\r\n$3\r\nSET\r\n$3\r\nkey\r\n$3\r\nold\r\n", "+OK\r\n");
+
+    // SET key value PXAT <now - 10s>
+    let deadline = (unix_time_ms() - 10_000).to_string();
+    let set_req = set_with_option_request("key", "new", "PXAT", &deadline);
+    client_test.assert_command_response(&set_req, "+OK\r\n");
+
+    // Neither the new nor the old value survive
+    client_test.assert_command_response("*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n", "$-1\r\n");
+}
+
+#[test]
+fn set_with_exat_and_px_is_syntax_error() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client_test = ValkyrieClientTest::new(server);
+
+    // SET key value EXAT 9999999999 PX 100
+    let set_req = "*7\r\n$3\r\nSET\r\n$3\r\nkey\r\n$5\r\nvalue\r\n$4\r\nEXAT\r\n$10\r\n9999999999\r\n$2\r\nPX\r\n$3\r\n100\r\n";
+    client_test.assert_command_response(set_req, "-ERR syntax error\r\n");
+}
+
+#[test]
+fn set_with_zero_expiration_is_rejected() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client_test = ValkyrieClientTest::new(server);
+
+    for option in ["EX", "PX", "EXAT", "PXAT"] {
+        let set_req = set_with_option_request("key", "value", option, "0");
+        client_test
+            .assert_command_response(&set_req, "-ERR invalid expire time in 'set' command\r\n");
+    }
+}
+
+#[test]
+fn get_large_value_returns_every_byte() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client_test = ValkyrieClientTest::new(server);
+
+    // A single request is capped at 64KB, so grow a 2MB value with APPEND
+    let chunks: Vec<String> = (0..64)
+        .map(|i| {
+            char::from(b'a' + (i % 26) as u8)
+                .to_string()
+                .repeat(32 * 1024)
+        })
+        .collect();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let append_req = format!(
+            "*3\r\n$6\r\nAPPEND\r\n$5\r\nlarge\r\n${}\r\n{}\r\n",
+            chunk.len(),
+            chunk
+        );
+        let append_resp = format!(":{}\r\n", (i + 1) * chunk.len());
+        client_test.assert_command_response(&append_req, &append_resp);
+    }
+
+    let value = chunks.concat();
+    let get_resp = format!("${}\r\n{}\r\n", value.len(), value);
+    client_test.assert_command_response("*2\r\n$3\r\nGET\r\n$5\r\nlarge\r\n", &get_resp);
+
+    // The connection is still in sync after the large reply
+    client_test.assert_command_response("*1\r\n$4\r\nPING\r\n", "+PONG\r\n");
+}
+
+#[test]
+fn set_accepts_integer_value() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client_test = ValkyrieClientTest::new(server);
+
+    // SET key :42 -> the integer is stored in its decimal form
+    client_test.assert_command_response("*3\r\n$3\r\nSET\r\n$3\r\nkey\r\n:42\r\n", "+OK\r\n");
+    client_test.assert_command_response("*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n", "$2\r\n42\r\n");
+
+    // Stored like any other numeric string
+    client_test.assert_command_response("*2\r\n$4\r\nINCR\r\n$3\r\nkey\r\n", ":43\r\n");
+    client_test.assert_command_response(
+        "*5\r\n$3\r\nSET\r\n$3\r\nneg\r\n:-7\r\n$2\r\nPX\r\n$5\r\n10000\r\n",
+        "+OK\r\n",
+    );
+    client_test.assert_command_response("*2\r\n$3\r\nGET\r\n$3\r\nneg\r\n", "$2\r\n-7\r\n");
+}
+
+#[test]
+fn string_writes_accept_integer_values() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client_test = ValkyrieClientTest::new(server);
+
+    client_test.assert_command_response("*3\r\n$5\r\nSETNX\r\n$1\r\nk\r\n:1\r\n", ":1\r\n");
+    client_test.assert_command_response("*3\r\n$6\r\nAPPEND\r\n$1\r\nk\r\n:23\r\n", ":3\r\n");
+    client_test.assert_command_response(
+        "*4\r\n$8\r\nSETRANGE\r\n$1\r\nk\r\n$1\r\n3\r\n:4\r\n",
+        ":4\r\n",
+    );
+    client_test.assert_command_response("*2\r\n$3\r\nGET\r\n$1\r\nk\r\n", "$4\r\n1234\r\n");
+
+    client_test.assert_command_response(
+        "*5\r\n$4\r\nMSET\r\n$1\r\na\r\n:5\r\n$1\r\nb\r\n$1\r\n6\r\n",
+        "+OK\r\n",
+    );
+    client_test.assert_command_response(
+        "*3\r\n$4\r\nMGET\r\n$1\r\na\r\n$1\r\nb\r\n",
+        "*2\r\n$1\r\n5\r\n$1\r\n6\r\n",
+    );
+}
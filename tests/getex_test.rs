@@ -0,0 +1,86 @@
+mod common;
+
+use crate::common::{request, start_client};
+use std::thread;
+use std::time::Duration;
+
+// https://redis.io/docs/latest/commands/getex/
+// GETEX key [EX seconds | PX milliseconds | EXAT unix-time-seconds | PXAT unix-time-milliseconds | PERSIST]
+
+#[test]
+fn getex_without_options_behaves_like_get() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "key", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["GETEX", "key"]), "$5\r\nvalue\r\n");
+    client.assert_command_response(&request(&["GET", "key"]), "$5\r\nvalue\r\n");
+}
+
+#[test]
+fn getex_ex_sets_expiration() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "key", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["GETEX", "key", "EX", "1"]), "$5\r\nvalue\r\n");
+    client.assert_command_response(&request(&["GET", "key"]), "$5\r\nvalue\r\n");
+
+    thread::sleep(Duration::from_millis(1300));
+    client.assert_command_response(&request(&["GET", "key"]), "$-1\r\n");
+}
+
+#[test]
+fn getex_px_replaces_existing_expiration() {
+    let mut client = start_client();
+
+    // Long expiration replaced by a short one
+    client.assert_command_response(&request(&["SET", "key", "value", "EX", "100"]), "+OK\r\n");
+    client.assert_command_response(&request(&["GETEX", "key", "PX", "200"]), "$5\r\nvalue\r\n");
+
+    thread::sleep(Duration::from_millis(500));
+    client.assert_command_response(&request(&["GET", "key"]), "$-1\r\n");
+}
+
+#[test]
+fn getex_persist_removes_expiration() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "key", "value", "PX", "200"]), "+OK\r\n");
+    client.assert_command_response(&request(&["GETEX", "key", "PERSIST"]), "$5\r\nvalue\r\n");
+
+    thread::sleep(Duration::from_millis(500));
+    client.assert_command_response(&request(&["GET", "key"]), "$5\r\nvalue\r\n");
+}
+
+#[test]
+fn getex_missing_key_is_nil_and_sets_no_expiration() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["GETEX", "key", "PX", "200"]), "$-1\r\n");
+
+    // A key created afterwards must not inherit the expiration
+    client.assert_command_response(&request(&["SET", "key", "value"]), "+OK\r\n");
+    thread::sleep(Duration::from_millis(500));
+    client.assert_command_response(&request(&["GET", "key"]), "$5\r\nvalue\r\n");
+}
+
+#[test]
+fn getex_invalid_options() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "key", "value"]), "+OK\r\n");
+    client.assert_command_response(
+        &request(&["GETEX", "key", "EX", "10", "PERSIST"]),
+        "-ERR syntax error\r\n",
+    );
+    client.assert_command_response(&request(&["GETEX", "key", "NOPE"]), "-ERR syntax error\r\n");
+    client.assert_command_response(
+        &request(&["GETEX", "key", "EX", "0"]),
+        "-ERR invalid expire time in 'getex' command\r\n",
+    );
+
+    client.assert_command_response(&request(&["RPUSH", "list", "a"]), ":1\r\n");
+    client.assert_command_response(
+        &request(&["GETEX", "list"]),
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use crate::common::{request, start_client};
+
+// https://redis.io/docs/latest/commands/hset/
+// https://redis.io/docs/latest/commands/hget/
+// https://redis.io/docs/latest/commands/hdel/
+// https://redis.io/docs/latest/commands/type/
+
+const WRONG_TYPE: &str = "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n";
+
+#[test]
+fn hset_counts_only_new_fields() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["HSET", "user", "name", "alice", "age", "30"]),
+        ":2\r\n",
+    );
+    // `age` is overwritten, only `city` is new
+    client.assert_command_response(
+        &request(&["HSET", "user", "age", "31", "city", "paris"]),
+        ":1\r\n",
+    );
+
+    client.assert_command_response(&request(&["HGET", "user", "name"]), "$5\r\nalice\r\n");
+    client.assert_command_response(&request(&["HGET", "user", "age"]), "$2\r\n31\r\n");
+    client.assert_command_response(&request(&["HGET", "user", "city"]), "$5\r\nparis\r\n");
+}
+
+#[test]
+fn hget_missing_key_or_field_returns_nil() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["HGET", "missing", "field"]), "$-1\r\n");
+
+    client.assert_command_response(&request(&["HSET", "hash", "field", "value"]), ":1\r\n");
+    client.assert_command_response(&request(&["HGET", "hash", "other"]), "$-1\r\n");
+}
+
+#[test]
+fn hdel_removes_fields_and_empty_hash() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["HDEL", "missing", "field"]), ":0\r\n");
+
+    client.assert_command_response(
+        &request(&["HSET", "hash", "a", "1", "b", "2", "c", "3"]),
+        ":3\r\n",
+    );
+    // Missing fields are ignored
+    client.assert_command_response(&request(&["HDEL", "hash", "a", "x"]), ":1\r\n");
+    client.assert_command_response(&request(&["HGET", "hash", "a"]), "$-1\r\n");
+    client.assert_command_response(&request(&["HGET", "hash", "b"]), "$1\r\n2\r\n");
+
+    client.assert_command_response(&request(&["HDEL", "hash", "b", "c"]), ":2\r\n");
+    client.assert_command_response(&request(&["EXISTS", "hash"]), ":0\r\n");
+}
+
+#[test]
+fn hash_commands_on_other_types_return_wrong_type() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "string", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["RPUSH", "list", "a"]), ":1\r\n");
+
+    for key in ["string", "list"] {
+        client.assert_command_response(&request(&["HSET", key, "f", "v"]), WRONG_TYPE);
+        client.assert_command_response(&request(&["HGET", key, "f"]), WRONG_TYPE);
+        client.assert_command_response(&request(&["HDEL", key, "f"]), WRONG_TYPE);
+    }
+
+    client.assert_command_response(&request(&["GET", "string"]), "$5\r\nvalue\r\n");
+    client.assert_command_response(&request(&["LLEN", "list"]), ":1\r\n");
+}
+
+#[test]
+fn type_reports_value_type() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "string", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["RPUSH", "list", "a"]), ":1\r\n");
+    client.assert_command_response(&request(&["HSET", "hash", "f", "v"]), ":1\r\n");
+    client.assert_command_response(
+        &request(&["XADD", "stream", "1-1", "f", "v"]),
+        "$3\r\n1-1\r\n",
+    );
+
+    client.assert_command_response(&request(&["TYPE", "string"]), "+string\r\n");
+    client.assert_command_response(&request(&["TYPE", "list"]), "+list\r\n");
+    client.assert_command_response(&request(&["TYPE", "hash"]), "+hash\r\n");
+    client.assert_command_response(&request(&["TYPE", "stream"]), "+stream\r\n");
+    client.assert_command_response(&request(&["TYPE", "missing"]), "+none\r\n");
+}
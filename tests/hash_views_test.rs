@@ -0,0 +1,106 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request, start_client};
+
+// https://redis.io/docs/latest/commands/hgetall/
+// https://redis.io/docs/latest/commands/hkeys/
+// https://redis.io/docs/latest/commands/hvals/
+// https://redis.io/docs/latest/commands/hlen/
+
+const WRONG_TYPE: &str = "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n";
+
+/// Sends the request and reads the array of bulk strings it replies with, only the header is expected as a line
+fn bulk_string_array(client: &mut ValkyrieClientTest, args: &[&str]) -> Vec<String> {
+    client.send(request(args).as_bytes()).expect("send request");
+
+    let header = client.read_line().expect("read array header");
+    assert!(header.starts_with('*'), "Expected array, got: {header:?}");
+    let len: usize = header[1..].trim().parse().expect("parse array length");
+
+    (0..len)
+        .map(|_| client.read_bulk_or_null().expect("bulk string"))
+        .collect()
+}
+
+fn sorted(mut values: Vec<String>) -> Vec<String> {
+    values.sort();
+    values
+}
+
+fn start_client_with_hash() -> ValkyrieClientTest {
+    let mut client = start_client();
+    client.assert_command_response(
+        &request(&["HSET", "hash", "a", "1", "b", "2", "c", "3"]),
+        ":3\r\n",
+    );
+    client
+}
+
+#[test]
+fn hgetall_returns_field_value_pairs() {
+    let mut client = start_client_with_hash();
+
+    let reply = bulk_string_array(&mut client, &["HGETALL", "hash"]);
+    assert_eq!(reply.len(), 6);
+
+    let mut pairs: Vec<(String, String)> = reply
+        .chunks_exact(2)
+        .map(|pair| (pair[0].clone(), pair[1].clone()))
+        .collect();
+    pairs.sort();
+    assert_eq!(
+        pairs,
+        [("a", "1"), ("b", "2"), ("c", "3")]
+            .map(|(field, value)| (field.to_string(), value.to_string()))
+    );
+}
+
+#[test]
+fn hkeys_and_hvals_list_fields_in_the_same_order() {
+    let mut client = start_client_with_hash();
+
+    let fields = bulk_string_array(&mut client, &["HKEYS", "hash"]);
+    let values = bulk_string_array(&mut client, &["HVALS", "hash"]);
+
+    assert_eq!(sorted(fields.clone()), ["a", "b", "c"]);
+    assert_eq!(sorted(values.clone()), ["1", "2", "3"]);
+
+    // Values of the fixture are the 1-based position of their field in the alphabet
+    for (field, value) in fields.iter().zip(&values) {
+        let expected = (field.as_bytes()[0] - b'a' + 1).to_string();
+        assert_eq!(value, &expected, "value of field {field}");
+    }
+}
+
+#[test]
+fn hlen_counts_fields() {
+    let mut client = start_client_with_hash();
+
+    client.assert_command_response(&request(&["HLEN", "hash"]), ":3\r\n");
+    client.assert_command_response(&request(&["HDEL", "hash", "a"]), ":1\r\n");
+    client.assert_command_response(&request(&["HLEN", "hash"]), ":2\r\n");
+}
+
+#[test]
+fn hash_views_of_missing_key() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["HGETALL", "missing"]), "*0\r\n");
+    client.assert_command_response(&request(&["HKEYS", "missing"]), "*0\r\n");
+    client.assert_command_response(&request(&["HVALS", "missing"]), "*0\r\n");
+    client.assert_command_response(&request(&["HLEN", "missing"]), ":0\r\n");
+}
+
+#[test]
+fn hash_views_of_other_types_return_wrong_type() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "string", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["RPUSH", "list", "a"]), ":1\r\n");
+
+    for key in ["string", "list"] {
+        for command in ["HGETALL", "HKEYS", "HVALS", "HLEN"] {
+            client.assert_command_response(&request(&[command, key]), WRONG_TYPE);
+        }
+    }
+}
@@ -0,0 +1,161 @@
+mod common;
+
+use std::io::{Read, Write};
+
+use crate::common::{ValkyrieClientTest, request, start_client};
+
+// https://redis.io/docs/latest/commands/hello/
+// HELLO switches the reply protocol of the connection between RESP2 and RESP3
+
+/// Reads a scalar reply line: the payload of a bulk string, or the raw line (`:3`, `*0`) otherwise.
+fn read_scalar(client: &mut ValkyrieClientTest) -> String {
+    let line = client.read_line().expect("read reply line");
+    if line.starts_with('$') {
+        client
+            .read_line()
+            .expect("read bulk payload")
+            .trim_end()
+            .to_string()
+    } else {
+        line.trim_end().to_string()
+    }
+}
+
+/// Sends HELLO and returns its reply header line with the key/value pairs that follow it.
+fn hello(client: &mut ValkyrieClientTest, args: &[&str]) -> (String, Vec<(String, String)>) {
+    let mut command = vec!["HELLO"];
+    command.extend_from_slice(args);
+    client
+        .send(request(&command).as_bytes())
+        .expect("send HELLO");
+
+    let header = client
+        .read_line()
+        .expect("read header")
+        .trim_end()
+        .to_string();
+    let pairs = match header.as_bytes()[0] {
+        b'*' => header[1..].parse::<usize>().expect("array length") / 2,
+        b'%' => header[1..].parse::<usize>().expect("map length"),
+        _ => panic!("Unexpected HELLO reply: {header:?}"),
+    };
+
+    let entries = (0..pairs)
+        .map(|_| (read_scalar(client), read_scalar(client)))
+        .collect();
+    (header, entries)
+}
+
+fn field<'a>(entries: &'a [(String, String)], name: &str) -> &'a str {
+    entries
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+        .unwrap_or_else(|| panic!("No {name} in HELLO reply"))
+}
+
+#[test]
+fn hello_2_replies_with_flat_array() {
+    let mut client = start_client();
+
+    let (header, entries) = hello(&mut client, &["2"]);
+    assert_eq!(header, "*14");
+    assert_eq!(field(&entries, "server"), "valkyrie");
+    assert_eq!(field(&entries, "proto"), ":2");
+    assert_eq!(field(&entries, "mode"), "standalone");
+    assert_eq!(field(&entries, "role"), "master");
+    assert_eq!(field(&entries, "modules"), "*0");
+
+    // Still RESP2 nulls
+    client.assert_command_response(&request(&["GET", "missing"]), "$-1\r\n");
+}
+
+#[test]
+fn hello_3_switches_to_resp3() {
+    let mut client = start_client();
+
+    let (header, entries) = hello(&mut client, &["3"]);
+    assert_eq!(header, "%7");
+    assert_eq!(field(&entries, "server"), "valkyrie");
+    assert_eq!(field(&entries, "proto"), ":3");
+    assert!(field(&entries, "id").starts_with(':'));
+
+    // RESP3 nulls, other replies unchanged
+    client.assert_command_response(&request(&["GET", "missing"]), "_\r\n");
+    client.assert_command_response(&request(&["SET", "key", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["GET", "key"]), "$5\r\nvalue\r\n");
+    client.assert_command_response(&request(&["LPOP", "missing"]), "_\r\n");
+
+    // HELLO without protover keeps the current protocol
+    let (header, entries) = hello(&mut client, &[]);
+    assert_eq!(header, "%7");
+    assert_eq!(field(&entries, "proto"), ":3");
+
+    // And back to RESP2
+    let (header, _) = hello(&mut client, &["2"]);
+    assert_eq!(header, "*14");
+    client.assert_command_response(&request(&["GET", "missing"]), "$-1\r\n");
+}
+
+#[test]
+fn protocol_is_per_connection() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut other = server.connect().expect("connect");
+    let mut client = ValkyrieClientTest::new(server);
+
+    let (header, _) = hello(&mut client, &["3"]);
+    assert_eq!(header, "%7");
+    client.assert_command_response(&request(&["GET", "missing"]), "_\r\n");
+
+    other
+        .write_all(request(&["GET", "missing"]).as_bytes())
+        .expect("send GET");
+    let mut reply = [0u8; 5];
+    other.read_exact(&mut reply).expect("read reply");
+    assert_eq!(&reply, b"$-1\r\n");
+}
+
+#[test]
+fn unsupported_protocol_version() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["HELLO", "4"]),
+        "-NOPROTO unsupported protocol version\r\n",
+    );
+    client.assert_command_response(
+        &request(&["HELLO", "1"]),
+        "-NOPROTO unsupported protocol version\r\n",
+    );
+    client.assert_command_response(
+        &request(&["HELLO", "three"]),
+        "-ERR Protocol version is not an integer or out of range\r\n",
+    );
+
+    // The connection stays on RESP2
+    client.assert_command_response(&request(&["GET", "missing"]), "$-1\r\n");
+}
+
+#[test]
+fn hello_auth_option() {
+    let server = common::ValkyrieServerTest::start_with_args(2, 3, &["--requirepass", "s3cret"])
+        .expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(
+        &request(&["HELLO", "3"]),
+        "-NOAUTH HELLO must be called with the client already authenticated, otherwise the HELLO <proto> AUTH <user> <pass> option can be used to authenticate the client and select the RESP protocol version at the same time\r\n",
+    );
+    client.assert_command_response(
+        &request(&["HELLO", "3", "AUTH", "default", "wrong"]),
+        "-WRONGPASS invalid username-password pair or user is disabled.\r\n",
+    );
+    client.assert_command_response(
+        &request(&["GET", "missing"]),
+        "-NOAUTH Authentication required.\r\n",
+    );
+
+    let (header, _) = hello(&mut client, &["3", "AUTH", "default", "s3cret"]);
+    assert_eq!(header, "%7");
+    client.assert_command_response(&request(&["GET", "missing"]), "_\r\n");
+}
@@ -0,0 +1,118 @@
+mod common;
+
+use crate::common::{request, start_client};
+
+// https://redis.io/docs/latest/commands/hincrby/
+// https://redis.io/docs/latest/commands/hincrbyfloat/
+
+#[test]
+fn hincrby_creates_missing_key_and_field() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["HINCRBY", "hash", "counter", "5"]), ":5\r\n");
+    client.assert_command_response(&request(&["HINCRBY", "hash", "other", "-3"]), ":-3\r\n");
+    client.assert_command_response(&request(&["HGET", "hash", "counter"]), "$1\r\n5\r\n");
+    client.assert_command_response(&request(&["HLEN", "hash"]), ":2\r\n");
+}
+
+#[test]
+fn hincrby_existing_field() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["HSET", "hash", "counter", "10"]), ":1\r\n");
+    client.assert_command_response(&request(&["HINCRBY", "hash", "counter", "1"]), ":11\r\n");
+    client.assert_command_response(&request(&["HINCRBY", "hash", "counter", "-20"]), ":-9\r\n");
+}
+
+#[test]
+fn hincrby_errors() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["HSET", "hash", "name", "alice"]), ":1\r\n");
+    client.assert_command_response(
+        &request(&["HINCRBY", "hash", "name", "1"]),
+        "-ERR hash value is not an integer\r\n",
+    );
+    client.assert_command_response(
+        &request(&["HINCRBY", "hash", "counter", "one"]),
+        "-ERR value is not an integer or out of range\r\n",
+    );
+
+    client.assert_command_response(
+        &request(&["HSET", "hash", "max", &i64::MAX.to_string()]),
+        ":1\r\n",
+    );
+    client.assert_command_response(
+        &request(&["HINCRBY", "hash", "max", "1"]),
+        "-ERR increment or decrement would overflow\r\n",
+    );
+
+    client.assert_command_response(&request(&["SET", "string", "1"]), "+OK\r\n");
+    client.assert_command_response(
+        &request(&["HINCRBY", "string", "field", "1"]),
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+}
+
+#[test]
+fn hincrbyfloat_creates_missing_key_and_field() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["HINCRBYFLOAT", "hash", "price", "10.5"]),
+        "$4\r\n10.5\r\n",
+    );
+    client.assert_command_response(&request(&["HGET", "hash", "price"]), "$4\r\n10.5\r\n");
+}
+
+#[test]
+fn hincrbyfloat_existing_field() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["HSET", "hash", "price", "10"]), ":1\r\n");
+    client.assert_command_response(
+        &request(&["HINCRBYFLOAT", "hash", "price", "0.25"]),
+        "$5\r\n10.25\r\n",
+    );
+    // Integral results have no fractional part, so HINCRBY can still parse them
+    client.assert_command_response(
+        &request(&["HINCRBYFLOAT", "hash", "price", "-0.25"]),
+        "$2\r\n10\r\n",
+    );
+    client.assert_command_response(&request(&["HINCRBY", "hash", "price", "1"]), ":11\r\n");
+}
+
+#[test]
+fn hincrbyfloat_hides_rounding_noise() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["HINCRBYFLOAT", "hash", "price", "0.1"]),
+        "$3\r\n0.1\r\n",
+    );
+    client.assert_command_response(
+        &request(&["HINCRBYFLOAT", "hash", "price", "0.2"]),
+        "$3\r\n0.3\r\n",
+    );
+}
+
+#[test]
+fn hincrbyfloat_errors() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["HSET", "hash", "name", "alice"]), ":1\r\n");
+    client.assert_command_response(
+        &request(&["HINCRBYFLOAT", "hash", "name", "1.5"]),
+        "-ERR hash value is not a float\r\n",
+    );
+    client.assert_command_response(
+        &request(&["HINCRBYFLOAT", "hash", "price", "abc"]),
+        "-ERR value is not a valid float\r\n",
+    );
+
+    client.assert_command_response(&request(&["RPUSH", "list", "a"]), ":1\r\n");
+    client.assert_command_response(
+        &request(&["HINCRBYFLOAT", "list", "field", "1"]),
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+}
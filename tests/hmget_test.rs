@@ -0,0 +1,48 @@
+mod common;
+
+use crate::common::{request, start_client};
+
+// https://redis.io/docs/latest/commands/hmget/
+// https://redis.io/docs/latest/commands/hexists/
+
+const WRONG_TYPE: &str = "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n";
+
+#[test]
+fn hmget_present_and_absent_fields() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["HSET", "hash", "a", "1", "c", "3"]), ":2\r\n");
+    client.assert_command_response(
+        &request(&["HMGET", "hash", "a", "b", "c", "a"]),
+        "*4\r\n$1\r\n1\r\n$-1\r\n$1\r\n3\r\n$1\r\n1\r\n",
+    );
+}
+
+#[test]
+fn hmget_missing_key_returns_nils() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["HMGET", "missing", "a", "b"]),
+        "*2\r\n$-1\r\n$-1\r\n",
+    );
+}
+
+#[test]
+fn hexists_field() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["HSET", "hash", "a", "1"]), ":1\r\n");
+    client.assert_command_response(&request(&["HEXISTS", "hash", "a"]), ":1\r\n");
+    client.assert_command_response(&request(&["HEXISTS", "hash", "b"]), ":0\r\n");
+    client.assert_command_response(&request(&["HEXISTS", "missing", "a"]), ":0\r\n");
+}
+
+#[test]
+fn hmget_and_hexists_on_other_types_return_wrong_type() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "string", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["HMGET", "string", "a"]), WRONG_TYPE);
+    client.assert_command_response(&request(&["HEXISTS", "string", "a"]), WRONG_TYPE);
+}
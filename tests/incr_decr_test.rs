@@ -0,0 +1,62 @@
+mod common;
+
+use crate::common::{request, start_client};
+
+// https://redis.io/docs/latest/commands/incr/
+// https://redis.io/docs/latest/commands/decr/
+
+#[test]
+fn incr_and_decr_missing_key_start_at_zero() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["INCR", "up"]), ":1\r\n");
+    client.assert_command_response(&request(&["INCR", "up"]), ":2\r\n");
+    client.assert_command_response(&request(&["GET", "up"]), "$1\r\n2\r\n");
+
+    client.assert_command_response(&request(&["DECR", "down"]), ":-1\r\n");
+    client.assert_command_response(&request(&["DECR", "down"]), ":-2\r\n");
+    client.assert_command_response(&request(&["GET", "down"]), "$2\r\n-2\r\n");
+}
+
+#[test]
+fn incr_existing_value_beyond_i32_range() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "big", "2147483647"]), "+OK\r\n");
+    client.assert_command_response(&request(&["INCR", "big"]), ":2147483648\r\n");
+
+    client.assert_command_response(&request(&["SET", "max", "9223372036854775806"]), "+OK\r\n");
+    client.assert_command_response(&request(&["INCR", "max"]), ":9223372036854775807\r\n");
+    client.assert_command_response(
+        &request(&["INCR", "max"]),
+        "-ERR value is not an integer or out of range\r\n",
+    );
+    client.assert_command_response(&request(&["GET", "max"]), "$19\r\n9223372036854775807\r\n");
+}
+
+#[test]
+fn incr_and_decr_non_integer_value() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "text", "abc"]), "+OK\r\n");
+    client.assert_command_response(
+        &request(&["INCR", "text"]),
+        "-ERR value is not an integer or out of range\r\n",
+    );
+    client.assert_command_response(
+        &request(&["DECR", "text"]),
+        "-ERR value is not an integer or out of range\r\n",
+    );
+    client.assert_command_response(&request(&["GET", "text"]), "$3\r\nabc\r\n");
+}
+
+#[test]
+fn incr_on_list_key_is_rejected() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["RPUSH", "list", "1"]), ":1\r\n");
+    client.assert_command_response(
+        &request(&["INCR", "list"]),
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+}
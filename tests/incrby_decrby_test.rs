@@ -0,0 +1,72 @@
+mod common;
+
+use crate::common::{request, start_client};
+
+// https://redis.io/docs/latest/commands/incrby/
+// https://redis.io/docs/latest/commands/decrby/
+
+const OUT_OF_RANGE: &str = "-ERR value is not an integer or out of range\r\n";
+
+#[test]
+fn incrby_and_decrby_positive_and_negative_steps() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["INCRBY", "counter", "10"]), ":10\r\n");
+    client.assert_command_response(&request(&["INCRBY", "counter", "-3"]), ":7\r\n");
+    client.assert_command_response(&request(&["DECRBY", "counter", "5"]), ":2\r\n");
+    client.assert_command_response(&request(&["DECRBY", "counter", "-8"]), ":10\r\n");
+    client.assert_command_response(&request(&["GET", "counter"]), "$2\r\n10\r\n");
+
+    client.assert_command_response(&request(&["DECRBY", "fresh", "4"]), ":-4\r\n");
+}
+
+#[test]
+fn incrby_and_decrby_overflow_is_rejected() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["SET", "counter", "9223372036854775800"]),
+        "+OK\r\n",
+    );
+    client.assert_command_response(&request(&["INCRBY", "counter", "100"]), OUT_OF_RANGE);
+    client.assert_command_response(
+        &request(&["GET", "counter"]),
+        "$19\r\n9223372036854775800\r\n",
+    );
+
+    client.assert_command_response(&request(&["SET", "low", "-9223372036854775800"]), "+OK\r\n");
+    client.assert_command_response(&request(&["DECRBY", "low", "100"]), OUT_OF_RANGE);
+
+    // Negating i64::MIN does not fit into i64
+    client.assert_command_response(
+        &request(&["DECRBY", "counter", "-9223372036854775808"]),
+        OUT_OF_RANGE,
+    );
+}
+
+#[test]
+fn incrby_invalid_increment_argument() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["INCRBY", "counter", "abc"]), OUT_OF_RANGE);
+    client.assert_command_response(&request(&["DECRBY", "counter", "1.5"]), OUT_OF_RANGE);
+    client.assert_command_response(
+        &request(&["INCRBY", "counter", "9223372036854775808"]),
+        OUT_OF_RANGE,
+    );
+    client.assert_command_response(&request(&["GET", "counter"]), "$-1\r\n");
+}
+
+#[test]
+fn incrby_wrong_type_stored_values() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "text", "abc"]), "+OK\r\n");
+    client.assert_command_response(&request(&["INCRBY", "text", "1"]), OUT_OF_RANGE);
+
+    client.assert_command_response(&request(&["RPUSH", "list", "1"]), ":1\r\n");
+    client.assert_command_response(
+        &request(&["DECRBY", "list", "1"]),
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use crate::common::{request, start_client};
+
+// https://redis.io/docs/latest/commands/incrbyfloat/
+// INCRBYFLOAT key increment
+
+const NOT_A_FLOAT: &str = "-ERR value is not a valid float\r\n";
+
+#[test]
+fn incrbyfloat_replies_with_bulk_string() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "mykey", "10.50"]), "+OK\r\n");
+    client.assert_command_response(&request(&["INCRBYFLOAT", "mykey", "0.1"]), "$4\r\n10.6\r\n");
+    client.assert_command_response(&request(&["INCRBYFLOAT", "mykey", "-5"]), "$3\r\n5.6\r\n");
+    client.assert_command_response(&request(&["GET", "mykey"]), "$3\r\n5.6\r\n");
+
+    // Missing key starts at 0
+    client.assert_command_response(
+        &request(&["INCRBYFLOAT", "fresh", "10.5"]),
+        "$4\r\n10.5\r\n",
+    );
+}
+
+#[test]
+fn incrbyfloat_formatting_edge_cases() {
+    let mut client = start_client();
+
+    // Exponent input, result without exponent and trailing zeros
+    client.assert_command_response(&request(&["SET", "mykey", "5.0e3"]), "+OK\r\n");
+    client.assert_command_response(
+        &request(&["INCRBYFLOAT", "mykey", "2.0e2"]),
+        "$4\r\n5200\r\n",
+    );
+    client.assert_command_response(&request(&["INCRBYFLOAT", "exp", "3.0e3"]), "$4\r\n3000\r\n");
+
+    // Integral result can be used by INCR afterwards
+    client.assert_command_response(&request(&["INCR", "exp"]), ":3001\r\n");
+
+    client.assert_command_response(
+        &request(&["INCRBYFLOAT", "neg", "-0.25"]),
+        "$5\r\n-0.25\r\n",
+    );
+}
+
+// Binary rounding noise doesn't show: 0.1 + 0.2 is 0.3, not 0.30000000000000004
+#[test]
+fn incrbyfloat_hides_rounding_noise() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["INCRBYFLOAT", "key", "0.1"]), "$3\r\n0.1\r\n");
+    client.assert_command_response(&request(&["INCRBYFLOAT", "key", "0.2"]), "$3\r\n0.3\r\n");
+    client.assert_command_response(&request(&["GET", "key"]), "$3\r\n0.3\r\n");
+
+    client.assert_command_response(
+        &request(&["INCRBYFLOAT", "precise", "1234567.89012345"]),
+        "$16\r\n1234567.89012345\r\n",
+    );
+}
+
+#[test]
+fn incrbyfloat_invalid_floats() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["INCRBYFLOAT", "mykey", "abc"]), NOT_A_FLOAT);
+    client.assert_command_response(&request(&["INCRBYFLOAT", "mykey", "nan"]), NOT_A_FLOAT);
+    client.assert_command_response(&request(&["INCRBYFLOAT", "mykey", "inf"]), NOT_A_FLOAT);
+
+    client.assert_command_response(&request(&["SET", "text", "abc"]), "+OK\r\n");
+    client.assert_command_response(&request(&["INCRBYFLOAT", "text", "1"]), NOT_A_FLOAT);
+
+    client.assert_command_response(
+        &request(&["SET", "huge", "1.7976931348623157e308"]),
+        "+OK\r\n",
+    );
+    client.assert_command_response(
+        &request(&["INCRBYFLOAT", "huge", "1.7976931348623157e308"]),
+        "-ERR increment would produce NaN or Infinity\r\n",
+    );
+
+    client.assert_command_response(&request(&["RPUSH", "list", "1"]), ":1\r\n");
+    client.assert_command_response(
+        &request(&["INCRBYFLOAT", "list", "1"]),
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use std::io::{Read, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::common::ValkyrieClientTest;
+
+// https://redis.io/docs/latest/commands/info/
+
+fn read_blocked_clients(client: &mut ValkyrieClientTest) -> usize {
+    client
+        .send(b"*2\r\n$4\r\nINFO\r\n$7\r\nclients\r\n")
+        .expect("send INFO");
+    let info = client.read_bulk_or_null().expect("INFO reply");
+
+    assert!(
+        info.starts_with("# Clients\r\n"),
+        "unexpected INFO: {info:?}"
+    );
+
+    info.lines()
+        .find_map(|line| line.strip_prefix("blocked_clients:"))
+        .expect("blocked_clients field")
+        .trim()
+        .parse()
+        .expect("blocked_clients is a number")
+}
+
+fn wait_for_blocked_clients(client: &mut ValkyrieClientTest, expected: usize) {
+    let start = Instant::now();
+    loop {
+        let actual = read_blocked_clients(client);
+        if actual == expected {
+            return;
+        }
+        assert!(
+            start.elapsed() < Duration::from_secs(2),
+            "blocked_clients stayed at {actual}, expected {expected}"
+        );
+        thread::sleep(Duration::from_millis(20));
+    }
+}
+
+#[test]
+fn info_reports_blocked_clients() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+
+    // Connection that will block on BLPOP
+    let mut blocked = server.connect().expect("connect blocked client");
+
+    let mut client = ValkyrieClientTest::new(server);
+    assert_eq!(read_blocked_clients(&mut client), 0);
+
+    // BLPOP mylist 0 (block indefinitely)
+    blocked
+        .write_all(b"*3\r\n$5\r\nBLPOP\r\n$6\r\nmylist\r\n$1\r\n0\r\n")
+        .expect("write blpop");
+
+    wait_for_blocked_clients(&mut client, 1);
+
+    // Unblock it and make sure the gauge goes back to 0
+    client.assert_command_response("*3\r\n$5\r\nRPUSH\r\n$6\r\nmylist\r\n$1\r\nv\r\n", ":1\r\n");
+
+    let expected = "*2\r\n$6\r\nmylist\r\n$1\r\nv\r\n";
+    let mut reply = vec![0u8; expected.len()];
+    blocked.read_exact(&mut reply).expect("read blpop reply");
+    assert_eq!(reply, expected.as_bytes());
+
+    wait_for_blocked_clients(&mut client, 0);
+}
+
+#[test]
+fn info_blocked_clients_decremented_on_timeout() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    // BLPOP with 50ms timeout returns Null Array
+    client.assert_command_response("*3\r\n$5\r\nBLPOP\r\n$1\r\nl\r\n$4\r\n0.05\r\n", "*-1\r\n");
+
+    assert_eq!(read_blocked_clients(&mut client), 0);
+}
+
+#[test]
+fn info_unknown_section_is_empty() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response("*2\r\n$4\r\nINFO\r\n$7\r\nunknown\r\n", "$0\r\n\r\n");
+}
@@ -0,0 +1,46 @@
+mod common;
+
+use crate::common::ValkyrieClientTest;
+
+// --initial-keyspace-size <n> pre-sizes every shard map with n / shards entries
+
+const KEYS: usize = 5_000;
+
+fn set_request(key: &str, value: &str) -> String {
+    format!(
+        "*3\r\n$3\r\nSET\r\n${}\r\n{}\r\n${}\r\n{}\r\n",
+        key.len(),
+        key,
+        value.len(),
+        value
+    )
+}
+
+#[test]
+fn bulk_load_with_preallocated_keyspace() {
+    let server = common::ValkyrieServerTest::start_with_args(
+        2,
+        3,
+        &["--initial-keyspace-size", &KEYS.to_string()],
+    )
+    .expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    // Pipeline the whole load in one write, then read every reply
+    let mut pipeline = String::new();
+    for i in 0..KEYS {
+        pipeline.push_str(&set_request(&format!("key:{i}"), &format!("value:{i}")));
+    }
+    client.send(pipeline.as_bytes()).expect("send pipeline");
+
+    for _ in 0..KEYS {
+        assert_eq!(client.read_simple_string_or_null().as_deref(), Some("OK"));
+    }
+
+    for i in [0, 1, KEYS / 2, KEYS - 1] {
+        let key = format!("key:{i}");
+        let get_req = format!("*2\r\n$3\r\nGET\r\n${}\r\n{}\r\n", key.len(), key);
+        client.send(get_req.as_bytes()).expect("send GET");
+        assert_eq!(client.read_bulk_or_null(), Some(format!("value:{i}")));
+    }
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use std::io::{Read, Write};
+
+use crate::common::start_client;
+
+// Inline commands: space separated arguments on a single line, as sent by telnet or nc
+// https://redis.io/docs/latest/develop/reference/protocol-spec/#inline-commands
+
+#[test]
+fn inline_ping_and_echo() {
+    let mut client = start_client();
+
+    client.assert_command_response("PING\r\n", "+PONG\r\n");
+    client.assert_command_response("ping\r\n", "+PONG\r\n");
+    client.assert_command_response("ECHO hi\r\n", "$2\r\nhi\r\n");
+}
+
+#[test]
+fn inline_quoted_argument() {
+    let mut client = start_client();
+
+    client.assert_command_response("SET greeting \"hello world\"\r\n", "+OK\r\n");
+    client.assert_command_response(
+        "*2\r\n$3\r\nGET\r\n$8\r\ngreeting\r\n",
+        "$11\r\nhello world\r\n",
+    );
+    client.assert_command_response("GET greeting\r\n", "$11\r\nhello world\r\n");
+}
+
+#[test]
+fn inline_and_resp_requests_pipelined() {
+    let mut client = start_client();
+
+    // The blank line gets no reply
+    client.assert_command_response(
+        "PING\r\n\r\n*2\r\n$4\r\nECHO\r\n$3\r\nabc\r\nECHO def\r\n",
+        "+PONG\r\n$3\r\nabc\r\n$3\r\ndef\r\n",
+    );
+}
+
+#[test]
+fn inline_unbalanced_quotes_close_the_connection() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut stream = server.connect().expect("connect to server");
+
+    stream.write_all(b"ECHO \"hi\r\n").expect("send");
+    let mut reply = String::new();
+    stream.read_to_string(&mut reply).expect("read until close");
+    assert_eq!(
+        reply,
+        "-ERR Protocol error: unbalanced quotes in request\r\n"
+    );
+}
@@ -0,0 +1,63 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request};
+
+// https://redis.io/docs/latest/commands/lindex/
+
+fn start_client_with_list() -> ValkyrieClientTest {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(&request(&["RPUSH", "list", "a", "b", "c"]), ":3\r\n");
+    client
+}
+
+#[test]
+fn lindex_positive_index() {
+    let mut client = start_client_with_list();
+
+    client.assert_command_response(&request(&["LINDEX", "list", "0"]), "$1\r\na\r\n");
+    client.assert_command_response(&request(&["LINDEX", "list", "2"]), "$1\r\nc\r\n");
+}
+
+#[test]
+fn lindex_negative_index() {
+    let mut client = start_client_with_list();
+
+    client.assert_command_response(&request(&["LINDEX", "list", "-1"]), "$1\r\nc\r\n");
+    client.assert_command_response(&request(&["LINDEX", "list", "-3"]), "$1\r\na\r\n");
+}
+
+#[test]
+fn lindex_out_of_range() {
+    let mut client = start_client_with_list();
+
+    client.assert_command_response(&request(&["LINDEX", "list", "3"]), "$-1\r\n");
+    client.assert_command_response(&request(&["LINDEX", "list", "-4"]), "$-1\r\n");
+    client.assert_command_response(&request(&["LINDEX", "missing", "0"]), "$-1\r\n");
+}
+
+#[test]
+fn lindex_wrong_type() {
+    let mut client = start_client_with_list();
+
+    client.assert_command_response(&request(&["SET", "skey", "sval"]), "+OK\r\n");
+    client.assert_command_response(
+        &request(&["LINDEX", "skey", "0"]),
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+}
+
+#[test]
+fn lindex_invalid_arguments() {
+    let mut client = start_client_with_list();
+
+    client.assert_command_response(
+        &request(&["LINDEX", "list", "one"]),
+        "-ERR value is not an integer or out of range\r\n",
+    );
+    client.assert_command_response(
+        &request(&["LINDEX", "list"]),
+        "-Wrong number of arguments for LINDEX command\r\n",
+    );
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use crate::common::ValkyrieClientTest;
+
+// Commands of every other type must reject a stream value with WRONGTYPE, while TYPE and
+// OBJECT ENCODING report it as a stream. A stream key is used as the non-string, non-list value.
+
+fn start_client_with_stream_key() -> ValkyrieClientTest {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    // XADD stream 1-1 field value
+    client.assert_command_response(
+        "*5\r\n$4\r\nXADD\r\n$6\r\nstream\r\n$3\r\n1-1\r\n$5\r\nfield\r\n$5\r\nvalue\r\n",
+        "$3\r\n1-1\r\n",
+    );
+
+    client
+}
+
+#[test]
+fn list_reads_on_stream_key_return_wrongtype() {
+    let mut client = start_client_with_stream_key();
+
+    client.assert_command_response(
+        "*2\r\n$4\r\nLLEN\r\n$6\r\nstream\r\n",
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+    client.assert_command_response(
+        "*4\r\n$6\r\nLRANGE\r\n$6\r\nstream\r\n$1\r\n0\r\n$2\r\n-1\r\n",
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+}
+
+#[test]
+fn list_mutations_on_stream_key_return_wrongtype() {
+    let mut client = start_client_with_stream_key();
+
+    client.assert_command_response(
+        "*2\r\n$4\r\nLPOP\r\n$6\r\nstream\r\n",
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+    client.assert_command_response(
+        "*3\r\n$5\r\nLPUSH\r\n$6\r\nstream\r\n$1\r\na\r\n",
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+    client.assert_command_response(
+        "*3\r\n$5\r\nRPUSH\r\n$6\r\nstream\r\n$1\r\na\r\n",
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+    client.assert_command_response(
+        "*3\r\n$5\r\nBLPOP\r\n$6\r\nstream\r\n$1\r\n1\r\n",
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+
+    // The stream is left untouched
+    client.assert_command_response("*2\r\n$4\r\nXLEN\r\n$6\r\nstream\r\n", ":1\r\n");
+}
+
+#[test]
+fn string_read_on_stream_key_returns_wrongtype() {
+    let mut client = start_client_with_stream_key();
+
+    client.assert_command_response(
+        "*2\r\n$3\r\nGET\r\n$6\r\nstream\r\n",
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+}
+
+#[test]
+fn type_and_object_encoding_report_stream() {
+    let mut client = start_client_with_stream_key();
+
+    client.assert_command_response("*2\r\n$4\r\nTYPE\r\n$6\r\nstream\r\n", "+stream\r\n");
+    client.assert_command_response(
+        "*3\r\n$6\r\nOBJECT\r\n$8\r\nENCODING\r\n$6\r\nstream\r\n",
+        "$6\r\nstream\r\n",
+    );
+    client.assert_command_response(
+        "*3\r\n$6\r\nOBJECT\r\n$8\r\nENCODING\r\n$7\r\nmissing\r\n",
+        "$-1\r\n",
+    );
+}
@@ -55,9 +55,12 @@ fn llen_on_string_key_fails() {
     );
     client_test.assert_command_response(&set_req, "+OK\r\n");
 
-    // LLEN skey -> error "'skey' is not a list."
+    // LLEN skey -> WRONGTYPE error
     let llen_req = "*2\r\n$4\r\nLLEN\r\n$4\r\nskey\r\n";
-    client_test.assert_command_response(llen_req, "-'skey' is not a list.\r\n");
+    client_test.assert_command_response(
+        llen_req,
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
 }
 
 // Error: not enough arguments
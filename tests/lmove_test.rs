@@ -0,0 +1,167 @@
+mod common;
+
+use crate::common::{request, start_client};
+
+// https://redis.io/docs/latest/commands/lmove/
+
+const WRONG_TYPE: &str = "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n";
+
+/// Destination key for `source` that lands on the same shard, or on another one when `same_shard` is false.
+/// With a single shard every key pair is a same-shard pair.
+fn destination_for(source: &str, shards: usize, same_shard: bool) -> String {
+    let source_shard = common::shard_for_key(source, shards);
+    (0..)
+        .map(|i| format!("dst:{i}"))
+        .find(|key| {
+            (common::shard_for_key(key, shards) == source_shard) == (same_shard || shards == 1)
+        })
+        .expect("destination key")
+}
+
+/// Moves one element of [a, b] into [x] and checks the reply and the resulting destination.
+fn assert_lmove(from: &str, to: &str, moved: &str, destination_after: &[&str]) {
+    let mut client = start_client();
+    let destination = destination_for("src", common::effective_shards(3), false);
+
+    client.assert_command_response(&request(&["RPUSH", "src", "a", "b"]), ":2\r\n");
+    client.assert_command_response(&request(&["RPUSH", &destination, "x"]), ":1\r\n");
+
+    client.assert_command_response(
+        &request(&["LMOVE", "src", &destination, from, to]),
+        &format!("${}\r\n{moved}\r\n", moved.len()),
+    );
+
+    let mut expected = format!("*{}\r\n", destination_after.len());
+    for value in destination_after {
+        expected.push_str(&format!("${}\r\n{value}\r\n", value.len()));
+    }
+    client.assert_command_response(&request(&["LRANGE", &destination, "0", "-1"]), &expected);
+    client.assert_command_response(&request(&["LLEN", "src"]), ":1\r\n");
+}
+
+#[test]
+fn lmove_left_left() {
+    assert_lmove("LEFT", "LEFT", "a", &["a", "x"]);
+}
+
+#[test]
+fn lmove_left_right() {
+    assert_lmove("LEFT", "RIGHT", "a", &["x", "a"]);
+}
+
+#[test]
+fn lmove_right_left() {
+    assert_lmove("RIGHT", "LEFT", "b", &["b", "x"]);
+}
+
+#[test]
+fn lmove_right_right() {
+    // Directions are case-insensitive
+    assert_lmove("right", "right", "b", &["x", "b"]);
+}
+
+#[test]
+fn lmove_same_key_rotates_list() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["RPUSH", "list", "a", "b", "c"]), ":3\r\n");
+
+    client.assert_command_response(
+        &request(&["LMOVE", "list", "list", "LEFT", "RIGHT"]),
+        "$1\r\na\r\n",
+    );
+    client.assert_command_response(
+        &request(&["LRANGE", "list", "0", "-1"]),
+        "*3\r\n$1\r\nb\r\n$1\r\nc\r\n$1\r\na\r\n",
+    );
+
+    client.assert_command_response(
+        &request(&["LMOVE", "list", "list", "RIGHT", "LEFT"]),
+        "$1\r\na\r\n",
+    );
+    client.assert_command_response(
+        &request(&["LRANGE", "list", "0", "-1"]),
+        "*3\r\n$1\r\na\r\n$1\r\nb\r\n$1\r\nc\r\n",
+    );
+
+    // Single element list stays in place
+    client.assert_command_response(&request(&["RPUSH", "single", "a"]), ":1\r\n");
+    client.assert_command_response(
+        &request(&["LMOVE", "single", "single", "LEFT", "LEFT"]),
+        "$1\r\na\r\n",
+    );
+    client.assert_command_response(
+        &request(&["LRANGE", "single", "0", "-1"]),
+        "*1\r\n$1\r\na\r\n",
+    );
+}
+
+#[test]
+fn lmove_missing_source_returns_nil() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["LMOVE", "missing", "dst", "LEFT", "RIGHT"]),
+        "$-1\r\n",
+    );
+    client.assert_command_response(&request(&["EXISTS", "dst"]), ":0\r\n");
+}
+
+#[test]
+fn lmove_wrong_type_keeps_source() {
+    let mut client = start_client();
+    let destination = destination_for("src", common::effective_shards(3), false);
+
+    client.assert_command_response(&request(&["RPUSH", "src", "a", "b"]), ":2\r\n");
+    client.assert_command_response(&request(&["SET", &destination, "string"]), "+OK\r\n");
+
+    client.assert_command_response(
+        &request(&["LMOVE", "src", &destination, "LEFT", "RIGHT"]),
+        WRONG_TYPE,
+    );
+    // Source is left as it was
+    client.assert_command_response(
+        &request(&["LRANGE", "src", "0", "-1"]),
+        "*2\r\n$1\r\na\r\n$1\r\nb\r\n",
+    );
+
+    client.assert_command_response(
+        &request(&["LMOVE", &destination, "src", "LEFT", "RIGHT"]),
+        WRONG_TYPE,
+    );
+}
+
+// Destination is checked first: the last element of source isn't popped, so source keeps its TTL
+#[test]
+fn lmove_wrong_type_destination_leaves_source_untouched() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["RPUSH", "src", "a"]), ":1\r\n");
+    client.assert_command_response(&request(&["EXPIRE", "src", "100"]), ":1\r\n");
+    client.assert_command_response(&request(&["SET", "dst", "string"]), "+OK\r\n");
+
+    client.assert_command_response(
+        &request(&["LMOVE", "src", "dst", "RIGHT", "LEFT"]),
+        WRONG_TYPE,
+    );
+    client.assert_command_response(&request(&["LRANGE", "src", "0", "-1"]), "*1\r\n$1\r\na\r\n");
+    client.assert_command_response(&request(&["TTL", "src"]), ":100\r\n");
+
+    // A missing source is nil whatever the destination holds
+    client.assert_command_response(
+        &request(&["LMOVE", "missing", "dst", "LEFT", "LEFT"]),
+        "$-1\r\n",
+    );
+}
+
+#[test]
+fn lmove_invalid_direction() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["RPUSH", "src", "a"]), ":1\r\n");
+    client.assert_command_response(
+        &request(&["LMOVE", "src", "dst", "UP", "LEFT"]),
+        "-ERR syntax error\r\n",
+    );
+    client.assert_command_response(&request(&["LLEN", "src"]), ":1\r\n");
+}
@@ -0,0 +1,57 @@
+use std::io::{BufRead, BufReader};
+use std::net::TcpListener;
+use std::process::{Command as StdCommand, Stdio};
+
+use assert_cmd::cargo::{self};
+
+fn free_address() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind ephemeral port");
+    let port = listener.local_addr().expect("local address").port();
+    format!("127.0.0.1:{port}")
+}
+
+#[test]
+fn json_log_format_emits_json_lines_on_stderr() {
+    let bin_path = cargo::cargo_bin!("valkyrie");
+    let mut child = StdCommand::new(bin_path)
+        .arg("--address")
+        .arg(free_address())
+        .arg("--log-format")
+        .arg("json")
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("spawn server");
+
+    let stderr = child.stderr.take().expect("server stderr");
+    let mut line = String::new();
+    let read = BufReader::new(stderr).read_line(&mut line);
+
+    let _ = child.kill();
+    let _ = child.wait();
+
+    assert!(read.expect("read startup log line") > 0, "No log output");
+    let event: serde_json::Value = serde_json::from_str(&line)
+        .unwrap_or_else(|err| panic!("Log line is not JSON ({err}): {line:?}"));
+
+    assert_eq!(event["level"], "INFO");
+    let message = event["fields"]["message"].as_str().expect("message field");
+    assert!(
+        message.starts_with("StartupArguments:"),
+        "Unexpected first log line: {message:?}"
+    );
+}
+
+#[test]
+fn unknown_log_format_is_rejected() {
+    let bin_path = cargo::cargo_bin!("valkyrie");
+    let status = StdCommand::new(bin_path)
+        .arg("--log-format")
+        .arg("xml")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .expect("run server");
+
+    assert!(!status.success());
+}
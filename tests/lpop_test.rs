@@ -146,5 +146,8 @@ fn lpop_on_string_key_fails() {
 
     // LPOP skey -> error
     let lpop_req = "*2\r\n$4\r\nLPOP\r\n$4\r\nskey\r\n";
-    client.assert_command_response(lpop_req, "-'skey' is not a list.\r\n");
+    client.assert_command_response(
+        lpop_req,
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
 }
@@ -90,9 +90,9 @@ fn lpush_on_existing_list_increases_length() {
     client_test.assert_command_response(req2, ":3\r\n");
 }
 
-// Interop: GET on a list key should return Null Bulk String (lists are not returned by GET)
+// Interop: GET on a list key is a WRONGTYPE error (lists are not returned by GET)
 #[test]
-fn lpush_then_get_returns_null_bulk_string() {
+fn lpush_then_get_returns_wrongtype() {
     let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
     let mut client_test = ValkyrieClientTest::new(server);
 
@@ -100,9 +100,12 @@ fn lpush_then_get_returns_null_bulk_string() {
     let req = "*3\r\n$5\r\nLPUSH\r\n$4\r\nlkey\r\n$2\r\nv1\r\n";
     client_test.assert_command_response(req, ":1\r\n");
 
-    // GET lkey -> $-1
+    // GET lkey -> WRONGTYPE
     let get_req = "*2\r\n$3\r\nGET\r\n$4\r\nlkey\r\n";
-    client_test.assert_command_response(get_req, "$-1\r\n");
+    client_test.assert_command_response(
+        get_req,
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
 }
 
 // Error: not enough arguments (requires at least key and one value)
@@ -182,7 +185,7 @@ fn lpush_on_string_key_fails() {
     let lpush_req = "*3\r\n$5\r\nLPUSH\r\n$4\r\nskey\r\n$2\r\nv1\r\n";
     client_test.assert_command_response(
         lpush_req,
-        "-Can't execute Left Push for a String value, should be List\r\n",
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
     );
 }
 
@@ -35,7 +35,10 @@ fn lrange_on_string_key_fails() {
 
     // LRANGE skey 0 -1 -> error
     let lrange_req = "*4\r\n$6\r\nLRANGE\r\n$4\r\nskey\r\n$1\r\n0\r\n$2\r\n-1\r\n";
-    client_test.assert_command_response(lrange_req, "-'skey' is not a list.\r\n");
+    client_test.assert_command_response(
+        lrange_req,
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
 }
 
 // Happy path: typical ranges (positive, negative, out-of-bounds)
@@ -73,6 +76,29 @@ fn lrange_basic_ranges() {
     client_test.assert_command_response(req_neg, resp_neg);
 }
 
+// Inner windows: exactly end - start + 1 elements, even when start > 0
+#[test]
+fn lrange_inner_window_returns_exact_count() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client_test = ValkyrieClientTest::new(server);
+
+    // Prepare: RPUSH list a b c d e  -> 5
+    let push_req = "*7\r\n$5\r\nRPUSH\r\n$4\r\nlist\r\n$1\r\na\r\n$1\r\nb\r\n$1\r\nc\r\n$1\r\nd\r\n$1\r\ne\r\n";
+    client_test.assert_command_response(push_req, ":5\r\n");
+
+    // LRANGE list 1 2 -> [b, c]
+    let req_1_2 = "*4\r\n$6\r\nLRANGE\r\n$4\r\nlist\r\n$1\r\n1\r\n$1\r\n2\r\n";
+    client_test.assert_command_response(req_1_2, "*2\r\n$1\r\nb\r\n$1\r\nc\r\n");
+
+    // LRANGE list 2 3 -> [c, d]
+    let req_2_3 = "*4\r\n$6\r\nLRANGE\r\n$4\r\nlist\r\n$1\r\n2\r\n$1\r\n3\r\n";
+    client_test.assert_command_response(req_2_3, "*2\r\n$1\r\nc\r\n$1\r\nd\r\n");
+
+    // LRANGE list 3 3 -> [d]
+    let req_3_3 = "*4\r\n$6\r\nLRANGE\r\n$4\r\nlist\r\n$1\r\n3\r\n$1\r\n3\r\n";
+    client_test.assert_command_response(req_3_3, "*1\r\n$1\r\nd\r\n");
+}
+
 // Error: not enough arguments
 #[test]
 fn lrange_not_enough_arguments() {
@@ -81,11 +107,17 @@ fn lrange_not_enough_arguments() {
 
     // LRANGE (no args)
     let req0 = "*1\r\n$6\r\nLRANGE\r\n";
-    client_test.assert_command_response(req0, "-Not enough arguments for LRANGE command\r\n");
+    client_test.assert_command_response(
+        req0,
+        "-ERR wrong number of arguments for 'lrange' command\r\n",
+    );
 
     // LRANGE key start (missing end)
     let req1 = "*3\r\n$6\r\nLRANGE\r\n$3\r\nkey\r\n$1\r\n0\r\n";
-    client_test.assert_command_response(req1, "-Not enough arguments for LRANGE command\r\n");
+    client_test.assert_command_response(
+        req1,
+        "-ERR wrong number of arguments for 'lrange' command\r\n",
+    );
 }
 
 // Error: incorrect parameter types and parsing errors
@@ -98,22 +130,20 @@ fn lrange_wrong_types_and_parse_errors() {
     let wrong_key = "*4\r\n$6\r\nLRANGE\r\n:1\r\n$1\r\n0\r\n$2\r\n-1\r\n";
     client_test.assert_command_response(
         wrong_key,
-        "-LRANGE incorrect parameter types, expected BulkString, BulkString, BulkString\r\n",
+        "-ERR invalid argument 'key' for 'lrange' command, expected a bulk string\r\n",
     );
 
     // start is not an integer (parse error)
     let bad_start = "*4\r\n$6\r\nLRANGE\r\n$2\r\nmy\r\n$1\r\na\r\n$2\r\n-1\r\n";
     client_test.assert_command_response(
         bad_start,
-        "-Failed to parse LRANGE start parameter 'a' as integer\r\n",
+        "-ERR value is not an integer or out of range\r\n",
     );
 
     // end is not an integer (parse error)
     let bad_end = "*4\r\n$6\r\nLRANGE\r\n$2\r\nmy\r\n$1\r\n0\r\n$1\r\nb\r\n";
-    client_test.assert_command_response(
-        bad_end,
-        "-Failed to parse LRANGE end parameter 'b' as integer\r\n",
-    );
+    client_test
+        .assert_command_response(bad_end, "-ERR value is not an integer or out of range\r\n");
 }
 
 // Command name is case-insensitive
@@ -135,3 +165,47 @@ fn lrange_case_insensitive_command_name() {
     let req_mixed = "*4\r\n$6\r\nLRange\r\n$4\r\nlist\r\n$1\r\n0\r\n$2\r\n-1\r\n";
     client_test.assert_command_response(req_mixed, resp_all);
 }
+
+// Large replies are written with vectored I/O, the bytes on the wire must be unchanged
+#[test]
+fn lrange_large_list_returns_every_element() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client_test = ValkyrieClientTest::new(server);
+
+    let values: Vec<String> = (0..500).map(|i| format!("value-{i}")).collect();
+
+    let mut rpush_req = format!("*{}\r\n$5\r\nRPUSH\r\n$7\r\nbiglist\r\n", values.len() + 2);
+    let mut expected = format!("*{}\r\n", values.len());
+    for value in &values {
+        let bulk = format!("${}\r\n{}\r\n", value.len(), value);
+        rpush_req.push_str(&bulk);
+        expected.push_str(&bulk);
+    }
+    client_test.assert_command_response(&rpush_req, &format!(":{}\r\n", values.len()));
+
+    // LRANGE biglist 0 -1
+    let lrange_req = "*4\r\n$6\r\nLRANGE\r\n$7\r\nbiglist\r\n$1\r\n0\r\n$2\r\n-1\r\n";
+    client_test.assert_command_response(lrange_req, &expected);
+}
+
+// Indexes are 64-bit, values beyond the i32 range are clamped like any out of range index
+#[test]
+fn lrange_indexes_beyond_i32_range() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client_test = ValkyrieClientTest::new(server);
+
+    client_test.assert_command_response(
+        "*5\r\n$5\r\nRPUSH\r\n$4\r\nlist\r\n$1\r\na\r\n$1\r\nb\r\n$1\r\nc\r\n",
+        ":3\r\n",
+    );
+
+    let resp_all = "*3\r\n$1\r\na\r\n$1\r\nb\r\n$1\r\nc\r\n";
+    client_test.assert_command_response(
+        "*4\r\n$6\r\nLRANGE\r\n$4\r\nlist\r\n$11\r\n-5000000000\r\n$10\r\n5000000000\r\n",
+        resp_all,
+    );
+    client_test.assert_command_response(
+        "*4\r\n$6\r\nLRANGE\r\n$4\r\nlist\r\n$10\r\n2147483648\r\n$2\r\n-1\r\n",
+        "*0\r\n",
+    );
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request};
+
+// https://redis.io/docs/latest/commands/lrem/
+
+fn array(values: &[&str]) -> String {
+    let mut resp = format!("*{}\r\n", values.len());
+    for value in values {
+        resp.push_str(&format!("${}\r\n{}\r\n", value.len(), value));
+    }
+    resp
+}
+
+fn start_client_with_list() -> ValkyrieClientTest {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(
+        &request(&["RPUSH", "list", "x", "a", "x", "b", "x", "c", "x"]),
+        ":7\r\n",
+    );
+    client
+}
+
+#[test]
+fn lrem_positive_count_removes_from_head() {
+    let mut client = start_client_with_list();
+
+    client.assert_command_response(&request(&["LREM", "list", "2", "x"]), ":2\r\n");
+    client.assert_command_response(
+        &request(&["LRANGE", "list", "0", "-1"]),
+        &array(&["a", "b", "x", "c", "x"]),
+    );
+}
+
+#[test]
+fn lrem_negative_count_removes_from_tail() {
+    let mut client = start_client_with_list();
+
+    client.assert_command_response(&request(&["LREM", "list", "-2", "x"]), ":2\r\n");
+    client.assert_command_response(
+        &request(&["LRANGE", "list", "0", "-1"]),
+        &array(&["x", "a", "x", "b", "c"]),
+    );
+}
+
+#[test]
+fn lrem_zero_count_removes_all() {
+    let mut client = start_client_with_list();
+
+    client.assert_command_response(&request(&["LREM", "list", "0", "x"]), ":4\r\n");
+    client.assert_command_response(
+        &request(&["LRANGE", "list", "0", "-1"]),
+        &array(&["a", "b", "c"]),
+    );
+
+    // Nothing left to remove
+    client.assert_command_response(&request(&["LREM", "list", "0", "x"]), ":0\r\n");
+}
+
+#[test]
+fn lrem_count_larger_than_matches() {
+    let mut client = start_client_with_list();
+
+    client.assert_command_response(&request(&["LREM", "list", "10", "x"]), ":4\r\n");
+    client.assert_command_response(&request(&["LREM", "list", "-10", "a"]), ":1\r\n");
+    client.assert_command_response(&request(&["LLEN", "list"]), ":2\r\n");
+}
+
+#[test]
+fn lrem_removes_emptied_list() {
+    let mut client = start_client_with_list();
+
+    client.assert_command_response(&request(&["RPUSH", "single", "v", "v"]), ":2\r\n");
+    client.assert_command_response(&request(&["LREM", "single", "0", "v"]), ":2\r\n");
+    client.assert_command_response(&request(&["EXISTS", "single"]), ":0\r\n");
+}
+
+#[test]
+fn lrem_missing_key_and_wrong_type() {
+    let mut client = start_client_with_list();
+
+    client.assert_command_response(&request(&["LREM", "missing", "0", "x"]), ":0\r\n");
+
+    client.assert_command_response(&request(&["SET", "skey", "x"]), "+OK\r\n");
+    client.assert_command_response(
+        &request(&["LREM", "skey", "0", "x"]),
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+}
+
+#[test]
+fn lrem_invalid_count() {
+    let mut client = start_client_with_list();
+
+    client.assert_command_response(
+        &request(&["LREM", "list", "many", "x"]),
+        "-ERR value is not an integer or out of range\r\n",
+    );
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request};
+
+// https://redis.io/docs/latest/commands/mset/
+// https://redis.io/docs/latest/commands/mget/
+
+fn bulk(value: &str) -> String {
+    format!("${}\r\n{}\r\n", value.len(), value)
+}
+
+/// Keys interleaved across every shard: consecutive keys never share a shard (when there is more than one).
+fn keys_across_shards(shards: usize, per_shard: usize) -> Vec<String> {
+    let mut buckets: Vec<Vec<String>> = vec![Vec::new(); shards];
+    let mut i = 0;
+    while buckets.iter().any(|bucket| bucket.len() < per_shard) {
+        let key = format!("key:{i}");
+        let bucket = &mut buckets[common::shard_for_key(&key, shards)];
+        if bucket.len() < per_shard {
+            bucket.push(key);
+        }
+        i += 1;
+    }
+
+    (0..per_shard)
+        .flat_map(|idx| buckets.iter().map(move |bucket| bucket[idx].clone()))
+        .collect()
+}
+
+#[test]
+fn mset_then_mget_keeps_argument_order_across_shards() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let shards = common::effective_shards(3);
+    let mut client = ValkyrieClientTest::new(server);
+
+    let keys = keys_across_shards(shards, 3);
+    let values: Vec<String> = keys.iter().map(|key| format!("value-of-{key}")).collect();
+
+    let mut mset = vec!["MSET"];
+    for (key, value) in keys.iter().zip(&values) {
+        mset.push(key);
+        mset.push(value);
+    }
+    client.assert_command_response(&request(&mset), "+OK\r\n");
+
+    // Reverse order, to make sure replies follow the arguments rather than the shards
+    let mut mget = vec!["MGET"];
+    mget.extend(keys.iter().rev().map(String::as_str));
+    let expected: String = values.iter().rev().map(|value| bulk(value)).collect();
+    client.assert_command_response(&request(&mget), &format!("*{}\r\n{expected}", values.len()));
+
+    // Every key is also visible through a plain GET
+    for (key, value) in keys.iter().zip(&values) {
+        client.assert_command_response(&request(&["GET", key]), &bulk(value));
+    }
+}
+
+#[test]
+fn mget_missing_and_non_string_keys_are_nil() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(&request(&["SET", "a", "1"]), "+OK\r\n");
+    client.assert_command_response(&request(&["RPUSH", "list", "x"]), ":1\r\n");
+
+    client.assert_command_response(
+        &request(&["MGET", "a", "missing", "list", "a"]),
+        "*4\r\n$1\r\n1\r\n$-1\r\n$-1\r\n$1\r\n1\r\n",
+    );
+}
+
+#[test]
+fn mset_repeated_key_keeps_last_value_and_overwrites_lists() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(&request(&["RPUSH", "list", "x"]), ":1\r\n");
+    client.assert_command_response(
+        &request(&["MSET", "k", "first", "list", "now-a-string", "k", "last"]),
+        "+OK\r\n",
+    );
+
+    client.assert_command_response(
+        &request(&["MGET", "k", "list"]),
+        "*2\r\n$4\r\nlast\r\n$12\r\nnow-a-string\r\n",
+    );
+}
+
+#[test]
+fn mset_wrong_number_of_arguments() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(
+        &request(&["MSET", "k", "v", "dangling"]),
+        "-Wrong number of arguments for MSET command\r\n",
+    );
+    client.assert_command_response(&request(&["GET", "k"]), "$-1\r\n");
+}
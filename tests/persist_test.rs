@@ -0,0 +1,41 @@
+mod common;
+
+use crate::common::{request, start_client};
+use std::thread;
+use std::time::Duration;
+
+// https://redis.io/docs/latest/commands/persist/
+
+#[test]
+fn persist_removes_expiration() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "key", "value", "PX", "200"]), "+OK\r\n");
+    client.assert_command_response(&request(&["PERSIST", "key"]), ":1\r\n");
+    client.assert_command_response(&request(&["TTL", "key"]), ":-1\r\n");
+
+    thread::sleep(Duration::from_millis(400));
+
+    client.assert_command_response(&request(&["GET", "key"]), "$5\r\nvalue\r\n");
+}
+
+#[test]
+fn persist_without_expiration_returns_zero() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["PERSIST", "missing"]), ":0\r\n");
+
+    client.assert_command_response(&request(&["SET", "key", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["PERSIST", "key"]), ":0\r\n");
+    client.assert_command_response(&request(&["GET", "key"]), "$5\r\nvalue\r\n");
+}
+
+#[test]
+fn persist_wrong_number_of_arguments() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["PERSIST"]),
+        "-Wrong number of arguments for PERSIST command\r\n",
+    );
+}
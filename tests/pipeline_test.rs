@@ -0,0 +1,96 @@
+mod common;
+
+use std::io::{BufRead, BufReader, Write};
+use std::time::Duration;
+
+use crate::common::{ValkyrieClientTest, request};
+
+// Pipelined commands are executed in order and their replies are written out together
+
+#[test]
+fn hundred_pings_in_one_write() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client
+        .send(request(&["PING"]).repeat(100).as_bytes())
+        .expect("send pipeline");
+
+    for i in 0..100 {
+        assert_eq!(
+            client.read_line().expect("read PONG"),
+            "+PONG\r\n",
+            "reply {i}"
+        );
+    }
+}
+
+// Replies larger than the output buffer, and vectored ones, keep their place in the pipeline
+#[test]
+fn large_replies_keep_pipeline_order() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    let value = "x".repeat(10_000);
+    client.assert_command_response(&request(&["SET", "big", &value]), "+OK\r\n");
+    let big_value = "y".repeat(32 * 1024);
+    client.assert_command_response(&request(&["SET", "bigger", &big_value]), "+OK\r\n");
+
+    let mut rpush = vec!["RPUSH", "list"];
+    let items: Vec<String> = (0..100).map(|i| i.to_string()).collect();
+    rpush.extend(items.iter().map(String::as_str));
+    client.assert_command_response(&request(&rpush), ":100\r\n");
+
+    let mut pipeline = String::new();
+    for i in 0..20 {
+        pipeline.push_str(&request(&["ECHO", &i.to_string()]));
+        pipeline.push_str(&request(&["GET", "big"]));
+    }
+    pipeline.push_str(&request(&["GET", "bigger"]));
+    pipeline.push_str(&request(&["LRANGE", "list", "0", "-1"]));
+    pipeline.push_str(&request(&["PING"]));
+    client.send(pipeline.as_bytes()).expect("send pipeline");
+
+    for i in 0..20 {
+        assert_eq!(client.read_bulk_or_null(), Some(i.to_string()));
+        assert_eq!(client.read_bulk_or_null(), Some(value.clone()));
+    }
+    assert_eq!(client.read_bulk_or_null(), Some(big_value));
+    assert_eq!(client.read_line().expect("read LRANGE header"), "*100\r\n");
+    for item in &items {
+        assert_eq!(client.read_bulk_or_null().as_ref(), Some(item));
+    }
+    assert_eq!(client.read_line().expect("read PONG"), "+PONG\r\n");
+}
+
+// The replies pipelined before a blocking command are sent before it starts waiting
+#[test]
+fn replies_before_blocking_command_are_not_held_back() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut waiting = server.connect().expect("connect");
+    waiting
+        .set_read_timeout(Some(Duration::from_secs(2)))
+        .expect("set read timeout");
+    let mut client = ValkyrieClientTest::new(server);
+
+    let mut pipeline = request(&["SET", "key", "value"]);
+    pipeline.push_str(&request(&["BLPOP", "empty", "0"]));
+
+    waiting
+        .write_all(pipeline.as_bytes())
+        .expect("send pipeline");
+    let mut reader = BufReader::new(waiting);
+    let mut reply = String::new();
+    reader.read_line(&mut reply).expect("read SET reply");
+    assert_eq!(reply, "+OK\r\n");
+
+    client.assert_command_response(&request(&["RPUSH", "empty", "v"]), ":1\r\n");
+
+    let mut blpop_reply = String::new();
+    for _ in 0..5 {
+        reader
+            .read_line(&mut blpop_reply)
+            .expect("read BLPOP reply");
+    }
+    assert_eq!(blpop_reply, "*2\r\n$5\r\nempty\r\n$1\r\nv\r\n");
+}
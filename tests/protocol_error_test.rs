@@ -0,0 +1,107 @@
+mod common;
+
+use std::io::{Read, Write};
+
+use crate::common::{ValkyrieClientTest, start_client};
+
+// Malformed RESP framing is answered with Redis-style protocol errors, then the connection is closed
+
+fn assert_connection_closed(client: &mut ValkyrieClientTest) {
+    let line = client.read_line().expect("read after protocol error");
+    assert_eq!(
+        line, "",
+        "Connection should be closed after a protocol error"
+    );
+}
+
+#[test]
+fn empty_multibulk_length() {
+    let mut client = start_client();
+
+    client.assert_command_response("*\r\n", "-ERR Protocol error: invalid multibulk length\r\n");
+    assert_connection_closed(&mut client);
+}
+
+#[test]
+fn bad_multibulk_length() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        "*abc\r\n$4\r\nPING\r\n",
+        "-ERR Protocol error: invalid multibulk length\r\n",
+    );
+    assert_connection_closed(&mut client);
+}
+
+#[test]
+fn bad_bulk_length() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        "*2\r\n$4\r\nECHO\r\n$-5\r\nhello\r\n",
+        "-ERR Protocol error: invalid bulk length\r\n",
+    );
+    assert_connection_closed(&mut client);
+}
+
+#[test]
+fn bulk_length_shorter_than_payload() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        "*2\r\n$4\r\nECHO\r\n$3\r\nhello\r\n",
+        "-ERR Protocol error: expected CRLF after bulk string payload\r\n",
+    );
+    assert_connection_closed(&mut client);
+}
+
+#[test]
+fn empty_bulk_length_inside_request() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        "*2\r\n$4\r\nECHO\r\n$\r\n",
+        "-ERR Protocol error: invalid bulk length\r\n",
+    );
+    assert_connection_closed(&mut client);
+}
+
+#[test]
+fn unexpected_type_marker() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        "*1\r\n!PING\r\n",
+        "-ERR Protocol error: expected '$', got '!'\r\n",
+    );
+    assert_connection_closed(&mut client);
+}
+
+#[test]
+fn garbage_does_not_affect_other_connections() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut garbage = server.connect().expect("connect to server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    garbage
+        .write_all(b"*2\r\n%2\r\n#t\r\n\x00\xff")
+        .expect("send garbage");
+    let mut reply = String::new();
+    garbage
+        .read_to_string(&mut reply)
+        .expect("read until close");
+    assert_eq!(reply, "-ERR Protocol error: expected '$', got '%'\r\n");
+
+    // The handler serving the other connections is still alive
+    client.assert_command_response("*1\r\n$4\r\nPING\r\n", "+PONG\r\n");
+    client.assert_command_response("*3\r\n$3\r\nSET\r\n$3\r\nkey\r\n$5\r\nvalue\r\n", "+OK\r\n");
+    client.assert_command_response("*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n", "$5\r\nvalue\r\n");
+}
+
+#[test]
+fn command_errors_keep_the_connection_open() {
+    let mut client = start_client();
+
+    client.assert_command_response("*1\r\n$4\r\nECHO\r\n", "-No argument for ECHO command\r\n");
+    client.assert_command_response("*1\r\n$4\r\nPING\r\n", "+PONG\r\n");
+}
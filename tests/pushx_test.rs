@@ -0,0 +1,44 @@
+mod common;
+
+use crate::common::{request, start_client};
+
+// https://redis.io/docs/latest/commands/lpushx/
+// https://redis.io/docs/latest/commands/rpushx/
+
+#[test]
+fn pushx_does_not_create_missing_key() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["LPUSHX", "list", "a"]), ":0\r\n");
+    client.assert_command_response(&request(&["RPUSHX", "list", "a", "b"]), ":0\r\n");
+    client.assert_command_response(&request(&["EXISTS", "list"]), ":0\r\n");
+}
+
+#[test]
+fn pushx_pushes_to_existing_list() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["RPUSH", "list", "m"]), ":1\r\n");
+    client.assert_command_response(&request(&["LPUSHX", "list", "b", "a"]), ":3\r\n");
+    client.assert_command_response(&request(&["RPUSHX", "list", "y", "z"]), ":5\r\n");
+    client.assert_command_response(
+        &request(&["LRANGE", "list", "0", "-1"]),
+        "*5\r\n$1\r\na\r\n$1\r\nb\r\n$1\r\nm\r\n$1\r\ny\r\n$1\r\nz\r\n",
+    );
+}
+
+#[test]
+fn pushx_wrong_type() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "key", "value"]), "+OK\r\n");
+    client.assert_command_response(
+        &request(&["LPUSHX", "key", "a"]),
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+    client.assert_command_response(
+        &request(&["RPUSHX", "key", "a"]),
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+    client.assert_command_response(&request(&["GET", "key"]), "$5\r\nvalue\r\n");
+}
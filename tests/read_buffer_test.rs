@@ -0,0 +1,60 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request};
+
+// --read-buffer-size <bytes> sets the initial read chunk of every client connection
+
+const COMMANDS: usize = 3_000;
+
+fn pipelined_set_then_get(read_buffer_size: &str) {
+    let server = common::ValkyrieServerTest::start_with_args(
+        2,
+        3,
+        &["--read-buffer-size", read_buffer_size],
+    )
+    .expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    // Well over 64KB in a single write: frames split across reads must still parse
+    let mut pipeline = String::new();
+    for i in 0..COMMANDS {
+        pipeline.push_str(&request(&[
+            "SET",
+            &format!("key:{i}"),
+            &format!("value:{i}"),
+        ]));
+    }
+    for i in 0..COMMANDS {
+        pipeline.push_str(&request(&["GET", &format!("key:{i}")]));
+    }
+    assert!(pipeline.len() > 64 * 1024);
+    client.send(pipeline.as_bytes()).expect("send pipeline");
+
+    for _ in 0..COMMANDS {
+        assert_eq!(client.read_simple_string_or_null().as_deref(), Some("OK"));
+    }
+    for i in 0..COMMANDS {
+        assert_eq!(client.read_bulk_or_null(), Some(format!("value:{i}")));
+    }
+}
+
+#[test]
+fn large_read_buffer_parses_pipelined_commands() {
+    pipelined_set_then_get("65536");
+}
+
+#[test]
+fn tiny_read_buffer_parses_pipelined_commands() {
+    pipelined_set_then_get("7");
+}
+
+#[test]
+fn oversized_request_is_still_rejected() {
+    let server =
+        common::ValkyrieServerTest::start_with_args(2, 3, &["--read-buffer-size", "65536"])
+            .expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    let value = "x".repeat(80 * 1024);
+    client.assert_command_response(&request(&["SET", "key", &value]), "-Request too large\r\n");
+}
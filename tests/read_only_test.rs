@@ -0,0 +1,43 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request};
+
+// --read-only rejects every command flagged as `write` in the command table
+
+const READ_ONLY_ERROR: &str = "-READONLY You can't write against a read only replica.\r\n";
+
+fn start_read_only_client() -> ValkyrieClientTest {
+    let server =
+        common::ValkyrieServerTest::start_with_args(2, 3, &["--read-only"]).expect("start server");
+    ValkyrieClientTest::new(server)
+}
+
+#[test]
+fn read_only_rejects_writes() {
+    let mut client = start_read_only_client();
+
+    client.assert_command_response(&request(&["SET", "key", "value"]), READ_ONLY_ERROR);
+    client.assert_command_response(&request(&["set", "key", "value"]), READ_ONLY_ERROR);
+    client.assert_command_response(&request(&["DEL", "key"]), READ_ONLY_ERROR);
+    client.assert_command_response(&request(&["RPUSH", "list", "a"]), READ_ONLY_ERROR);
+    client.assert_command_response(&request(&["INCR", "counter"]), READ_ONLY_ERROR);
+}
+
+#[test]
+fn read_only_allows_reads() {
+    let mut client = start_read_only_client();
+
+    client.assert_command_response(&request(&["GET", "key"]), "$-1\r\n");
+    client.assert_command_response(&request(&["EXISTS", "key"]), ":0\r\n");
+    client.assert_command_response(&request(&["LLEN", "list"]), ":0\r\n");
+    client.assert_command_response(&request(&["PING"]), "+PONG\r\n");
+}
+
+#[test]
+fn writes_are_accepted_by_default() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(&request(&["SET", "key", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["GET", "key"]), "$5\r\nvalue\r\n");
+}
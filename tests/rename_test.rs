@@ -0,0 +1,158 @@
+mod common;
+
+use crate::common::{request, start_client};
+use std::thread;
+use std::time::Duration;
+
+// https://redis.io/docs/latest/commands/rename/
+// https://redis.io/docs/latest/commands/renamenx/
+
+/// Destination key for `source` that lands on the same shard, or on another one when `same_shard` is false.
+/// With a single shard every key pair is a same-shard pair.
+fn destination_for(source: &str, shards: usize, same_shard: bool) -> String {
+    let source_shard = common::shard_for_key(source, shards);
+    (0..)
+        .map(|i| format!("dst:{i}"))
+        .find(|key| {
+            (common::shard_for_key(key, shards) == source_shard) == (same_shard || shards == 1)
+        })
+        .expect("destination key")
+}
+
+fn rename_moves_value(same_shard: bool) {
+    let mut client = start_client();
+    let destination = destination_for("src", common::effective_shards(3), same_shard);
+
+    client.assert_command_response(&request(&["SET", "src", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["SET", &destination, "old"]), "+OK\r\n");
+    client.assert_command_response(&request(&["RENAME", "src", &destination]), "+OK\r\n");
+
+    client.assert_command_response(&request(&["GET", "src"]), "$-1\r\n");
+    client.assert_command_response(&request(&["GET", &destination]), "$5\r\nvalue\r\n");
+
+    // Lists are moved as well
+    client.assert_command_response(&request(&["RPUSH", "src", "a", "b"]), ":2\r\n");
+    client.assert_command_response(&request(&["RENAME", "src", &destination]), "+OK\r\n");
+    client.assert_command_response(
+        &request(&["LRANGE", &destination, "0", "-1"]),
+        "*2\r\n$1\r\na\r\n$1\r\nb\r\n",
+    );
+}
+
+#[test]
+fn rename_same_shard() {
+    rename_moves_value(true);
+}
+
+#[test]
+fn rename_cross_shard() {
+    rename_moves_value(false);
+}
+
+#[test]
+fn rename_keeps_remaining_ttl() {
+    let mut client = start_client();
+    let destination = destination_for("src", common::effective_shards(3), false);
+
+    client.assert_command_response(&request(&["SET", "src", "value", "PX", "300"]), "+OK\r\n");
+    client.assert_command_response(
+        &request(&["SET", &destination, "old", "EX", "100"]),
+        "+OK\r\n",
+    );
+    client.assert_command_response(&request(&["RENAME", "src", &destination]), "+OK\r\n");
+    client.assert_command_response(&request(&["TTL", &destination]), ":0\r\n");
+
+    thread::sleep(Duration::from_millis(500));
+
+    client.assert_command_response(&request(&["GET", &destination]), "$-1\r\n");
+
+    // A key without TTL overwrites the destination's TTL
+    client.assert_command_response(&request(&["SET", "src", "value"]), "+OK\r\n");
+    client.assert_command_response(
+        &request(&["SET", &destination, "old", "PX", "200"]),
+        "+OK\r\n",
+    );
+    client.assert_command_response(&request(&["RENAME", "src", &destination]), "+OK\r\n");
+
+    thread::sleep(Duration::from_millis(400));
+
+    client.assert_command_response(&request(&["GET", &destination]), "$5\r\nvalue\r\n");
+}
+
+#[test]
+fn rename_missing_source() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["RENAME", "missing", "dst"]),
+        "-ERR no such key\r\n",
+    );
+    client.assert_command_response(
+        &request(&["RENAMENX", "missing", "dst"]),
+        "-ERR no such key\r\n",
+    );
+    client.assert_command_response(
+        &request(&["RENAME", "missing", "missing"]),
+        "-ERR no such key\r\n",
+    );
+}
+
+#[test]
+fn renamenx_existing_destination() {
+    let mut client = start_client();
+
+    for same_shard in [true, false] {
+        let destination = destination_for("src", common::effective_shards(3), same_shard);
+
+        client.assert_command_response(&request(&["SET", "src", "value", "EX", "100"]), "+OK\r\n");
+        client.assert_command_response(&request(&["SET", &destination, "old"]), "+OK\r\n");
+        client.assert_command_response(&request(&["RENAMENX", "src", &destination]), ":0\r\n");
+
+        // Both keys are left as they were
+        client.assert_command_response(&request(&["GET", "src"]), "$5\r\nvalue\r\n");
+        client.assert_command_response(&request(&["TTL", "src"]), ":100\r\n");
+        client.assert_command_response(&request(&["GET", &destination]), "$3\r\nold\r\n");
+
+        client.assert_command_response(&request(&["DEL", &destination]), ":1\r\n");
+        client.assert_command_response(&request(&["RENAMENX", "src", &destination]), ":1\r\n");
+        client.assert_command_response(&request(&["GET", &destination]), "$5\r\nvalue\r\n");
+        client.assert_command_response(&request(&["DEL", &destination]), ":1\r\n");
+    }
+}
+
+// The source is checked even though the destination already rules the rename out
+#[test]
+fn renamenx_missing_source_onto_existing_destination() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "dst", "old"]), "+OK\r\n");
+    client.assert_command_response(
+        &request(&["RENAMENX", "missing", "dst"]),
+        "-ERR no such key\r\n",
+    );
+    client.assert_command_response(&request(&["GET", "dst"]), "$3\r\nold\r\n");
+}
+
+#[test]
+fn rename_to_itself() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "key", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["RENAME", "key", "key"]), "+OK\r\n");
+    client.assert_command_response(&request(&["RENAMENX", "key", "key"]), ":0\r\n");
+    client.assert_command_response(&request(&["GET", "key"]), "$5\r\nvalue\r\n");
+}
+
+#[test]
+fn rename_wrong_number_of_arguments() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["RENAME", "key"]),
+        "-Wrong number of arguments for RENAME command\r\n",
+    );
+    client.assert_command_response(
+        &request(&["RENAMENX", "key"]),
+        "-Wrong number of arguments for RENAMENX command\r\n",
+    );
+}
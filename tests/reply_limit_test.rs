@@ -0,0 +1,85 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request};
+
+// --max-reply-elements / --max-reply-bytes replace oversized replies with an error
+
+const REPLY_TOO_LARGE_ERROR: &str = "-ERR reply is too large\r\n";
+
+fn start_client_with_args(extra_args: &[&str]) -> ValkyrieClientTest {
+    let server =
+        common::ValkyrieServerTest::start_with_args(2, 3, extra_args).expect("start server");
+    ValkyrieClientTest::new(server)
+}
+
+fn push_values(client: &mut ValkyrieClientTest, key: &str, count: usize) {
+    let mut args = vec!["RPUSH".to_string(), key.to_string()];
+    args.extend((0..count).map(|i| format!("value-{i}")));
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    client.assert_command_response(&request(&args), &format!(":{count}\r\n"));
+}
+
+#[test]
+fn large_list_over_element_limit_is_rejected() {
+    let mut client = start_client_with_args(&["--max-reply-elements", "100"]);
+
+    push_values(&mut client, "biglist", 1_000);
+
+    client.assert_command_response(
+        &request(&["LRANGE", "biglist", "0", "-1"]),
+        REPLY_TOO_LARGE_ERROR,
+    );
+
+    // Within the limit, and the connection is still usable after the error
+    client.assert_command_response(
+        &request(&["LRANGE", "biglist", "0", "1"]),
+        "*2\r\n$7\r\nvalue-0\r\n$7\r\nvalue-1\r\n",
+    );
+}
+
+#[test]
+fn large_list_over_byte_limit_is_rejected() {
+    let mut client = start_client_with_args(&["--max-reply-bytes", "1024"]);
+
+    push_values(&mut client, "biglist", 1_000);
+
+    client.assert_command_response(
+        &request(&["LRANGE", "biglist", "0", "-1"]),
+        REPLY_TOO_LARGE_ERROR,
+    );
+    client.assert_command_response(
+        &request(&["LRANGE", "biglist", "0", "0"]),
+        "*1\r\n$7\r\nvalue-0\r\n",
+    );
+}
+
+#[test]
+fn large_string_over_byte_limit_is_rejected() {
+    let mut client = start_client_with_args(&["--max-reply-bytes", "1024"]);
+
+    let value = "x".repeat(4096);
+    client.assert_command_response(&request(&["SET", "big", &value]), "+OK\r\n");
+
+    client.assert_command_response(&request(&["GET", "big"]), REPLY_TOO_LARGE_ERROR);
+}
+
+#[test]
+fn replies_are_unlimited_by_default() {
+    let mut client = start_client_with_args(&[]);
+
+    push_values(&mut client, "biglist", 1_000);
+
+    client
+        .send(request(&["LLEN", "biglist"]).as_bytes())
+        .expect("send LLEN");
+    assert_eq!(client.read_line().expect("read LLEN"), ":1000\r\n");
+
+    client
+        .send(request(&["LRANGE", "biglist", "0", "-1"]).as_bytes())
+        .expect("send LRANGE");
+    assert_eq!(client.read_line().expect("read header"), "*1000\r\n");
+    for i in 0..1_000 {
+        assert_eq!(client.read_bulk_or_null(), Some(format!("value-{i}")));
+    }
+}
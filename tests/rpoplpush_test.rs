@@ -0,0 +1,89 @@
+mod common;
+
+use crate::common::{request, start_client};
+
+// https://redis.io/docs/latest/commands/rpoplpush/
+
+/// Destination key for `source` that lands on the same shard, or on another one when `same_shard` is false.
+/// With a single shard every key pair is a same-shard pair.
+fn destination_for(source: &str, shards: usize, same_shard: bool) -> String {
+    let source_shard = common::shard_for_key(source, shards);
+    (0..)
+        .map(|i| format!("dst:{i}"))
+        .find(|key| {
+            (common::shard_for_key(key, shards) == source_shard) == (same_shard || shards == 1)
+        })
+        .expect("destination key")
+}
+
+#[test]
+fn rpoplpush_same_key_rotates_list() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["RPUSH", "list", "a", "b", "c"]), ":3\r\n");
+    client.assert_command_response(&request(&["RPOPLPUSH", "list", "list"]), "$1\r\nc\r\n");
+    client.assert_command_response(
+        &request(&["LRANGE", "list", "0", "-1"]),
+        "*3\r\n$1\r\nc\r\n$1\r\na\r\n$1\r\nb\r\n",
+    );
+}
+
+fn rpoplpush_moves_element(same_shard: bool) {
+    let mut client = start_client();
+    let destination = destination_for("src", common::effective_shards(3), same_shard);
+
+    client.assert_command_response(&request(&["RPUSH", "src", "a", "b"]), ":2\r\n");
+    client.assert_command_response(&request(&["RPUSH", &destination, "x"]), ":1\r\n");
+
+    client.assert_command_response(&request(&["RPOPLPUSH", "src", &destination]), "$1\r\nb\r\n");
+    client.assert_command_response(&request(&["RPOPLPUSH", "src", &destination]), "$1\r\na\r\n");
+
+    // Source is deleted once its last element was moved
+    client.assert_command_response(&request(&["EXISTS", "src"]), ":0\r\n");
+    client.assert_command_response(
+        &request(&["LRANGE", &destination, "0", "-1"]),
+        "*3\r\n$1\r\na\r\n$1\r\nb\r\n$1\r\nx\r\n",
+    );
+}
+
+#[test]
+fn rpoplpush_same_shard() {
+    rpoplpush_moves_element(true);
+}
+
+#[test]
+fn rpoplpush_cross_shard() {
+    rpoplpush_moves_element(false);
+}
+
+#[test]
+fn rpoplpush_missing_source_returns_nil() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["RPOPLPUSH", "missing", "dst"]), "$-1\r\n");
+    client.assert_command_response(&request(&["EXISTS", "dst"]), ":0\r\n");
+}
+
+#[test]
+fn rpoplpush_wrong_type_keeps_source() {
+    let mut client = start_client();
+    let destination = destination_for("src", common::effective_shards(3), false);
+
+    client.assert_command_response(&request(&["RPUSH", "src", "a", "b"]), ":2\r\n");
+    client.assert_command_response(&request(&["SET", &destination, "string"]), "+OK\r\n");
+
+    client.assert_command_response(
+        &request(&["RPOPLPUSH", "src", &destination]),
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+    client.assert_command_response(
+        &request(&["LRANGE", "src", "0", "-1"]),
+        "*2\r\n$1\r\na\r\n$1\r\nb\r\n",
+    );
+
+    // Source holding another type
+    client.assert_command_response(
+        &request(&["RPOPLPUSH", &destination, "src"]),
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+}
@@ -74,9 +74,9 @@ fn rpush_on_existing_list_increases_length() {
     client_test.assert_command_response(req2, ":3\r\n");
 }
 
-// Interop: GET on a list key should return Null Bulk String (lists are not returned by GET)
+// Interop: GET on a list key is a WRONGTYPE error (lists are not returned by GET)
 #[test]
-fn rpush_then_get_returns_null_bulk_string() {
+fn rpush_then_get_returns_wrongtype() {
     let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
     let mut client_test = ValkyrieClientTest::new(server);
 
@@ -84,9 +84,12 @@ fn rpush_then_get_returns_null_bulk_string() {
     let req = "*3\r\n$5\r\nRPUSH\r\n$4\r\nlkey\r\n$2\r\nv1\r\n";
     client_test.assert_command_response(req, ":1\r\n");
 
-    // GET lkey -> $-1
+    // GET lkey -> WRONGTYPE
     let get_req = "*2\r\n$3\r\nGET\r\n$4\r\nlkey\r\n";
-    client_test.assert_command_response(get_req, "$-1\r\n");
+    client_test.assert_command_response(
+        get_req,
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
 }
 
 // Error: not enough arguments (requires at least key and one value)
@@ -97,7 +100,10 @@ fn rpush_not_enough_arguments_only_command() {
 
     // RPUSH (no key, no values)
     let req = "*1\r\n$5\r\nRPUSH\r\n";
-    client_test.assert_command_response(req, "-Not enough arguments for RPUSH command\r\n");
+    client_test.assert_command_response(
+        req,
+        "-ERR wrong number of arguments for 'rpush' command\r\n",
+    );
 }
 
 // Error: not enough arguments (has key, but no values)
@@ -108,7 +114,10 @@ fn rpush_not_enough_arguments_only_key() {
 
     // RPUSH mylist (no values)
     let req = "*2\r\n$5\r\nRPUSH\r\n$6\r\nmylist\r\n";
-    client_test.assert_command_response(req, "-Not enough arguments for RPUSH command\r\n");
+    client_test.assert_command_response(
+        req,
+        "-ERR wrong number of arguments for 'rpush' command\r\n",
+    );
 }
 
 // Error: key must be a BulkString (Integer provided instead)
@@ -119,7 +128,10 @@ fn rpush_key_wrong_type_integer() {
 
     // RPUSH :1 value
     let req = "*3\r\n$5\r\nRPUSH\r\n:1\r\n$5\r\nvalue\r\n";
-    client_test.assert_command_response(req, "-RPUSH key is not BulkString\r\n");
+    client_test.assert_command_response(
+        req,
+        "-ERR invalid argument 'key' for 'rpush' command, expected a bulk string\r\n",
+    );
 }
 
 // Error: value is neither BulkString nor Integer (SimpleString used)
@@ -130,7 +142,7 @@ fn rpush_wrong_value_type_simplestring() {
 
     // RPUSH mykey +foo
     let req = "*3\r\n$5\r\nRPUSH\r\n$5\r\nmykey\r\n+foo\r\n";
-    client_test.assert_command_response(req, "-RPUSH argument is not BulkString or Integer\r\n");
+    client_test.assert_command_response(req, "-ERR invalid argument 'element' for 'rpush' command, expected a bulk string or an integer\r\n");
 }
 
 // Error: value Null Bulk String is not accepted by current parser/command logic
@@ -141,7 +153,7 @@ fn rpush_wrong_value_type_null_bulk_string() {
 
     // RPUSH mykey $-1
     let req = "*3\r\n$5\r\nRPUSH\r\n$5\r\nmykey\r\n$-1\r\n";
-    client_test.assert_command_response(req, "-RPUSH argument is not BulkString or Integer\r\n");
+    client_test.assert_command_response(req, "-ERR invalid argument 'element' for 'rpush' command, expected a bulk string or an integer\r\n");
 }
 
 // Error: pushing into a key that holds a String value
@@ -166,7 +178,7 @@ fn rpush_on_string_key_fails() {
     let rpush_req = "*3\r\n$5\r\nRPUSH\r\n$4\r\nskey\r\n$2\r\nv1\r\n";
     client_test.assert_command_response(
         rpush_req,
-        "-Can't execute Right Push for a String value, should be List\r\n",
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
     );
 }
 
@@ -0,0 +1,81 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request, start_client};
+
+// https://redis.io/docs/latest/commands/sadd/
+// https://redis.io/docs/latest/commands/srem/
+// https://redis.io/docs/latest/commands/smembers/
+
+const WRONG_TYPE: &str = "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n";
+
+/// SMEMBERS reply sorted, as sets have no order
+fn sorted_members(client: &mut ValkyrieClientTest, key: &str) -> Vec<String> {
+    client
+        .send(request(&["SMEMBERS", key]).as_bytes())
+        .expect("send SMEMBERS");
+
+    let header = client.read_line().expect("read array header");
+    assert!(header.starts_with('*'), "Expected array, got: {header:?}");
+    let len: usize = header[1..].trim().parse().expect("parse array length");
+
+    let mut members: Vec<String> = (0..len)
+        .map(|_| client.read_bulk_or_null().expect("member"))
+        .collect();
+    members.sort();
+    members
+}
+
+#[test]
+fn sadd_is_idempotent() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SADD", "set", "a", "b", "a"]), ":2\r\n");
+    client.assert_command_response(&request(&["SADD", "set", "a", "b"]), ":0\r\n");
+    client.assert_command_response(&request(&["SADD", "set", "b", "c"]), ":1\r\n");
+
+    assert_eq!(sorted_members(&mut client, "set"), ["a", "b", "c"]);
+}
+
+#[test]
+fn srem_removes_members_and_empty_set() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SREM", "missing", "a"]), ":0\r\n");
+
+    client.assert_command_response(&request(&["SADD", "set", "a", "b", "c"]), ":3\r\n");
+    // Members not in the set are ignored
+    client.assert_command_response(&request(&["SREM", "set", "a", "x"]), ":1\r\n");
+    assert_eq!(sorted_members(&mut client, "set"), ["b", "c"]);
+
+    client.assert_command_response(&request(&["SREM", "set", "b", "c"]), ":2\r\n");
+    client.assert_command_response(&request(&["EXISTS", "set"]), ":0\r\n");
+}
+
+#[test]
+fn smembers_missing_key_is_empty() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SMEMBERS", "missing"]), "*0\r\n");
+}
+
+#[test]
+fn set_commands_on_other_types_return_wrong_type() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "string", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["SADD", "string", "a"]), WRONG_TYPE);
+    client.assert_command_response(&request(&["SREM", "string", "a"]), WRONG_TYPE);
+    client.assert_command_response(&request(&["SMEMBERS", "string"]), WRONG_TYPE);
+
+    // Sets are not lists either
+    client.assert_command_response(&request(&["SADD", "set", "a"]), ":1\r\n");
+    client.assert_command_response(&request(&["HGET", "set", "a"]), WRONG_TYPE);
+}
+
+#[test]
+fn type_reports_set() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SADD", "set", "a"]), ":1\r\n");
+    client.assert_command_response(&request(&["TYPE", "set"]), "+set\r\n");
+}
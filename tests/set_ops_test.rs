@@ -0,0 +1,127 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request, start_client};
+
+// https://redis.io/docs/latest/commands/sinter/
+// https://redis.io/docs/latest/commands/sunion/
+// https://redis.io/docs/latest/commands/sdiff/
+
+const WRONG_TYPE: &str = "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n";
+
+/// Operand keys spread over the shards (round robin), so set operations gather members from several shards.
+fn keys_on_distinct_shards(count: usize) -> Vec<String> {
+    let shards = common::effective_shards(3);
+    (0..count)
+        .map(|i| {
+            (0..)
+                .map(|n| format!("set{i}:{n}"))
+                .find(|key| common::shard_for_key(key, shards) == i % shards)
+                .expect("key on shard")
+        })
+        .collect()
+}
+
+/// Array reply sorted, as sets have no order
+fn sorted_reply(client: &mut ValkyrieClientTest, args: &[&str]) -> Vec<String> {
+    client
+        .send(request(args).as_bytes())
+        .expect("send set operation");
+
+    let header = client.read_line().expect("read array header");
+    assert!(header.starts_with('*'), "Expected array, got: {header:?}");
+    let len: usize = header[1..].trim().parse().expect("parse array length");
+
+    let mut members: Vec<String> = (0..len)
+        .map(|_| client.read_bulk_or_null().expect("member"))
+        .collect();
+    members.sort();
+    members
+}
+
+fn fill_sets(client: &mut ValkyrieClientTest, keys: &[String]) {
+    client.assert_command_response(&request(&["SADD", &keys[0], "a", "b", "c", "d"]), ":4\r\n");
+    client.assert_command_response(&request(&["SADD", &keys[1], "b", "c", "e"]), ":3\r\n");
+    client.assert_command_response(&request(&["SADD", &keys[2], "c", "d", "f"]), ":3\r\n");
+}
+
+#[test]
+fn sinter_across_shards() {
+    let mut client = start_client();
+    let keys = keys_on_distinct_shards(3);
+    fill_sets(&mut client, &keys);
+
+    assert_eq!(
+        sorted_reply(&mut client, &["SINTER", &keys[0]]),
+        ["a", "b", "c", "d"]
+    );
+    assert_eq!(
+        sorted_reply(&mut client, &["SINTER", &keys[0], &keys[1]]),
+        ["b", "c"]
+    );
+    assert_eq!(
+        sorted_reply(&mut client, &["SINTER", &keys[0], &keys[1], &keys[2]]),
+        ["c"]
+    );
+    // A missing key is an empty set, so the intersection is empty
+    assert!(sorted_reply(&mut client, &["SINTER", &keys[0], "missing"]).is_empty());
+}
+
+#[test]
+fn sunion_across_shards() {
+    let mut client = start_client();
+    let keys = keys_on_distinct_shards(3);
+    fill_sets(&mut client, &keys);
+
+    assert_eq!(
+        sorted_reply(&mut client, &["SUNION", &keys[0], &keys[1], &keys[2]]),
+        ["a", "b", "c", "d", "e", "f"]
+    );
+    assert_eq!(
+        sorted_reply(&mut client, &["SUNION", "missing", &keys[1]]),
+        ["b", "c", "e"]
+    );
+    assert!(sorted_reply(&mut client, &["SUNION", "missing", "other"]).is_empty());
+}
+
+#[test]
+fn sdiff_across_shards() {
+    let mut client = start_client();
+    let keys = keys_on_distinct_shards(3);
+    fill_sets(&mut client, &keys);
+
+    assert_eq!(
+        sorted_reply(&mut client, &["SDIFF", &keys[0], &keys[1]]),
+        ["a", "d"]
+    );
+    assert_eq!(
+        sorted_reply(&mut client, &["SDIFF", &keys[0], &keys[1], &keys[2]]),
+        ["a"]
+    );
+    // Order matters: only the first set is subtracted from
+    assert_eq!(
+        sorted_reply(&mut client, &["SDIFF", &keys[1], &keys[0]]),
+        ["e"]
+    );
+    assert_eq!(
+        sorted_reply(&mut client, &["SDIFF", &keys[0], "missing"]),
+        ["a", "b", "c", "d"]
+    );
+    assert!(sorted_reply(&mut client, &["SDIFF", "missing", &keys[0]]).is_empty());
+}
+
+#[test]
+fn set_operations_reject_non_set_keys() {
+    let mut client = start_client();
+    let keys = keys_on_distinct_shards(2);
+
+    client.assert_command_response(&request(&["SADD", &keys[0], "a"]), ":1\r\n");
+    client.assert_command_response(&request(&["SET", &keys[1], "value"]), "+OK\r\n");
+
+    for command in ["SINTER", "SUNION", "SDIFF"] {
+        client.assert_command_response(&request(&[command, &keys[0], &keys[1]]), WRONG_TYPE);
+    }
+    client.assert_command_response(
+        &request(&["SINTER"]),
+        "-Wrong number of arguments for SINTER command\r\n",
+    );
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use crate::common::ValkyrieClientTest;
+
+// https://redis.io/docs/latest/commands/setnx/
+// SETNX key value
+
+fn setnx_request(key: &str, value: &str) -> String {
+    format!(
+        "*3\r\n$5\r\nSETNX\r\n${}\r\n{}\r\n${}\r\n{}\r\n",
+        key.len(),
+        key,
+        value.len(),
+        value
+    )
+}
+
+#[test]
+fn setnx_sets_only_when_missing() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(&setnx_request("mykey", "Hello"), ":1\r\n");
+    client.assert_command_response(&setnx_request("mykey", "World"), ":0\r\n");
+
+    client.assert_command_response("*2\r\n$3\r\nGET\r\n$5\r\nmykey\r\n", "$5\r\nHello\r\n");
+}
+
+#[test]
+fn setnx_does_not_overwrite_list_key() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response("*3\r\n$5\r\nRPUSH\r\n$4\r\nlist\r\n$1\r\na\r\n", ":1\r\n");
+    client.assert_command_response(&setnx_request("list", "value"), ":0\r\n");
+
+    client.assert_command_response(
+        "*4\r\n$6\r\nLRANGE\r\n$4\r\nlist\r\n$1\r\n0\r\n$2\r\n-1\r\n",
+        "*1\r\n$1\r\na\r\n",
+    );
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use crate::common::{request, start_client};
+
+// https://redis.io/docs/latest/commands/sismember/
+// https://redis.io/docs/latest/commands/scard/
+
+const WRONG_TYPE: &str = "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n";
+
+#[test]
+fn sismember_membership() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SADD", "set", "a", "b"]), ":2\r\n");
+    client.assert_command_response(&request(&["SISMEMBER", "set", "a"]), ":1\r\n");
+    client.assert_command_response(&request(&["SISMEMBER", "set", "c"]), ":0\r\n");
+
+    client.assert_command_response(&request(&["SREM", "set", "a"]), ":1\r\n");
+    client.assert_command_response(&request(&["SISMEMBER", "set", "a"]), ":0\r\n");
+}
+
+#[test]
+fn scard_counts_members() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SADD", "set", "a", "b", "a"]), ":2\r\n");
+    client.assert_command_response(&request(&["SCARD", "set"]), ":2\r\n");
+    client.assert_command_response(&request(&["SADD", "set", "c"]), ":1\r\n");
+    client.assert_command_response(&request(&["SCARD", "set"]), ":3\r\n");
+}
+
+#[test]
+fn sismember_and_scard_missing_key() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SISMEMBER", "missing", "a"]), ":0\r\n");
+    client.assert_command_response(&request(&["SCARD", "missing"]), ":0\r\n");
+}
+
+#[test]
+fn sismember_and_scard_wrong_type() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["RPUSH", "list", "a"]), ":1\r\n");
+    client.assert_command_response(&request(&["SISMEMBER", "list", "a"]), WRONG_TYPE);
+    client.assert_command_response(&request(&["SCARD", "list"]), WRONG_TYPE);
+}
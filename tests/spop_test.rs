@@ -0,0 +1,171 @@
+mod common;
+
+use std::collections::HashSet;
+
+use crate::common::{ValkyrieClientTest, request, start_client};
+
+// https://redis.io/docs/latest/commands/spop/
+// https://redis.io/docs/latest/commands/srandmember/
+
+const WRONG_TYPE: &str = "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n";
+
+/// Array reply in the order it was received
+fn array_reply(client: &mut ValkyrieClientTest, args: &[&str]) -> Vec<String> {
+    client.send(request(args).as_bytes()).expect("send request");
+
+    let header = client.read_line().expect("read array header");
+    assert!(header.starts_with('*'), "Expected array, got: {header:?}");
+    let len: usize = header[1..].trim().parse().expect("parse array length");
+
+    (0..len)
+        .map(|_| client.read_bulk_or_null().expect("member"))
+        .collect()
+}
+
+fn scard(client: &mut ValkyrieClientTest, key: &str) -> usize {
+    client
+        .send(request(&["SCARD", key]).as_bytes())
+        .expect("send SCARD");
+    let line = client.read_line().expect("read SCARD reply");
+    line.trim_start_matches(':')
+        .trim()
+        .parse()
+        .expect("parse SCARD reply")
+}
+
+const MEMBERS: [&str; 5] = ["a", "b", "c", "d", "e"];
+
+fn fill_set(client: &mut ValkyrieClientTest, key: &str) {
+    let mut args = vec!["SADD", key];
+    args.extend(MEMBERS);
+    client.assert_command_response(&request(&args), ":5\r\n");
+}
+
+#[test]
+fn spop_removes_the_returned_members() {
+    let mut client = start_client();
+    fill_set(&mut client, "set");
+
+    client
+        .send(request(&["SPOP", "set"]).as_bytes())
+        .expect("send SPOP");
+    let popped = client.read_bulk_or_null().expect("popped member");
+    assert!(MEMBERS.contains(&popped.as_str()));
+    assert_eq!(scard(&mut client, "set"), 4);
+    client.assert_command_response(&request(&["SISMEMBER", "set", &popped]), ":0\r\n");
+
+    let popped_many = array_reply(&mut client, &["SPOP", "set", "2"]);
+    assert_eq!(popped_many.iter().collect::<HashSet<_>>().len(), 2);
+    assert!(!popped_many.contains(&popped));
+    assert_eq!(scard(&mut client, "set"), 2);
+
+    assert!(array_reply(&mut client, &["SPOP", "set", "0"]).is_empty());
+    assert_eq!(scard(&mut client, "set"), 2);
+}
+
+#[test]
+fn spop_count_larger_than_the_set_returns_all_members_and_deletes_the_key() {
+    let mut client = start_client();
+    fill_set(&mut client, "set");
+
+    let mut popped = array_reply(&mut client, &["SPOP", "set", "10"]);
+    popped.sort();
+    assert_eq!(popped, MEMBERS);
+
+    client.assert_command_response(&request(&["EXISTS", "set"]), ":0\r\n");
+    client.assert_command_response(&request(&["SPOP", "set"]), "$-1\r\n");
+    client.assert_command_response(&request(&["SPOP", "set", "3"]), "*0\r\n");
+}
+
+#[test]
+fn srandmember_leaves_the_set_untouched() {
+    let mut client = start_client();
+    fill_set(&mut client, "set");
+
+    client
+        .send(request(&["SRANDMEMBER", "set"]).as_bytes())
+        .expect("send SRANDMEMBER");
+    let member = client.read_bulk_or_null().expect("random member");
+    assert!(MEMBERS.contains(&member.as_str()));
+
+    let distinct = array_reply(&mut client, &["SRANDMEMBER", "set", "3"]);
+    assert_eq!(distinct.iter().collect::<HashSet<_>>().len(), 3);
+
+    let mut all = array_reply(&mut client, &["SRANDMEMBER", "set", "10"]);
+    all.sort();
+    assert_eq!(all, MEMBERS);
+
+    // Negative count: exactly that many members, repeats allowed
+    let repeated = array_reply(&mut client, &["SRANDMEMBER", "set", "-12"]);
+    assert_eq!(repeated.len(), 12);
+    assert!(
+        repeated
+            .iter()
+            .all(|member| MEMBERS.contains(&member.as_str()))
+    );
+
+    assert_eq!(scard(&mut client, "set"), 5);
+}
+
+#[test]
+fn srandmember_and_spop_on_missing_or_wrong_type_keys() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SRANDMEMBER", "missing"]), "$-1\r\n");
+    client.assert_command_response(&request(&["SRANDMEMBER", "missing", "-3"]), "*0\r\n");
+
+    client.assert_command_response(&request(&["SET", "string", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["SPOP", "string"]), WRONG_TYPE);
+    client.assert_command_response(&request(&["SRANDMEMBER", "string", "2"]), WRONG_TYPE);
+
+    client.assert_command_response(
+        &request(&["SPOP", "string", "-1"]),
+        "-ERR value is out of range, must be positive\r\n",
+    );
+    client.assert_command_response(
+        &request(&["SRANDMEMBER", "string", "many"]),
+        "-ERR value is not an integer or out of range\r\n",
+    );
+}
+
+// A negative count is the size of the reply, it must not be allocated up front
+#[test]
+fn srandmember_huge_negative_count() {
+    let server =
+        common::ValkyrieServerTest::start_with_args(2, 3, &["--max-reply-elements", "1000"])
+            .expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+    client.assert_command_response(&request(&["SADD", "set", "a"]), ":1\r\n");
+
+    client.assert_command_response(
+        &request(&["SRANDMEMBER", "set", "-9223372036854775807"]),
+        "-ERR value is out of range\r\n",
+    );
+    client.assert_command_response(
+        &request(&["SRANDMEMBER", "set", "-10000000000"]),
+        "-ERR reply is too large\r\n",
+    );
+
+    // Within the limit the members are picked as the reply is written, and the server is still up
+    let repeated = array_reply(&mut client, &["SRANDMEMBER", "set", "-1000"]);
+    assert_eq!(repeated.len(), 1000);
+    assert!(repeated.iter().all(|member| member == "a"));
+    client.assert_command_response(&request(&["SCARD", "set"]), ":1\r\n");
+}
+
+#[test]
+fn srandmember_negative_count_over_byte_limit() {
+    let server = common::ValkyrieServerTest::start_with_args(2, 3, &["--max-reply-bytes", "1024"])
+        .expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+    client.assert_command_response(&request(&["SADD", "set", "a", "b"]), ":2\r\n");
+
+    client.assert_command_response(
+        &request(&["SRANDMEMBER", "set", "-1000"]),
+        "-ERR reply is too large\r\n",
+    );
+    assert_eq!(
+        array_reply(&mut client, &["SRANDMEMBER", "set", "-100"]).len(),
+        100
+    );
+}
@@ -0,0 +1,133 @@
+mod common;
+
+use crate::common::{request, start_client};
+
+// https://redis.io/docs/latest/commands/getrange/
+// https://redis.io/docs/latest/commands/setrange/
+
+#[test]
+fn getrange_basic_and_negative_offsets() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "greeting", "Hello"]), "+OK\r\n");
+
+    client.assert_command_response(
+        &request(&["GETRANGE", "greeting", "0", "-1"]),
+        "$5\r\nHello\r\n",
+    );
+    client.assert_command_response(
+        &request(&["GETRANGE", "greeting", "1", "3"]),
+        "$3\r\nell\r\n",
+    );
+    client.assert_command_response(
+        &request(&["GETRANGE", "greeting", "-3", "-1"]),
+        "$3\r\nllo\r\n",
+    );
+    client.assert_command_response(
+        &request(&["GETRANGE", "greeting", "0", "100"]),
+        "$5\r\nHello\r\n",
+    );
+}
+
+#[test]
+fn getrange_negative_start_beyond_length_clamps_to_zero() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "greeting", "Hello"]), "+OK\r\n");
+
+    client.assert_command_response(
+        &request(&["GETRANGE", "greeting", "-100", "-1"]),
+        "$5\r\nHello\r\n",
+    );
+    client.assert_command_response(
+        &request(&["GETRANGE", "greeting", "-100", "1"]),
+        "$2\r\nHe\r\n",
+    );
+}
+
+#[test]
+fn getrange_start_greater_than_end_is_empty() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "greeting", "Hello"]), "+OK\r\n");
+
+    client.assert_command_response(&request(&["GETRANGE", "greeting", "3", "1"]), "$0\r\n\r\n");
+    client.assert_command_response(
+        &request(&["GETRANGE", "greeting", "-1", "-3"]),
+        "$0\r\n\r\n",
+    );
+    client.assert_command_response(
+        &request(&["GETRANGE", "greeting", "10", "20"]),
+        "$0\r\n\r\n",
+    );
+}
+
+#[test]
+fn getrange_on_empty_string_and_missing_key_is_empty() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "empty", ""]), "+OK\r\n");
+    client.assert_command_response(&request(&["GETRANGE", "empty", "0", "-1"]), "$0\r\n\r\n");
+
+    client.assert_command_response(&request(&["GETRANGE", "missing", "0", "-1"]), "$0\r\n\r\n");
+}
+
+#[test]
+fn setrange_empty_value_is_noop_returning_current_length() {
+    let mut client = start_client();
+
+    // Missing key: returns 0 and the key is not created
+    client.assert_command_response(&request(&["SETRANGE", "missing", "0", ""]), ":0\r\n");
+    client.assert_command_response(&request(&["GET", "missing"]), "$-1\r\n");
+
+    // Offset is ignored for an empty value, the string is not padded
+    client.assert_command_response(&request(&["SETRANGE", "missing", "10", ""]), ":0\r\n");
+    client.assert_command_response(&request(&["GET", "missing"]), "$-1\r\n");
+
+    // Existing key: returns its length and keeps the value
+    client.assert_command_response(&request(&["SET", "greeting", "Hello"]), "+OK\r\n");
+    client.assert_command_response(&request(&["SETRANGE", "greeting", "0", ""]), ":5\r\n");
+    client.assert_command_response(&request(&["GET", "greeting"]), "$5\r\nHello\r\n");
+}
+
+#[test]
+fn setrange_overwrites_and_pads_with_zero_bytes() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "greeting", "Hello World"]), "+OK\r\n");
+    client.assert_command_response(&request(&["SETRANGE", "greeting", "6", "Redis"]), ":11\r\n");
+    client.assert_command_response(&request(&["GET", "greeting"]), "$11\r\nHello Redis\r\n");
+
+    client.assert_command_response(&request(&["SETRANGE", "padded", "3", "ab"]), ":5\r\n");
+    client.assert_command_response(&request(&["GET", "padded"]), "$5\r\n\0\0\0ab\r\n");
+}
+
+#[test]
+fn setrange_negative_offset_is_rejected() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["SETRANGE", "greeting", "-1", "x"]),
+        "-ERR offset is out of range\r\n",
+    );
+}
+
+#[test]
+fn string_range_commands_reject_non_string_values() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["RPUSH", "list", "a"]), ":1\r\n");
+
+    client.assert_command_response(
+        &request(&["GETRANGE", "list", "0", "-1"]),
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+    client.assert_command_response(
+        &request(&["SETRANGE", "list", "0", "x"]),
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+    client.assert_command_response(
+        &request(&["SETRANGE", "list", "0", ""]),
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+}
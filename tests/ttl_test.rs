@@ -0,0 +1,66 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request, start_client};
+use std::thread;
+use std::time::Duration;
+
+// https://redis.io/docs/latest/commands/ttl/
+// https://redis.io/docs/latest/commands/pttl/
+
+fn read_integer(client: &mut ValkyrieClientTest, req: &str) -> i64 {
+    client.send(req.as_bytes()).expect("send request");
+    let line = client.read_line().expect("read response");
+    assert!(
+        line.starts_with(':'),
+        "Expected integer reply, got: {line:?}"
+    );
+    line[1..].trim().parse().expect("parse integer reply")
+}
+
+#[test]
+fn ttl_missing_key() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["TTL", "missing"]), ":-2\r\n");
+    client.assert_command_response(&request(&["PTTL", "missing"]), ":-2\r\n");
+}
+
+#[test]
+fn ttl_key_without_expiration() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "key", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["TTL", "key"]), ":-1\r\n");
+    client.assert_command_response(&request(&["PTTL", "key"]), ":-1\r\n");
+
+    // Lists have no expiration either
+    client.assert_command_response(&request(&["RPUSH", "list", "a"]), ":1\r\n");
+    client.assert_command_response(&request(&["TTL", "list"]), ":-1\r\n");
+}
+
+#[test]
+fn ttl_key_with_expiration() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "key", "value", "EX", "100"]), "+OK\r\n");
+    client.assert_command_response(&request(&["TTL", "key"]), ":100\r\n");
+
+    let pttl = read_integer(&mut client, &request(&["PTTL", "key"]));
+    assert!(pttl > 99_000 && pttl <= 100_000, "unexpected PTTL {pttl}");
+
+    // SET without options clears the expiration
+    client.assert_command_response(&request(&["SET", "key", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["TTL", "key"]), ":-1\r\n");
+}
+
+#[test]
+fn ttl_after_expiration_reports_missing_key() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["SET", "key", "value", "PX", "200"]), "+OK\r\n");
+    let pttl = read_integer(&mut client, &request(&["PTTL", "key"]));
+    assert!(pttl > 0 && pttl <= 200, "unexpected PTTL {pttl}");
+
+    thread::sleep(Duration::from_millis(500));
+    client.assert_command_response(&request(&["PTTL", "key"]), ":-2\r\n");
+}
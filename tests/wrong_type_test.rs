@@ -0,0 +1,52 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request};
+
+// Every type mismatch is reported with the WRONGTYPE error code, which clients match on,
+// whatever the command and the two types involved
+
+#[test]
+fn type_mismatches_use_the_wrongtype_prefix() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(&request(&["SET", "string", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["RPUSH", "list", "a"]), ":1\r\n");
+    client.assert_command_response(&request(&["HSET", "hash", "f", "v"]), ":1\r\n");
+    client.assert_command_response(&request(&["SADD", "set", "m"]), ":1\r\n");
+    client.assert_command_response(&request(&["ZADD", "zset", "1", "m"]), ":1\r\n");
+    client.assert_command_response(
+        &request(&["XADD", "stream", "1-1", "f", "v"]),
+        "$3\r\n1-1\r\n",
+    );
+
+    // One command per type: (type, command, arguments after the key)
+    let commands: &[(&str, &str, &[&str])] = &[
+        ("string", "APPEND", &["x"]),
+        ("list", "LPUSH", &["x"]),
+        ("list", "RPUSH", &["x"]),
+        ("hash", "HGET", &["f"]),
+        ("set", "SADD", &["x"]),
+        ("zset", "ZSCORE", &["m"]),
+        ("stream", "XADD", &["*", "f", "v"]),
+        ("stream", "XLEN", &[]),
+        ("stream", "XRANGE", &["-", "+"]),
+    ];
+    let keys = ["string", "list", "hash", "set", "zset", "stream"];
+
+    for (command_type, command, rest) in commands {
+        for key in keys.iter().filter(|key| *key != command_type) {
+            let mut args = vec![*command, *key];
+            args.extend_from_slice(rest);
+            client
+                .send(request(&args).as_bytes())
+                .expect("send command");
+
+            let reply = client.read_line().expect("read reply");
+            assert!(
+                reply.starts_with("-WRONGTYPE "),
+                "{args:?} on a {key} replied {reply:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,151 @@
+mod common;
+
+use crate::common::ValkyrieClientTest;
+
+// https://redis.io/docs/latest/commands/xadd/
+// https://redis.io/docs/latest/commands/xlen/
+
+// XADD with '*' generates an '<ms>-<seq>' ID and returns it as BulkString
+#[test]
+fn xadd_auto_id_generation() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    // XADD mystream * name alice
+    let req = "*5\r\n$4\r\nXADD\r\n$8\r\nmystream\r\n$1\r\n*\r\n$4\r\nname\r\n$5\r\nalice\r\n";
+
+    client.send(req.as_bytes()).expect("send XADD");
+    let first_id = client.read_bulk_or_null().expect("first id");
+
+    client.send(req.as_bytes()).expect("send XADD");
+    let second_id = client.read_bulk_or_null().expect("second id");
+
+    let parse_id = |id: &str| -> (u64, u64) {
+        let (ms, seq) = id.split_once('-').expect("id has '-' separator");
+        (ms.parse().expect("ms part"), seq.parse().expect("seq part"))
+    };
+
+    let first = parse_id(&first_id);
+    let second = parse_id(&second_id);
+    assert!(first.0 > 0, "ms part should be a unix timestamp");
+    assert!(second > first, "IDs must be strictly increasing");
+}
+
+// XADD with '<ms>-*' generates only the sequence part
+#[test]
+fn xadd_auto_sequence_generation() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(
+        "*5\r\n$4\r\nXADD\r\n$1\r\ns\r\n$3\r\n5-*\r\n$1\r\nf\r\n$1\r\nv\r\n",
+        "$3\r\n5-0\r\n",
+    );
+    client.assert_command_response(
+        "*5\r\n$4\r\nXADD\r\n$1\r\ns\r\n$3\r\n5-*\r\n$1\r\nf\r\n$1\r\nv\r\n",
+        "$3\r\n5-1\r\n",
+    );
+}
+
+// XLEN counts entries, 0 for missing key
+#[test]
+fn xlen_counts_entries() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response("*2\r\n$4\r\nXLEN\r\n$1\r\ns\r\n", ":0\r\n");
+
+    client.assert_command_response(
+        "*5\r\n$4\r\nXADD\r\n$1\r\ns\r\n$3\r\n1-1\r\n$1\r\na\r\n$1\r\n1\r\n",
+        "$3\r\n1-1\r\n",
+    );
+    client.assert_command_response(
+        "*7\r\n$4\r\nXADD\r\n$1\r\ns\r\n$3\r\n1-2\r\n$1\r\na\r\n$1\r\n2\r\n$1\r\nb\r\n$1\r\n3\r\n",
+        "$3\r\n1-2\r\n",
+    );
+
+    client.assert_command_response("*2\r\n$4\r\nXLEN\r\n$1\r\ns\r\n", ":2\r\n");
+}
+
+// Explicit IDs must be strictly greater than the stream top item
+#[test]
+fn xadd_rejects_equal_or_smaller_id() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(
+        "*5\r\n$4\r\nXADD\r\n$1\r\ns\r\n$3\r\n5-1\r\n$1\r\nf\r\n$1\r\nv\r\n",
+        "$3\r\n5-1\r\n",
+    );
+
+    let expected =
+        "-ERR The ID specified in XADD is equal or smaller than the target stream top item\r\n";
+
+    // Equal ID
+    client.assert_command_response(
+        "*5\r\n$4\r\nXADD\r\n$1\r\ns\r\n$3\r\n5-1\r\n$1\r\nf\r\n$1\r\nv\r\n",
+        expected,
+    );
+
+    // Smaller ID
+    client.assert_command_response(
+        "*5\r\n$4\r\nXADD\r\n$1\r\ns\r\n$3\r\n4-9\r\n$1\r\nf\r\n$1\r\nv\r\n",
+        expected,
+    );
+
+    // Rejected entries are not added
+    client.assert_command_response("*2\r\n$4\r\nXLEN\r\n$1\r\ns\r\n", ":1\r\n");
+}
+
+#[test]
+fn xadd_rejects_zero_id() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(
+        "*5\r\n$4\r\nXADD\r\n$1\r\ns\r\n$3\r\n0-0\r\n$1\r\nf\r\n$1\r\nv\r\n",
+        "-ERR The ID specified in XADD must be greater than 0-0\r\n",
+    );
+
+    // The key must not be created
+    client.assert_command_response("*2\r\n$4\r\nXLEN\r\n$1\r\ns\r\n", ":0\r\n");
+}
+
+#[test]
+fn xadd_invalid_id_format() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(
+        "*5\r\n$4\r\nXADD\r\n$1\r\ns\r\n$3\r\nabc\r\n$1\r\nf\r\n$1\r\nv\r\n",
+        "-ERR Invalid stream ID specified as stream command argument\r\n",
+    );
+}
+
+#[test]
+fn xadd_odd_number_of_field_value_arguments() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(
+        "*6\r\n$4\r\nXADD\r\n$1\r\ns\r\n$1\r\n*\r\n$1\r\nf\r\n$1\r\nv\r\n$1\r\ng\r\n",
+        "-Wrong number of arguments for XADD command\r\n",
+    );
+}
+
+#[test]
+fn xadd_and_xlen_on_list_key_fail() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response("*3\r\n$5\r\nRPUSH\r\n$1\r\nl\r\n$1\r\na\r\n", ":1\r\n");
+
+    client.assert_command_response(
+        "*5\r\n$4\r\nXADD\r\n$1\r\nl\r\n$1\r\n*\r\n$1\r\nf\r\n$1\r\nv\r\n",
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+    client.assert_command_response(
+        "*2\r\n$4\r\nXLEN\r\n$1\r\nl\r\n",
+        "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n",
+    );
+}
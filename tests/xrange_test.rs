@@ -0,0 +1,134 @@
+mod common;
+
+use crate::common::ValkyrieClientTest;
+
+// https://redis.io/docs/latest/commands/xrange/
+// XRANGE key start end [COUNT count]
+
+fn xadd_request(id: &str, field: &str, value: &str) -> String {
+    format!(
+        "*5\r\n$4\r\nXADD\r\n$1\r\ns\r\n${}\r\n{}\r\n${}\r\n{}\r\n${}\r\n{}\r\n",
+        id.len(),
+        id,
+        field.len(),
+        field,
+        value.len(),
+        value
+    )
+}
+
+fn populate_stream(client: &mut ValkyrieClientTest) {
+    client.assert_command_response(&xadd_request("1-1", "a", "1"), "$3\r\n1-1\r\n");
+    client.assert_command_response(&xadd_request("1-2", "b", "2"), "$3\r\n1-2\r\n");
+    client.assert_command_response(&xadd_request("2-1", "c", "3"), "$3\r\n2-1\r\n");
+    client.assert_command_response(&xadd_request("3-1", "d", "4"), "$3\r\n3-1\r\n");
+}
+
+fn entry(id: &str, field: &str, value: &str) -> String {
+    format!(
+        "*2\r\n${}\r\n{}\r\n*2\r\n${}\r\n{}\r\n${}\r\n{}\r\n",
+        id.len(),
+        id,
+        field.len(),
+        field,
+        value.len(),
+        value
+    )
+}
+
+#[test]
+fn xrange_full_range() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+    populate_stream(&mut client);
+
+    // XRANGE s - +
+    let expected = format!(
+        "*4\r\n{}{}{}{}",
+        entry("1-1", "a", "1"),
+        entry("1-2", "b", "2"),
+        entry("2-1", "c", "3"),
+        entry("3-1", "d", "4")
+    );
+    client.assert_command_response(
+        "*4\r\n$6\r\nXRANGE\r\n$1\r\ns\r\n$1\r\n-\r\n$1\r\n+\r\n",
+        &expected,
+    );
+}
+
+#[test]
+fn xrange_bounded_range() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+    populate_stream(&mut client);
+
+    // XRANGE s 1-2 2-1 (both bounds inclusive)
+    let expected = format!("*2\r\n{}{}", entry("1-2", "b", "2"), entry("2-1", "c", "3"));
+    client.assert_command_response(
+        "*4\r\n$6\r\nXRANGE\r\n$1\r\ns\r\n$3\r\n1-2\r\n$3\r\n2-1\r\n",
+        &expected,
+    );
+
+    // XRANGE s 1 1: incomplete IDs cover the whole millisecond
+    let expected = format!("*2\r\n{}{}", entry("1-1", "a", "1"), entry("1-2", "b", "2"));
+    client.assert_command_response(
+        "*4\r\n$6\r\nXRANGE\r\n$1\r\ns\r\n$1\r\n1\r\n$1\r\n1\r\n",
+        &expected,
+    );
+}
+
+#[test]
+fn xrange_count_limits_entries() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+    populate_stream(&mut client);
+
+    // XRANGE s - + COUNT 2
+    let expected = format!("*2\r\n{}{}", entry("1-1", "a", "1"), entry("1-2", "b", "2"));
+    client.assert_command_response(
+        "*6\r\n$6\r\nXRANGE\r\n$1\r\ns\r\n$1\r\n-\r\n$1\r\n+\r\n$5\r\nCOUNT\r\n$1\r\n2\r\n",
+        &expected,
+    );
+}
+
+#[test]
+fn xrange_empty_results() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    // Missing stream
+    client.assert_command_response(
+        "*4\r\n$6\r\nXRANGE\r\n$1\r\ns\r\n$1\r\n-\r\n$1\r\n+\r\n",
+        "*0\r\n",
+    );
+
+    populate_stream(&mut client);
+
+    // Range without entries
+    client.assert_command_response(
+        "*4\r\n$6\r\nXRANGE\r\n$1\r\ns\r\n$1\r\n5\r\n$1\r\n+\r\n",
+        "*0\r\n",
+    );
+
+    // start > end
+    client.assert_command_response(
+        "*4\r\n$6\r\nXRANGE\r\n$1\r\ns\r\n$1\r\n3\r\n$1\r\n1\r\n",
+        "*0\r\n",
+    );
+}
+
+#[test]
+fn xrange_invalid_arguments() {
+    let server = common::ValkyrieServerTest::start(2, 3).expect("start server");
+    let mut client = ValkyrieClientTest::new(server);
+
+    client.assert_command_response(
+        "*4\r\n$6\r\nXRANGE\r\n$1\r\ns\r\n$3\r\nabc\r\n$1\r\n+\r\n",
+        "-ERR Invalid stream ID specified as stream command argument\r\n",
+    );
+
+    client.assert_command_response(
+        "*6\r\n$6\r\nXRANGE\r\n$1\r\ns\r\n$1\r\n-\r\n$1\r\n+\r\n$5\r\nLIMIT\r\n$1\r\n2\r\n",
+        "-ERR syntax error\r\n",
+    );
+}
@@ -0,0 +1,97 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request, start_client};
+
+// https://redis.io/docs/latest/commands/zpopmin/
+// https://redis.io/docs/latest/commands/zpopmax/
+
+const WRONG_TYPE: &str = "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n";
+
+fn array(values: &[&str]) -> String {
+    let mut resp = format!("*{}\r\n", values.len());
+    for value in values {
+        resp.push_str(&format!("${}\r\n{}\r\n", value.len(), value));
+    }
+    resp
+}
+
+fn start_client_with_zset() -> ValkyrieClientTest {
+    let mut client = start_client();
+
+    // b and c share a score, so they are ordered by member
+    client.assert_command_response(
+        &request(&[
+            "ZADD", "zset", "3", "d", "1", "a", "2", "c", "2", "b", "1.5", "x",
+        ]),
+        ":5\r\n",
+    );
+    client
+}
+
+#[test]
+fn zpopmin_pops_lowest_scores_first() {
+    let mut client = start_client_with_zset();
+
+    client.assert_command_response(&request(&["ZPOPMIN", "zset"]), &array(&["a", "1"]));
+    client.assert_command_response(
+        &request(&["ZPOPMIN", "zset", "3"]),
+        &array(&["x", "1.5", "b", "2", "c", "2"]),
+    );
+    client.assert_command_response(&request(&["ZRANGE", "zset", "0", "-1"]), &array(&["d"]));
+}
+
+#[test]
+fn zpopmax_pops_highest_scores_first() {
+    let mut client = start_client_with_zset();
+
+    client.assert_command_response(&request(&["ZPOPMAX", "zset"]), &array(&["d", "3"]));
+    client.assert_command_response(
+        &request(&["ZPOPMAX", "zset", "2"]),
+        &array(&["c", "2", "b", "2"]),
+    );
+    client.assert_command_response(
+        &request(&["ZRANGE", "zset", "0", "-1"]),
+        &array(&["a", "x"]),
+    );
+}
+
+#[test]
+fn count_larger_than_set_pops_everything_and_deletes_key() {
+    let mut client = start_client_with_zset();
+
+    client.assert_command_response(
+        &request(&["ZPOPMAX", "zset", "10"]),
+        &array(&["d", "3", "c", "2", "b", "2", "x", "1.5", "a", "1"]),
+    );
+    client.assert_command_response(&request(&["EXISTS", "zset"]), ":0\r\n");
+    client.assert_command_response(&request(&["TYPE", "zset"]), "+none\r\n");
+}
+
+#[test]
+fn zero_count_and_missing_key_return_empty_array() {
+    let mut client = start_client_with_zset();
+
+    client.assert_command_response(&request(&["ZPOPMIN", "zset", "0"]), "*0\r\n");
+    client.assert_command_response(&request(&["ZCOUNT", "zset", "-inf", "+inf"]), ":5\r\n");
+
+    client.assert_command_response(&request(&["ZPOPMIN", "missing"]), "*0\r\n");
+    client.assert_command_response(&request(&["ZPOPMAX", "missing", "3"]), "*0\r\n");
+}
+
+#[test]
+fn invalid_count_and_wrong_type() {
+    let mut client = start_client_with_zset();
+
+    client.assert_command_response(
+        &request(&["ZPOPMIN", "zset", "-1"]),
+        "-ERR value is out of range, must be positive\r\n",
+    );
+    client.assert_command_response(
+        &request(&["ZPOPMAX", "zset", "many"]),
+        "-ERR value is not an integer or out of range\r\n",
+    );
+
+    client.assert_command_response(&request(&["SET", "str", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["ZPOPMIN", "str"]), WRONG_TYPE);
+    client.assert_command_response(&request(&["ZPOPMAX", "str"]), WRONG_TYPE);
+}
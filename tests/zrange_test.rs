@@ -0,0 +1,119 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request, start_client};
+
+// https://redis.io/docs/latest/commands/zrange/
+// https://redis.io/docs/latest/commands/zrevrange/
+
+const WRONG_TYPE: &str = "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n";
+
+fn array(values: &[&str]) -> String {
+    let mut resp = format!("*{}\r\n", values.len());
+    for value in values {
+        resp.push_str(&format!("${}\r\n{}\r\n", value.len(), value));
+    }
+    resp
+}
+
+fn start_client_with_zset() -> ValkyrieClientTest {
+    let mut client = start_client();
+
+    // Scores in another order than insertion and members
+    client.assert_command_response(
+        &request(&["ZADD", "zset", "3", "c", "1", "a", "2.5", "b", "10", "d"]),
+        ":4\r\n",
+    );
+    client
+}
+
+#[test]
+fn zrange_positive_and_negative_indexes() {
+    let mut client = start_client_with_zset();
+
+    client.assert_command_response(
+        &request(&["ZRANGE", "zset", "0", "-1"]),
+        &array(&["a", "b", "c", "d"]),
+    );
+    client.assert_command_response(&request(&["ZRANGE", "zset", "1", "2"]), &array(&["b", "c"]));
+    client.assert_command_response(
+        &request(&["ZRANGE", "zset", "-2", "-1"]),
+        &array(&["c", "d"]),
+    );
+    // Stop beyond the end is clamped, start beyond it is empty
+    client.assert_command_response(
+        &request(&["ZRANGE", "zset", "2", "100"]),
+        &array(&["c", "d"]),
+    );
+    client.assert_command_response(&request(&["ZRANGE", "zset", "4", "10"]), "*0\r\n");
+    client.assert_command_response(&request(&["ZRANGE", "zset", "2", "1"]), "*0\r\n");
+
+    client.assert_command_response(&request(&["ZRANGE", "missing", "0", "-1"]), "*0\r\n");
+}
+
+#[test]
+fn zrevrange_ranks_from_the_highest_score() {
+    let mut client = start_client_with_zset();
+
+    client.assert_command_response(
+        &request(&["ZREVRANGE", "zset", "0", "-1"]),
+        &array(&["d", "c", "b", "a"]),
+    );
+    client.assert_command_response(&request(&["ZREVRANGE", "zset", "0", "0"]), &array(&["d"]));
+    client.assert_command_response(
+        &request(&["ZREVRANGE", "zset", "-2", "-1"]),
+        &array(&["b", "a"]),
+    );
+}
+
+#[test]
+fn zrange_withscores_interleaves_scores() {
+    let mut client = start_client_with_zset();
+
+    client.assert_command_response(
+        &request(&["ZRANGE", "zset", "0", "1", "WITHSCORES"]),
+        &array(&["a", "1", "b", "2.5"]),
+    );
+    client.assert_command_response(
+        &request(&["ZREVRANGE", "zset", "0", "0", "withscores"]),
+        &array(&["d", "10"]),
+    );
+    client.assert_command_response(
+        &request(&["ZRANGE", "zset", "0", "1", "SCORES"]),
+        "-ERR syntax error\r\n",
+    );
+}
+
+#[test]
+fn zrange_orders_equal_scores_by_member() {
+    let mut client = start_client_with_zset();
+
+    client.assert_command_response(
+        &request(&[
+            "ZADD", "ties", "1", "beta", "1", "alpha", "1", "gamma", "0", "zero",
+        ]),
+        ":4\r\n",
+    );
+
+    client.assert_command_response(
+        &request(&["ZRANGE", "ties", "0", "-1"]),
+        &array(&["zero", "alpha", "beta", "gamma"]),
+    );
+    client.assert_command_response(
+        &request(&["ZREVRANGE", "ties", "0", "-1"]),
+        &array(&["gamma", "beta", "alpha", "zero"]),
+    );
+}
+
+#[test]
+fn zrange_rejects_bad_indexes_and_other_types() {
+    let mut client = start_client_with_zset();
+
+    client.assert_command_response(
+        &request(&["ZRANGE", "zset", "first", "-1"]),
+        "-ERR value is not an integer or out of range\r\n",
+    );
+
+    client.assert_command_response(&request(&["RPUSH", "list", "a"]), ":1\r\n");
+    client.assert_command_response(&request(&["ZRANGE", "list", "0", "-1"]), WRONG_TYPE);
+    client.assert_command_response(&request(&["ZREVRANGE", "list", "0", "-1"]), WRONG_TYPE);
+}
@@ -0,0 +1,169 @@
+mod common;
+
+use crate::common::{ValkyrieClientTest, request, start_client};
+
+// https://redis.io/docs/latest/commands/zrangebyscore/
+// https://redis.io/docs/latest/commands/zcount/
+
+const WRONG_TYPE: &str = "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n";
+
+fn array(values: &[&str]) -> String {
+    let mut resp = format!("*{}\r\n", values.len());
+    for value in values {
+        resp.push_str(&format!("${}\r\n{}\r\n", value.len(), value));
+    }
+    resp
+}
+
+fn start_client_with_zset() -> ValkyrieClientTest {
+    let mut client = start_client();
+
+    // b and c share a score, so they are ordered by member
+    client.assert_command_response(
+        &request(&[
+            "ZADD", "zset", "3", "d", "1", "a", "2", "c", "2", "b", "10", "e",
+        ]),
+        ":5\r\n",
+    );
+    client
+}
+
+#[test]
+fn zrangebyscore_inclusive_bounds() {
+    let mut client = start_client_with_zset();
+
+    client.assert_command_response(
+        &request(&["ZRANGEBYSCORE", "zset", "1", "3"]),
+        &array(&["a", "b", "c", "d"]),
+    );
+    client.assert_command_response(
+        &request(&["ZRANGEBYSCORE", "zset", "2", "2", "WITHSCORES"]),
+        &array(&["b", "2", "c", "2"]),
+    );
+    client.assert_command_response(&request(&["ZRANGEBYSCORE", "zset", "4", "9"]), "*0\r\n");
+    client.assert_command_response(&request(&["ZRANGEBYSCORE", "zset", "3", "1"]), "*0\r\n");
+}
+
+#[test]
+fn zrangebyscore_exclusive_bounds() {
+    let mut client = start_client_with_zset();
+
+    client.assert_command_response(
+        &request(&["ZRANGEBYSCORE", "zset", "(1", "3"]),
+        &array(&["b", "c", "d"]),
+    );
+    client.assert_command_response(
+        &request(&["ZRANGEBYSCORE", "zset", "1", "(3"]),
+        &array(&["a", "b", "c"]),
+    );
+    client.assert_command_response(
+        &request(&["ZRANGEBYSCORE", "zset", "(1", "(3"]),
+        &array(&["b", "c"]),
+    );
+    client.assert_command_response(&request(&["ZRANGEBYSCORE", "zset", "(2", "(2"]), "*0\r\n");
+}
+
+#[test]
+fn zrangebyscore_infinities() {
+    let mut client = start_client_with_zset();
+
+    client.assert_command_response(
+        &request(&["ZRANGEBYSCORE", "zset", "-inf", "+inf"]),
+        &array(&["a", "b", "c", "d", "e"]),
+    );
+    client.assert_command_response(
+        &request(&["ZRANGEBYSCORE", "zset", "(3", "+inf", "WITHSCORES"]),
+        &array(&["e", "10"]),
+    );
+    client.assert_command_response(
+        &request(&["ZRANGEBYSCORE", "zset", "-inf", "(2"]),
+        &array(&["a"]),
+    );
+}
+
+#[test]
+fn zrangebyscore_limit() {
+    let mut client = start_client_with_zset();
+
+    client.assert_command_response(
+        &request(&["ZRANGEBYSCORE", "zset", "-inf", "+inf", "LIMIT", "1", "2"]),
+        &array(&["b", "c"]),
+    );
+    client.assert_command_response(
+        &request(&[
+            "ZRANGEBYSCORE",
+            "zset",
+            "-inf",
+            "+inf",
+            "limit",
+            "3",
+            "-1",
+            "withscores",
+        ]),
+        &array(&["d", "3", "e", "10"]),
+    );
+    client.assert_command_response(
+        &request(&["ZRANGEBYSCORE", "zset", "-inf", "+inf", "LIMIT", "5", "10"]),
+        "*0\r\n",
+    );
+    client.assert_command_response(
+        &request(&["ZRANGEBYSCORE", "zset", "-inf", "+inf", "LIMIT", "-1", "10"]),
+        "*0\r\n",
+    );
+    client.assert_command_response(
+        &request(&["ZRANGEBYSCORE", "zset", "-inf", "+inf", "LIMIT", "0", "0"]),
+        "*0\r\n",
+    );
+}
+
+#[test]
+fn zcount_bounds() {
+    let mut client = start_client_with_zset();
+
+    client.assert_command_response(&request(&["ZCOUNT", "zset", "-inf", "+inf"]), ":5\r\n");
+    client.assert_command_response(&request(&["ZCOUNT", "zset", "2", "3"]), ":3\r\n");
+    client.assert_command_response(&request(&["ZCOUNT", "zset", "(2", "3"]), ":1\r\n");
+    client.assert_command_response(&request(&["ZCOUNT", "zset", "(1", "(10"]), ":3\r\n");
+    client.assert_command_response(&request(&["ZCOUNT", "zset", "11", "+inf"]), ":0\r\n");
+}
+
+#[test]
+fn missing_key_and_wrong_type() {
+    let mut client = start_client_with_zset();
+
+    client.assert_command_response(
+        &request(&["ZRANGEBYSCORE", "missing", "-inf", "+inf"]),
+        "*0\r\n",
+    );
+    client.assert_command_response(&request(&["ZCOUNT", "missing", "-inf", "+inf"]), ":0\r\n");
+
+    client.assert_command_response(&request(&["SET", "str", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["ZRANGEBYSCORE", "str", "0", "1"]), WRONG_TYPE);
+    client.assert_command_response(&request(&["ZCOUNT", "str", "0", "1"]), WRONG_TYPE);
+}
+
+#[test]
+fn invalid_arguments() {
+    let mut client = start_client_with_zset();
+
+    client.assert_command_response(
+        &request(&["ZRANGEBYSCORE", "zset", "low", "3"]),
+        "-ERR min or max is not a float\r\n",
+    );
+    client.assert_command_response(
+        &request(&["ZCOUNT", "zset", "1", "(nan"]),
+        "-ERR min or max is not a float\r\n",
+    );
+    client.assert_command_response(
+        &request(&["ZRANGEBYSCORE", "zset", "1", "3", "LIMIT", "1"]),
+        "-ERR syntax error\r\n",
+    );
+    client.assert_command_response(
+        &request(&["ZRANGEBYSCORE", "zset", "1", "3", "LIMIT", "x", "1"]),
+        "-ERR value is not an integer or out of range\r\n",
+    );
+    client.assert_command_response(
+        &request(&["ZRANGEBYSCORE", "zset", "1", "3", "REV"]),
+        "-ERR syntax error\r\n",
+    );
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use crate::common::{request, start_client};
+
+// https://redis.io/docs/latest/commands/zadd/
+// https://redis.io/docs/latest/commands/zscore/
+
+const WRONG_TYPE: &str = "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n";
+
+#[test]
+fn zadd_counts_new_members_only() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["ZADD", "zset", "1", "one", "2", "two"]),
+        ":2\r\n",
+    );
+    client.assert_command_response(
+        &request(&["ZADD", "zset", "3", "three", "1", "one"]),
+        ":1\r\n",
+    );
+    client.assert_command_response(&request(&["TYPE", "zset"]), "+zset\r\n");
+
+    client.assert_command_response(&request(&["ZSCORE", "zset", "one"]), "$1\r\n1\r\n");
+    client.assert_command_response(&request(&["ZSCORE", "zset", "three"]), "$1\r\n3\r\n");
+}
+
+#[test]
+fn zadd_updates_the_score_of_existing_members() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["ZADD", "zset", "1", "member"]), ":1\r\n");
+    client.assert_command_response(&request(&["ZADD", "zset", "2.5", "member"]), ":0\r\n");
+    client.assert_command_response(&request(&["ZSCORE", "zset", "member"]), "$3\r\n2.5\r\n");
+
+    // Same member twice in one call: the last score wins
+    client.assert_command_response(
+        &request(&["ZADD", "zset", "-1", "other", "1e2", "other"]),
+        ":1\r\n",
+    );
+    client.assert_command_response(&request(&["ZSCORE", "zset", "other"]), "$3\r\n100\r\n");
+}
+
+#[test]
+fn zscore_of_missing_key_or_member_is_null() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["ZSCORE", "missing", "member"]), "$-1\r\n");
+
+    client.assert_command_response(&request(&["ZADD", "zset", "1", "member"]), ":1\r\n");
+    client.assert_command_response(&request(&["ZSCORE", "zset", "other"]), "$-1\r\n");
+}
+
+#[test]
+fn zadd_rejects_invalid_scores_and_other_types() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&["ZADD", "zset", "high", "member"]),
+        "-ERR value is not a valid float\r\n",
+    );
+    client.assert_command_response(
+        &request(&["ZADD", "zset", "1", "member", "2"]),
+        "-Wrong number of arguments for ZADD command\r\n",
+    );
+    client.assert_command_response(&request(&["EXISTS", "zset"]), ":0\r\n");
+
+    client.assert_command_response(&request(&["SET", "string", "value"]), "+OK\r\n");
+    client.assert_command_response(&request(&["ZADD", "string", "1", "member"]), WRONG_TYPE);
+    client.assert_command_response(&request(&["ZSCORE", "string", "member"]), WRONG_TYPE);
+}
+
+#[test]
+fn zadd_gt_and_lt_only_update_in_one_direction() {
+    let mut client = start_client();
+
+    client.assert_command_response(&request(&["ZADD", "zset", "5", "member"]), ":1\r\n");
+
+    client.assert_command_response(&request(&["ZADD", "zset", "GT", "3", "member"]), ":0\r\n");
+    client.assert_command_response(&request(&["ZSCORE", "zset", "member"]), "$1\r\n5\r\n");
+    client.assert_command_response(&request(&["ZADD", "zset", "gt", "7", "member"]), ":0\r\n");
+    client.assert_command_response(&request(&["ZSCORE", "zset", "member"]), "$1\r\n7\r\n");
+
+    client.assert_command_response(&request(&["ZADD", "zset", "LT", "9", "member"]), ":0\r\n");
+    client.assert_command_response(&request(&["ZSCORE", "zset", "member"]), "$1\r\n7\r\n");
+    client.assert_command_response(
+        &request(&["ZADD", "zset", "LT", "-inf", "member"]),
+        ":0\r\n",
+    );
+    client.assert_command_response(&request(&["ZSCORE", "zset", "member"]), "$4\r\n-inf\r\n");
+
+    // New members are still added
+    client.assert_command_response(&request(&["ZADD", "zset", "GT", "1", "other"]), ":1\r\n");
+
+    client.assert_command_response(
+        &request(&["ZADD", "zset", "GT", "LT", "1", "member"]),
+        "-ERR GT, LT, and/or NX options at the same time are not compatible\r\n",
+    );
+    client.assert_command_response(
+        &request(&["ZADD", "zset", "GT"]),
+        "-Wrong number of arguments for ZADD command\r\n",
+    );
+}
+
+#[test]
+fn zadd_accepts_infinite_and_negative_zero_scores() {
+    let mut client = start_client();
+
+    client.assert_command_response(
+        &request(&[
+            "ZADD", "zset", "+inf", "top", "-inf", "bottom", "-0", "zero",
+        ]),
+        ":3\r\n",
+    );
+    client.assert_command_response(&request(&["ZSCORE", "zset", "top"]), "$3\r\ninf\r\n");
+    client.assert_command_response(&request(&["ZSCORE", "zset", "zero"]), "$1\r\n0\r\n");
+    client.assert_command_response(
+        &request(&["ZRANGE", "zset", "0", "-1"]),
+        "*3\r\n$6\r\nbottom\r\n$4\r\nzero\r\n$3\r\ntop\r\n",
+    );
+
+    // 0 is not greater than -0
+    client.assert_command_response(&request(&["ZADD", "zset", "GT", "0", "zero"]), ":0\r\n");
+    client.assert_command_response(&request(&["ZCOUNT", "zset", "0", "-0"]), ":1\r\n");
+
+    client.assert_command_response(
+        &request(&["ZADD", "zset", "nan", "member"]),
+        "-ERR value is not a valid float\r\n",
+    );
+}